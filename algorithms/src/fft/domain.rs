@@ -145,6 +145,13 @@ impl<F: FftField> EvaluationDomain<F> {
         })
     }
 
+    /// Return the size of the largest domain supported by the field,
+    /// i.e. `2^TWO_ADICITY` (capped to the largest power of two that fits in a `usize`).
+    /// Callers can compare the size of a circuit against this before attempting to prove.
+    pub fn max_size() -> usize {
+        1 << core::cmp::min(F::FftParameters::TWO_ADICITY, usize::BITS - 1)
+    }
+
     /// Return the size of a domain that is large enough for evaluations of a polynomial
     /// having `num_coeffs` coefficients.
     pub fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
@@ -253,7 +260,10 @@ impl<F: FftField> EvaluationDomain<F> {
     }
 
     /// Evaluate all the lagrange polynomials defined by this domain at the point
-    /// `tau`.
+    /// `tau`, using the barycentric formula with a single batch inversion.
+    ///
+    /// Dotting the output with the evaluations of a polynomial over this domain
+    /// yields the evaluation of the polynomial at `tau`.
     pub fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F> {
         // Evaluate all Lagrange polynomials
         let size = self.size as usize;
@@ -835,7 +845,7 @@ impl<F: FftField> IFFTPrecomputation<F> {
 mod tests {
     use crate::fft::{DensePolynomial, EvaluationDomain};
     use snarkvm_curves::bls12_377::Fr;
-    use snarkvm_fields::{FftField, Field, One, Zero};
+    use snarkvm_fields::{FftField, FftParameters, Field, One, Zero};
     use snarkvm_utilities::UniformRand;
 
     use rand::{thread_rng, Rng};
@@ -932,6 +942,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_max_size() {
+        let max_size = EvaluationDomain::<Fr>::max_size();
+        assert_eq!(1 << <Fr as FftField>::FftParameters::TWO_ADICITY, max_size);
+
+        // Ensure the largest domain succeeds, and any larger domain fails.
+        let domain = EvaluationDomain::<Fr>::new(max_size).unwrap();
+        assert_eq!(max_size, domain.size());
+        assert!(EvaluationDomain::<Fr>::new(max_size + 1).is_none());
+        assert_eq!(Some(max_size), EvaluationDomain::<Fr>::compute_size_of_domain(max_size));
+        assert_eq!(None, EvaluationDomain::<Fr>::compute_size_of_domain(max_size + 1));
+
+        // Ensure the generator of the largest domain is the two-adic root of unity, of order exactly `max_size`.
+        assert_eq!(Fr::two_adic_root_of_unity(), domain.group_gen);
+        assert_eq!(Fr::one(), domain.group_gen.pow([max_size as u64]));
+        assert_ne!(Fr::one(), domain.group_gen.pow([max_size as u64 / 2]));
+    }
+
     /// Tests that the roots of unity result is the same as domain.elements().
     #[test]
     fn test_roots_of_unity() {
@@ -18,7 +18,7 @@
 
 use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::{cfg_iter_mut, serialize::*};
+use snarkvm_utilities::{cfg_iter, cfg_iter_mut, serialize::*};
 
 use rand::Rng;
 use std::{
@@ -113,6 +113,14 @@ impl<F: Field> DensePolynomial<F> {
         crate::cfg_reduce!(mapping, || zero, |a, b| a + b)
     }
 
+    /// Evaluates `self` at each of the given `points` in the field.
+    /// Each point is evaluated with Horner's method, which avoids computing the powers of each point.
+    pub fn evaluate_batch(&self, points: &[F]) -> Vec<F> {
+        cfg_iter!(points)
+            .map(|point| self.coeffs.iter().rev().fold(F::zero(), |result, coeff| result * point + coeff))
+            .collect()
+    }
+
     /// Outputs a polynomial of degree `d` where each coefficient is sampled uniformly at random
     /// from the field `F`.
     pub fn rand<R: Rng>(d: usize, rng: &mut R) -> Self {
@@ -480,6 +488,24 @@ mod tests {
 
     use rand::thread_rng;
 
+    #[test]
+    fn evaluate_batch() {
+        let rng = &mut thread_rng();
+        let p = DensePolynomial::<Fr>::rand(100, rng);
+        let points = (0..50).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+        let expected = points.iter().map(|point| p.evaluate(*point)).collect::<Vec<_>>();
+        assert_eq!(expected, p.evaluate_batch(&points));
+
+        // Ensure the edge cases match, including the zero point and the zero polynomial.
+        let points = [Fr::zero(), Fr::one(), -Fr::one()];
+        for p in [p, DensePolynomial::zero(), DensePolynomial::from_coefficients_vec(vec![Fr::one()])] {
+            let expected = points.iter().map(|point| p.evaluate(*point)).collect::<Vec<_>>();
+            assert_eq!(expected, p.evaluate_batch(&points));
+        }
+        assert!(DensePolynomial::<Fr>::rand(10, rng).evaluate_batch(&[]).is_empty());
+    }
+
     #[test]
     fn double_polynomials_random() {
         let rng = &mut thread_rng();
@@ -29,8 +29,24 @@ use crate::{
 use itertools::Itertools;
 use snarkvm_fields::{Field, PrimeField};
 
-use core::{borrow::Borrow, marker::PhantomData};
-use std::collections::BTreeMap;
+use core::{
+    any::{Any, TypeId},
+    borrow::Borrow,
+    marker::PhantomData,
+};
+use parking_lot::Mutex;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Weak},
+};
+
+/// The FFT and IFFT precomputations for a domain, held weakly so they are dropped along with the last index.
+type FFTPrecomputationEntry = (Weak<dyn Any + Send + Sync>, Weak<dyn Any + Send + Sync>);
+
+lazy_static::lazy_static! {
+    /// The FFT precomputations currently held by an index, keyed by field and domain size.
+    static ref FFT_PRECOMPUTATIONS: Mutex<HashMap<(TypeId, usize), FFTPrecomputationEntry>> = Default::default();
+}
 
 /// The algebraic holographic proof defined in [CHMMVW19](https://eprint.iacr.org/2019/1047).
 /// Currently, this AHP only supports inputs of size one
@@ -57,7 +73,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     pub fn num_formatted_public_inputs_is_admissible(num_inputs: usize) -> Result<(), AHPError> {
         match num_inputs.count_ones() == 1 {
             true => Ok(()),
-            false => Err(AHPError::InvalidPublicInputLength),
+            false => Err(AHPError::InvalidPublicInputLength(num_inputs)),
         }
     }
 
@@ -74,9 +90,9 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let padded_matrix_dim = matrices::padded_matrix_dim(num_variables, num_constraints);
         let zk_bound = 1;
         let constraint_domain_size = EvaluationDomain::<F>::compute_size_of_domain(padded_matrix_dim)
-            .ok_or(AHPError::PolynomialDegreeTooLarge)?;
-        let non_zero_domain_size =
-            EvaluationDomain::<F>::compute_size_of_domain(num_non_zero).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+            .ok_or(AHPError::PolynomialDegreeTooLarge(padded_matrix_dim))?;
+        let non_zero_domain_size = EvaluationDomain::<F>::compute_size_of_domain(num_non_zero)
+            .ok_or(AHPError::PolynomialDegreeTooLarge(num_non_zero))?;
 
         Ok(*[
             2 * constraint_domain_size + zk_bound - 2,
@@ -125,7 +141,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         non_zero_a_domain_size: usize,
         non_zero_b_domain_size: usize,
         non_zero_c_domain_size: usize,
-    ) -> Option<(FFTPrecomputation<F>, IFFTPrecomputation<F>)> {
+    ) -> Option<(Arc<FFTPrecomputation<F>>, Arc<IFFTPrecomputation<F>>)> {
         let largest_domain_size = [
             3 * constraint_domain_size,
             non_zero_a_domain_size * 2,
@@ -136,11 +152,38 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         .max()?;
         let largest_mul_domain = EvaluationDomain::new(largest_domain_size)?;
 
-        let fft_precomputation = largest_mul_domain.precompute_fft();
-        let ifft_precomputation = fft_precomputation.to_ifft_precomputation();
+        // Reuse the precomputation for this domain size, if another index still holds it.
+        let key = (TypeId::of::<F>(), largest_mul_domain.size());
+        if let Some(precomputations) = Self::cached_fft_precomputation(&FFT_PRECOMPUTATIONS.lock(), &key) {
+            return Some(precomputations);
+        }
+
+        // Compute the precomputation without holding the lock, so that indices of other domains are not blocked.
+        let fft_precomputation = Arc::new(largest_mul_domain.precompute_fft());
+        let ifft_precomputation = Arc::new(fft_precomputation.to_ifft_precomputation());
+
+        let mut cache = FFT_PRECOMPUTATIONS.lock();
+        // If another index computed the precomputation for this domain in the meantime, reuse it instead.
+        if let Some(precomputations) = Self::cached_fft_precomputation(&cache, &key) {
+            return Some(precomputations);
+        }
+        // Prune the precomputations that are no longer held by any index.
+        cache.retain(|_, (fft, ifft)| fft.strong_count() > 0 && ifft.strong_count() > 0);
+        let (fft, ifft): (Arc<dyn Any + Send + Sync>, Arc<dyn Any + Send + Sync>) =
+            (fft_precomputation.clone(), ifft_precomputation.clone());
+        cache.insert(key, (Arc::downgrade(&fft), Arc::downgrade(&ifft)));
         Some((fft_precomputation, ifft_precomputation))
     }
 
+    /// Returns the FFT and IFFT precomputations for the given key from the cache, if an index still holds them.
+    fn cached_fft_precomputation(
+        cache: &HashMap<(TypeId, usize), FFTPrecomputationEntry>,
+        key: &(TypeId, usize),
+    ) -> Option<(Arc<FFTPrecomputation<F>>, Arc<IFFTPrecomputation<F>>)> {
+        let (fft, ifft) = cache.get(key)?;
+        Some((fft.upgrade()?.downcast().ok()?, ifft.upgrade()?.downcast().ok()?))
+    }
+
     /// Construct the linear combinations that are checked by the AHP.
     /// Public input should be unformatted.
     #[allow(non_snake_case)]
@@ -168,7 +211,8 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             })
             .collect::<Result<Vec<_>, _>>()?;
 
-        let input_domain = EvaluationDomain::new(public_inputs[0].len()).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        let input_domain = EvaluationDomain::new(public_inputs[0].len())
+            .ok_or(AHPError::PolynomialDegreeTooLarge(public_inputs[0].len()))?;
 
         let first_round_msg = state.first_round_message.as_ref().unwrap();
         let alpha = first_round_msg.alpha;
@@ -15,22 +15,44 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 /// Describes the failure modes of the AHP scheme.
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum AHPError {
     /// An error occurred during constraint generation.
+    #[error("Failed to synthesize the constraint system: {0}")]
     ConstraintSystemError(snarkvm_r1cs::errors::SynthesisError),
     /// An error occurred during Fiat-Shamir.
+    #[error("Failed to sample a Fiat-Shamir challenge: {0}")]
     FiatShamirError(crate::snark::marlin::fiat_shamir::FiatShamirError),
+    /// The circuit indexes are not compatible, as described by the message.
+    #[error("The circuit indexes are not compatible: {0}")]
+    IncompatibleIndex(String),
     /// The instance generated during proving does not match that in the index.
-    InstanceDoesNotMatchIndex,
+    #[error(
+        "The instance does not match the index: expected {expected_constraints} constraints and {expected_variables} \
+         variables, found {found_constraints} constraints and {found_variables} variables. \
+         Ensure the circuit was indexed with the same constraint system that is being proved"
+    )]
+    InstanceDoesNotMatchIndex {
+        expected_constraints: usize,
+        found_constraints: usize,
+        expected_variables: usize,
+        found_variables: usize,
+    },
     /// The number of public inputs is incorrect.
-    InvalidPublicInputLength,
+    #[error("The number of formatted public inputs must be a power of two, found {0}")]
+    InvalidPublicInputLength(usize),
     /// During verification, a required evaluation is missing
+    #[error("The proof is missing the evaluation for '{0}'")]
     MissingEval(String),
     /// Currently we only support square constraint matrices.
+    #[error("The constraint matrices must be square (the number of constraints must equal the number of variables)")]
     NonSquareMatrix,
     /// During synthesis, our polynomials ended up being too high of degree
-    PolynomialDegreeTooLarge,
+    #[error(
+        "No evaluation domain supports {0} elements, as it exceeds the two-adicity of the field. \
+         Reduce the number of constraints, variables, or non-zero matrix entries in the circuit"
+    )]
+    PolynomialDegreeTooLarge(usize),
 }
 
 impl From<crate::snark::marlin::fiat_shamir::FiatShamirError> for AHPError {
@@ -44,3 +66,43 @@ impl From<snarkvm_r1cs::errors::SynthesisError> for AHPError {
         AHPError::ConstraintSystemError(other)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fft::EvaluationDomain,
+        snark::marlin::{ahp::AHPForR1CS, MarlinHidingMode},
+    };
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{FftField, FftParameters};
+
+    #[test]
+    fn test_polynomial_degree_too_large_message() {
+        // Request a domain larger than the two-adicity of the field supports.
+        let num_elements = 1 << (<Fr as FftField>::FftParameters::TWO_ADICITY + 1);
+        assert!(EvaluationDomain::<Fr>::compute_size_of_domain(num_elements).is_none());
+
+        let error = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(num_elements, num_elements, 1).unwrap_err();
+        let message = error.to_string();
+        assert!(matches!(error, AHPError::PolynomialDegreeTooLarge(size) if size >= num_elements));
+        assert!(message.starts_with("No evaluation domain supports "));
+        assert!(message.contains("Reduce the number of constraints, variables, or non-zero matrix entries"));
+    }
+
+    #[test]
+    fn test_instance_does_not_match_index_message() {
+        let error = AHPError::InstanceDoesNotMatchIndex {
+            expected_constraints: 8,
+            found_constraints: 9,
+            expected_variables: 8,
+            found_variables: 10,
+        };
+        assert_eq!(
+            "The instance does not match the index: expected 8 constraints and 8 variables, \
+             found 9 constraints and 10 variables. \
+             Ensure the circuit was indexed with the same constraint system that is being proved",
+            error.to_string()
+        );
+    }
+}
@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use core::marker::PhantomData;
+use std::sync::Arc;
 
 use crate::{
     fft::{
@@ -51,8 +52,9 @@ pub struct Circuit<F: PrimeField, MM: MarlinMode> {
     pub b_arith: MatrixArithmetization<F>,
     pub c_arith: MatrixArithmetization<F>,
 
-    pub fft_precomputation: FFTPrecomputation<F>,
-    pub ifft_precomputation: IFFTPrecomputation<F>,
+    /// The FFT precomputations for the largest domain, shared between indices with equal domains.
+    pub fft_precomputation: Arc<FFTPrecomputation<F>>,
+    pub ifft_precomputation: Arc<IFFTPrecomputation<F>>,
 
     pub(crate) mode: PhantomData<MM>,
 }
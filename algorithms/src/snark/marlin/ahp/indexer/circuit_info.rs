@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::snark::marlin::{ahp::AHPForR1CS, MarlinMode};
+use crate::snark::marlin::{
+    ahp::{AHPError, AHPForR1CS},
+    MarlinMode,
+};
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{serialize::*, ToBytes};
 
@@ -49,6 +52,29 @@ impl<F: PrimeField> CircuitInfo<F> {
         let max_non_zero = self.num_non_zero_a.max(self.num_non_zero_b).max(self.num_non_zero_c);
         AHPForR1CS::<F, MM>::max_degree(self.num_constraints, self.num_variables, max_non_zero).unwrap()
     }
+
+    /// Returns `true` if `self` and `other` have the same number of public inputs,
+    /// constraints, and non-zero entries in each of the A, B, and C matrices.
+    pub fn is_compatible_with(&self, other: &Self) -> bool {
+        self.check_compatible_with(other).is_ok()
+    }
+
+    /// Returns an error describing the first mismatch between `self` and `other`, if any.
+    pub fn check_compatible_with(&self, other: &Self) -> Result<(), AHPError> {
+        let fields = [
+            ("public inputs", self.num_public_inputs, other.num_public_inputs),
+            ("constraints", self.num_constraints, other.num_constraints),
+            ("non-zero entries in A", self.num_non_zero_a, other.num_non_zero_a),
+            ("non-zero entries in B", self.num_non_zero_b, other.num_non_zero_b),
+            ("non-zero entries in C", self.num_non_zero_c, other.num_non_zero_c),
+        ];
+        match fields.iter().find(|(_, first, second)| first != second) {
+            Some((name, first, second)) => {
+                Err(AHPError::IncompatibleIndex(format!("Mismatched number of {name}: {first} != {second}")))
+            }
+            None => Ok(()),
+        }
+    }
 }
 
 impl<F: PrimeField> ToBytes for CircuitInfo<F> {
@@ -61,3 +87,42 @@ impl<F: PrimeField> ToBytes for CircuitInfo<F> {
         (self.num_non_zero_c as u64).write_le(&mut w)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+
+    fn sample_circuit_info() -> CircuitInfo<Fr> {
+        CircuitInfo {
+            num_public_inputs: 2,
+            num_variables: 10,
+            num_constraints: 8,
+            num_non_zero_a: 12,
+            num_non_zero_b: 9,
+            num_non_zero_c: 7,
+            f: PhantomData,
+        }
+    }
+
+    #[test]
+    fn test_is_compatible_with() {
+        let info = sample_circuit_info();
+        assert!(info.is_compatible_with(&info));
+        assert!(info.check_compatible_with(&sample_circuit_info()).is_ok());
+
+        // Ensure differing non-zero counts are flagged.
+        let other = CircuitInfo { num_non_zero_b: 10, ..info };
+        assert!(!info.is_compatible_with(&other));
+        match info.check_compatible_with(&other) {
+            Err(AHPError::IncompatibleIndex(message)) => {
+                assert_eq!("Mismatched number of non-zero entries in B: 9 != 10", message)
+            }
+            _ => panic!("Expected an incompatible index error"),
+        }
+
+        // Ensure differing constraint and input counts are flagged.
+        assert!(!info.is_compatible_with(&CircuitInfo { num_constraints: 16, ..info }));
+        assert!(!info.is_compatible_with(&CircuitInfo { num_public_inputs: 4, ..info }));
+    }
+}
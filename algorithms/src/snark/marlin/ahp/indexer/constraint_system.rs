@@ -14,13 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::snark::marlin::ahp::matrices::{make_matrices_square, padded_matrix_dim, to_matrix_helper};
+use crate::snark::marlin::ahp::{
+    indexer::num_non_zero,
+    matrices::{make_matrices_square, padded_matrix_dim, to_matrix_helper},
+};
 use snarkvm_fields::Field;
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem as CS, Index as VarIndex, LinearCombination, Variable};
 use snarkvm_utilities::serialize::*;
 
 /// Stores constraints during index generation.
-pub(crate) struct ConstraintSystem<F: Field> {
+///
+/// A circuit may be synthesized into this constraint system, to inspect the sparsity of its matrices
+/// with `count_nonzeros` before indexing.
+pub struct ConstraintSystem<F: Field> {
     pub(crate) a: Vec<Vec<(F, VarIndex)>>,
     pub(crate) b: Vec<Vec<(F, VarIndex)>>,
     pub(crate) c: Vec<Vec<(F, VarIndex)>>,
@@ -30,8 +36,9 @@ pub(crate) struct ConstraintSystem<F: Field> {
 }
 
 impl<F: Field> ConstraintSystem<F> {
+    /// Initializes an empty constraint system.
     #[inline]
-    pub(crate) fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             a: Vec::new(),
             b: Vec::new(),
@@ -57,6 +64,12 @@ impl<F: Field> ConstraintSystem<F> {
         to_matrix_helper(&self.c, self.num_public_variables)
     }
 
+    /// Returns the number of non-zero entries in the A, B, and C matrices.
+    #[inline]
+    pub fn count_nonzeros(&self) -> (usize, usize, usize) {
+        (num_non_zero(&self.a_matrix()), num_non_zero(&self.b_matrix()), num_non_zero(&self.c_matrix()))
+    }
+
     #[inline]
     pub(crate) fn make_matrices_square(&mut self) {
         let num_variables = self.num_public_variables + self.num_private_variables;
@@ -76,6 +89,12 @@ impl<F: Field> ConstraintSystem<F> {
     }
 }
 
+impl<F: Field> Default for ConstraintSystem<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<F: Field> CS<F> for ConstraintSystem<F> {
     type Root = Self;
 
@@ -38,6 +38,17 @@ use std::collections::BTreeMap;
 use snarkvm_utilities::println;
 
 impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
+    /// Returns the number of non-zero entries in the A, B, and C matrices of this constraint system.
+    ///
+    /// This matches the `num_non_zero_a`, `num_non_zero_b`, and `num_non_zero_c` of the index,
+    /// as padding the matrices does not add non-zero entries, and allows checking that the
+    /// domain sizes are admissible without performing the (expensive) indexing.
+    pub fn count_nonzeros<C: ConstraintSynthesizer<F>>(c: &C) -> Result<(usize, usize, usize), AHPError> {
+        let mut ics = IndexerConstraintSystem::new();
+        c.generate_constraints(&mut ics)?;
+        Ok(ics.count_nonzeros())
+    }
+
     /// Generate the index for this constraint system.
     pub fn index<C: ConstraintSynthesizer<F>>(c: &C) -> Result<Circuit<F, MM>, AHPError> {
         let index_time = start_timer!(|| "AHP::Index");
@@ -23,7 +23,7 @@ mod circuit_info;
 pub(crate) use circuit_info::*;
 
 mod constraint_system;
-pub(crate) use constraint_system::*;
+pub use constraint_system::*;
 
 mod indexer;
 
@@ -85,7 +85,12 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
                 if index.index_info.num_constraints != num_constraints
                     || index.index_info.num_variables != (num_public_variables + num_private_variables)
                 {
-                    return Err(AHPError::InstanceDoesNotMatchIndex);
+                    return Err(AHPError::InstanceDoesNotMatchIndex {
+                        expected_constraints: index.index_info.num_constraints,
+                        found_constraints: num_constraints,
+                        expected_variables: index.index_info.num_variables,
+                        found_variables: num_public_variables + num_private_variables,
+                    });
                 }
 
                 Self::formatted_public_input_is_admissible(&padded_public_variables)?;
@@ -83,7 +83,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let z_time = start_timer!(|| "Compute z poly");
         let z = cfg_iter!(state.first_round_oracles.as_ref().unwrap().batches)
             .zip_eq(batch_combiners)
-            .zip(&state.x_poly)
+            .zip(state.x_polys())
             .map(|((b, &coeff), x_poly)| {
                 let mut z = b.w_poly.polynomial().as_dense().unwrap().mul_by_vanishing_poly(state.input_domain);
                 // Zip safety: `x_poly` is smaller than `z_poly`.
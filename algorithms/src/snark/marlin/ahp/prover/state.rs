@@ -31,6 +31,12 @@ use crate::{
 };
 use snarkvm_fields::PrimeField;
 use snarkvm_r1cs::SynthesisError;
+use snarkvm_utilities::cfg_iter;
+
+use once_cell::sync::OnceCell;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 /// State for the AHP prover.
 pub struct State<'a, F: PrimeField, MM: MarlinMode> {
@@ -69,8 +75,9 @@ pub struct State<'a, F: PrimeField, MM: MarlinMode> {
     pub(super) z_b: Option<Vec<Vec<F>>>,
 
     /// A list of polynomials corresponding to the interpolation of the public input.
+    /// Each polynomial is computed on first use, unless the state was initialized eagerly.
     /// The length of this list must be equal to the batch size.
-    pub(super) x_poly: Vec<DensePolynomial<F>>,
+    x_poly: Vec<OnceCell<DensePolynomial<F>>>,
 
     /// The first round oracles sent by the prover.
     /// The length of this list must be equal to the batch size.
@@ -90,10 +97,25 @@ pub struct State<'a, F: PrimeField, MM: MarlinMode> {
 }
 
 impl<'a, F: PrimeField, MM: MarlinMode> State<'a, F, MM> {
+    /// Initializes the prover state, interpolating the public input of every instance up front.
     pub fn initialize(
         padded_public_input: Vec<Vec<F>>,
         private_variables: Vec<Vec<F>>,
         index: &'a Circuit<F, MM>,
+    ) -> Result<Self, AHPError> {
+        let state = Self::initialize_lazy(padded_public_input, private_variables, index)?;
+        cfg_iter!(state.x_poly).enumerate().for_each(|(instance, x_poly)| {
+            x_poly.get_or_init(|| state.interpolate_x_poly(instance));
+        });
+        Ok(state)
+    }
+
+    /// Initializes the prover state, deferring the interpolation of each public input until it is used.
+    /// This reduces peak memory for large batches when only a few polynomials are needed at a time.
+    pub fn initialize_lazy(
+        padded_public_input: Vec<Vec<F>>,
+        private_variables: Vec<Vec<F>>,
+        index: &'a Circuit<F, MM>,
     ) -> Result<Self, AHPError> {
         let index_info = &index.index_info;
         let constraint_domain =
@@ -109,12 +131,7 @@ impl<'a, F: PrimeField, MM: MarlinMode> State<'a, F, MM> {
         let input_domain =
             EvaluationDomain::new(padded_public_input[0].len()).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
 
-        let x_poly = padded_public_input
-            .iter()
-            .map(|padded_public_input| {
-                EvaluationsOnDomain::from_vec_and_domain(padded_public_input.clone(), input_domain).interpolate()
-            })
-            .collect();
+        let x_poly = padded_public_input.iter().map(|_| OnceCell::new()).collect();
         let batch_size = private_variables.len();
         assert_eq!(padded_public_input.len(), batch_size);
 
@@ -154,6 +171,58 @@ impl<'a, F: PrimeField, MM: MarlinMode> State<'a, F, MM> {
         self.padded_public_variables.clone()
     }
 
+    /// Get the sizes of the domains for the non-zero entries of the A, B, and C matrices.
+    pub fn non_zero_domain_sizes(&self) -> (usize, usize, usize) {
+        (self.non_zero_a_domain.size(), self.non_zero_b_domain.size(), self.non_zero_c_domain.size())
+    }
+
+    /// Get the size of the domain for the constraints.
+    pub fn constraint_domain_size(&self) -> usize {
+        self.constraint_domain.size()
+    }
+
+    /// Get the evaluations of `z_a` for the given instance over the constraint domain.
+    /// This is intended for debugging unsatisfied constraints, and is available after the first round.
+    pub fn evaluate_z_a_on_domain(&self, instance: usize) -> Option<Vec<F>> {
+        let entry = self.first_round_oracles.as_ref()?.batches.get(instance)?;
+        Some(entry.z_a_poly.polynomial().as_dense()?.evaluate_over_domain_by_ref(self.constraint_domain).evaluations)
+    }
+
+    /// Get the evaluations of `z_b` for the given instance over the constraint domain.
+    /// This is intended for debugging unsatisfied constraints, and is available after the first round.
+    pub fn evaluate_z_b_on_domain(&self, instance: usize) -> Option<Vec<F>> {
+        let entry = self.first_round_oracles.as_ref()?.batches.get(instance)?;
+        Some(entry.z_b_poly.polynomial().as_dense()?.evaluate_over_domain_by_ref(self.constraint_domain).evaluations)
+    }
+
+    /// Get the witness assignment for the given instance, i.e. its padded public variables without
+    /// the leading one, followed by its private variables. Prepending the one yields the assignment
+    /// in the order expected by the constraint matrices.
+    pub fn witness_assignment(&self, instance: usize) -> Option<Vec<F>> {
+        let public_variables = self.padded_public_variables.get(instance)?;
+        let private_variables = self.private_variables.get(instance)?;
+        let mut assignment = super::ConstraintSystem::unformat_public_input(public_variables);
+        assignment.extend_from_slice(private_variables);
+        Some(assignment)
+    }
+
+    /// Get the interpolation of the padded public input for the given instance, computing it if necessary.
+    pub fn x_poly(&self, instance: usize) -> Option<&DensePolynomial<F>> {
+        let x_poly = self.x_poly.get(instance)?;
+        Some(x_poly.get_or_init(|| self.interpolate_x_poly(instance)))
+    }
+
+    /// Get the interpolations of the padded public inputs for the entire batch, computing them if necessary.
+    pub(super) fn x_polys(&self) -> Vec<&DensePolynomial<F>> {
+        (0..self.batch_size).filter_map(|instance| self.x_poly(instance)).collect()
+    }
+
+    /// Interpolates the padded public input for the given instance over the input domain.
+    fn interpolate_x_poly(&self, instance: usize) -> DensePolynomial<F> {
+        let padded_public_input = self.padded_public_variables[instance].clone();
+        EvaluationsOnDomain::from_vec_and_domain(padded_public_input, self.input_domain).interpolate()
+    }
+
     pub fn fft_precomputation(&self) -> &FFTPrecomputation<F> {
         &self.index.fft_precomputation
     }
@@ -162,3 +231,183 @@ impl<'a, F: PrimeField, MM: MarlinMode> State<'a, F, MM> {
         &self.index.ifft_precomputation
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fft::EvaluationDomain,
+        snark::marlin::{
+            ahp::{prover::State, AHPForR1CS},
+            tests::Circuit,
+            MarlinHidingMode,
+        },
+    };
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::rand::{test_rng, UniformRand};
+
+    #[test]
+    fn test_domain_sizes() {
+        let rng = &mut test_rng();
+
+        for (num_constraints, num_variables) in [(3, 3), (25, 25), (100, 25), (300, 120)] {
+            let circuit = Circuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables };
+
+            let index = AHPForR1CS::<Fr, MarlinHidingMode>::index(&circuit).unwrap();
+            let state = AHPForR1CS::init_prover(&index, &[circuit]).unwrap();
+
+            // Ensure the reported sizes are the non-zero counts rounded up to the domain size.
+            let info = &index.index_info;
+            let domain_size = |num_elements| EvaluationDomain::<Fr>::compute_size_of_domain(num_elements).unwrap();
+            let expected = (
+                domain_size(info.num_non_zero_a),
+                domain_size(info.num_non_zero_b),
+                domain_size(info.num_non_zero_c),
+            );
+            assert_eq!(expected, state.non_zero_domain_sizes());
+            assert_eq!(domain_size(info.num_constraints), state.constraint_domain_size());
+
+            // Ensure each domain is a power of two that fits its number of elements.
+            for (size, num_elements) in [
+                (expected.0, info.num_non_zero_a),
+                (expected.1, info.num_non_zero_b),
+                (expected.2, info.num_non_zero_c),
+                (state.constraint_domain_size(), info.num_constraints),
+            ] {
+                assert!(size.is_power_of_two());
+                assert!(size >= num_elements);
+                assert!(size < 2 * num_elements);
+            }
+        }
+    }
+
+    #[test]
+    fn test_evaluate_z_on_domain() {
+        let rng = &mut test_rng();
+
+        for (num_constraints, num_variables) in [(3, 3), (25, 25), (100, 25)] {
+            let circuits = (0..2)
+                .map(|_| Circuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables })
+                .collect::<Vec<_>>();
+
+            let index = AHPForR1CS::<Fr, MarlinHidingMode>::index(&circuits[0]).unwrap();
+            let state = AHPForR1CS::init_prover(&index, &circuits).unwrap();
+
+            // Compute `A * z` and `B * z` directly, for each instance.
+            let mut expected = vec![];
+            for (public, private) in state.padded_public_variables.iter().zip(&state.private_variables) {
+                let z = public.iter().chain(private).collect::<Vec<_>>();
+                let product = |matrix: &[Vec<(Fr, usize)>]| {
+                    let mut evaluations = matrix
+                        .iter()
+                        .map(|row| row.iter().map(|(coefficient, index)| *coefficient * z[*index]).sum::<Fr>())
+                        .collect::<Vec<_>>();
+                    evaluations.resize(state.constraint_domain_size(), Fr::zero());
+                    evaluations
+                };
+                expected.push((product(&index.a), product(&index.b)));
+            }
+
+            // Ensure the evaluations are unavailable before the first round.
+            assert!(state.evaluate_z_a_on_domain(0).is_none());
+            assert!(state.evaluate_z_b_on_domain(0).is_none());
+
+            let state = AHPForR1CS::prover_first_round(state, rng).unwrap();
+
+            // Ensure the evaluations over the constraint domain match.
+            for (instance, (expected_z_a, expected_z_b)) in expected.iter().enumerate() {
+                assert_eq!(expected_z_a, &state.evaluate_z_a_on_domain(instance).unwrap());
+                assert_eq!(expected_z_b, &state.evaluate_z_b_on_domain(instance).unwrap());
+            }
+            assert!(state.evaluate_z_a_on_domain(expected.len()).is_none());
+            assert!(state.evaluate_z_b_on_domain(expected.len()).is_none());
+        }
+    }
+
+    #[test]
+    fn test_witness_assignment() {
+        let rng = &mut test_rng();
+
+        for (num_constraints, num_variables) in [(3, 3), (25, 25), (100, 25)] {
+            let circuits = (0..2)
+                .map(|_| Circuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables })
+                .collect::<Vec<_>>();
+
+            let index = AHPForR1CS::<Fr, MarlinHidingMode>::index(&circuits[0]).unwrap();
+            let state = AHPForR1CS::init_prover(&index, &circuits).unwrap();
+
+            for (instance, circuit) in circuits.iter().enumerate() {
+                let assignment = state.witness_assignment(instance).unwrap();
+                assert_eq!(index.index_info.num_variables - 1, assignment.len());
+
+                // Ensure the public inputs of the circuit lead the assignment.
+                let c = circuit.a.unwrap() * circuit.b.unwrap();
+                assert_eq!([c, c * circuit.b.unwrap()], assignment[..2]);
+
+                // Ensure the assignment, with the leading one, satisfies every constraint.
+                let z = [vec![Fr::one()], assignment].concat();
+                let product = |row: &Vec<(Fr, usize)>| {
+                    row.iter().map(|(coefficient, index)| *coefficient * z[*index]).sum::<Fr>()
+                };
+                for ((a, b), c) in index.a.iter().zip(&index.b).zip(&index.c) {
+                    assert_eq!(product(a) * product(b), product(c));
+                }
+            }
+            assert!(state.witness_assignment(circuits.len()).is_none());
+        }
+    }
+
+    #[test]
+    fn test_x_poly_lazy() {
+        let rng = &mut test_rng();
+
+        for (num_constraints, num_variables) in [(3, 3), (25, 25), (100, 25)] {
+            let circuits = (0..3)
+                .map(|_| Circuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables })
+                .collect::<Vec<_>>();
+
+            let index = AHPForR1CS::<Fr, MarlinHidingMode>::index(&circuits[0]).unwrap();
+            let eager = AHPForR1CS::init_prover(&index, &circuits).unwrap();
+            let lazy =
+                State::initialize_lazy(eager.padded_public_inputs(), eager.private_variables.clone(), &index).unwrap();
+
+            // Ensure the eager polynomials are computed up front, and the lazy ones are not.
+            assert!(eager.x_poly.iter().all(|x_poly| x_poly.get().is_some()));
+            assert!(lazy.x_poly.iter().all(|x_poly| x_poly.get().is_none()));
+
+            // Ensure the lazy polynomial is computed on demand and cached, and matches the eager one.
+            assert_eq!(eager.x_poly(1), lazy.x_poly(1));
+            assert!(lazy.x_poly[0].get().is_none());
+            assert!(lazy.x_poly[1].get().is_some());
+            assert_eq!(eager.x_polys(), lazy.x_polys());
+            assert!(lazy.x_poly(circuits.len()).is_none());
+        }
+    }
+
+    #[test]
+    fn test_lagrange_coefficients_evaluate_x_poly() {
+        let rng = &mut test_rng();
+
+        for (num_constraints, num_variables) in [(3, 3), (25, 25), (100, 25)] {
+            let circuits = (0..2)
+                .map(|_| Circuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables })
+                .collect::<Vec<_>>();
+
+            let index = AHPForR1CS::<Fr, MarlinHidingMode>::index(&circuits[0]).unwrap();
+            let state = AHPForR1CS::init_prover(&index, &circuits).unwrap();
+
+            // Sample points outside and inside of the input domain.
+            let points = [state.input_domain.sample_element_outside_domain(rng), state.input_domain.group_gen];
+            for (instance, padded_public_input) in state.padded_public_variables.iter().enumerate() {
+                let x_poly = state.x_poly(instance).unwrap();
+                for point in points {
+                    // Ensure the Lagrange coefficients, dotted with the padded public input, evaluate the `x_poly`.
+                    let coefficients = state.input_domain.evaluate_all_lagrange_coefficients(point);
+                    assert_eq!(state.input_domain.size(), coefficients.len());
+                    let candidate = coefficients.iter().zip(padded_public_input).map(|(l, x)| *l * x).sum::<Fr>();
+                    assert_eq!(x_poly.evaluate(point), candidate);
+                }
+            }
+        }
+    }
+}
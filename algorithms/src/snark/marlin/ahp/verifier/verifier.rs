@@ -44,16 +44,16 @@ impl<TargetField: PrimeField, MM: MarlinMode> AHPForR1CS<TargetField, MM> {
             return Err(AHPError::NonSquareMatrix);
         }
 
-        let constraint_domain =
-            EvaluationDomain::new(index_info.num_constraints).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        let constraint_domain = EvaluationDomain::new(index_info.num_constraints)
+            .ok_or(AHPError::PolynomialDegreeTooLarge(index_info.num_constraints))?;
 
-        let non_zero_a_domain =
-            EvaluationDomain::new(index_info.num_non_zero_a).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        let non_zero_a_domain = EvaluationDomain::new(index_info.num_non_zero_a)
+            .ok_or(AHPError::PolynomialDegreeTooLarge(index_info.num_non_zero_a))?;
 
-        let non_zero_b_domain =
-            EvaluationDomain::new(index_info.num_non_zero_b).ok_or(AHPError::PolynomialDegreeTooLarge)?;
-        let non_zero_c_domain =
-            EvaluationDomain::new(index_info.num_non_zero_c).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        let non_zero_b_domain = EvaluationDomain::new(index_info.num_non_zero_b)
+            .ok_or(AHPError::PolynomialDegreeTooLarge(index_info.num_non_zero_b))?;
+        let non_zero_c_domain = EvaluationDomain::new(index_info.num_non_zero_c)
+            .ok_or(AHPError::PolynomialDegreeTooLarge(index_info.num_non_zero_c))?;
 
         let elems = fs_rng.squeeze_nonnative_field_elements(3 + batch_size - 1, OptimizationType::Weight)?;
         let (first, rest) = elems.split_at(3);
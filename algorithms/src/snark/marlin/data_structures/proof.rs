@@ -66,8 +66,7 @@ impl<E: PairingEngine> Commitments<E> {
 
     fn serialized_size(&self, compress: Compress) -> usize {
         let mut size = 0;
-        size += self.witness_commitments.len()
-            * CanonicalSerialize::serialized_size(&self.witness_commitments[0], compress);
+        size += self.witness_commitments.iter().map(|comm| comm.serialized_size(compress)).sum::<usize>();
         size += CanonicalSerialize::serialized_size(&self.mask_poly, compress);
         size += CanonicalSerialize::serialized_size(&self.g_1, compress);
         size += CanonicalSerialize::serialized_size(&self.h_1, compress);
@@ -157,7 +156,8 @@ impl<F: PrimeField> Evaluations<F> {
         compress: Compress,
         validate: Validate,
     ) -> Result<Self, snarkvm_utilities::SerializationError> {
-        let mut z_b_evals = Vec::with_capacity(batch_size);
+        // Note: The batch size is untrusted, so the evaluations are not preallocated.
+        let mut z_b_evals = Vec::new();
         for _ in 0..batch_size {
             z_b_evals.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
         }
@@ -301,4 +301,15 @@ impl<E: PairingEngine> FromBytes for Proof<E> {
     fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
         Self::deserialize_compressed(&mut r).map_err(|_| error("could not deserialize Proof"))
     }
+
+    /// Returns the proof from its compressed byte representation,
+    /// ensuring the bytes do not contain any trailing data.
+    fn from_bytes_le(bytes: &[u8]) -> anyhow::Result<Self> {
+        let mut reader = bytes;
+        let proof = Self::read_le(&mut reader)?;
+        match reader.is_empty() {
+            true => Ok(proof),
+            false => anyhow::bail!("could not deserialize Proof: found {} trailing bytes", reader.len()),
+        }
+    }
 }
@@ -36,3 +36,11 @@ pub struct MarlinNonHidingMode;
 impl MarlinMode for MarlinNonHidingMode {
     const ZK: bool = false;
 }
+
+/// The Marlin unsafe no-zk mode is an alias of [`MarlinNonHidingMode`], for benchmarking and CI.
+///
+/// # Warning
+/// This mode does **not** blind the prover polynomials, and the resulting proofs (while still valid)
+/// **leak information about the witness**. It must never be used in production.
+#[cfg(feature = "unsafe-no-zk")]
+pub type MarlinUnsafeNoZKMode = MarlinNonHidingMode;
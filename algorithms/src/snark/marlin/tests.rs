@@ -292,6 +292,205 @@ mod marlin {
     }
 }
 
+#[cfg(feature = "unsafe-no-zk")]
+mod marlin_unsafe_no_zk {
+    use super::*;
+    use crate::snark::marlin::{
+        fiat_shamir::FiatShamirChaChaRng,
+        AHPForR1CS,
+        MarlinSNARK,
+        MarlinUnsafeNoZKMode,
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_utilities::{
+        rand::{test_crypto_rng, UniformRand},
+        ToBytes,
+    };
+
+    use blake2::Blake2s256;
+    use core::ops::MulAssign;
+
+    type MarlinInst = MarlinSNARK<Bls12_377, FiatShamirChaChaRng<Fr, Fq, Blake2s256>, MarlinUnsafeNoZKMode, [Fr]>;
+
+    #[test]
+    fn prove_and_verify_without_zk_is_deterministic() {
+        // Ensure the unsafe no-zk mode does not blind the prover polynomials.
+        assert!(AHPForR1CS::<Fr, MarlinUnsafeNoZKMode>::zk_bound().is_none());
+
+        let rng = &mut test_crypto_rng();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinUnsafeNoZKMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(&max_degree, rng).unwrap();
+
+        for _ in 0..10 {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let mut c = a;
+            c.mul_assign(&b);
+            let mut d = c;
+            d.mul_assign(&b);
+
+            let circ = Circuit { a: Some(a), b: Some(b), num_constraints: 100, num_variables: 25 };
+            let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circ).unwrap();
+
+            // Prove twice, with independently-seeded RNGs.
+            let proof = MarlinInst::prove(&index_pk, &circ, &mut test_crypto_rng()).unwrap();
+            let candidate = MarlinInst::prove(&index_pk, &circ, &mut test_crypto_rng()).unwrap();
+
+            // Ensure the proof verifies, and is deterministic.
+            assert!(MarlinInst::verify(&index_vk, [c, d], &proof).unwrap());
+            assert_eq!(proof.to_bytes_le().unwrap(), candidate.to_bytes_le().unwrap());
+        }
+    }
+}
+
+mod marlin_fft_precomputation {
+    use super::*;
+    use crate::snark::marlin::{
+        fiat_shamir::FiatShamirChaChaRng,
+        AHPForR1CS,
+        MarlinNonHidingMode,
+        MarlinSNARK,
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_utilities::{
+        rand::{test_crypto_rng, UniformRand},
+        ToBytes,
+    };
+
+    use blake2::Blake2s256;
+    use core::ops::MulAssign;
+    use std::sync::Arc;
+
+    type MarlinInst = MarlinSNARK<Bls12_377, FiatShamirChaChaRng<Fr, Fq, Blake2s256>, MarlinNonHidingMode, [Fr]>;
+
+    #[test]
+    fn indices_with_equal_domains_share_fft_precomputation() {
+        let rng = &mut test_crypto_rng();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinNonHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(&max_degree, rng).unwrap();
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let mut d = c;
+        d.mul_assign(&b);
+
+        let circ = Circuit { a: Some(a), b: Some(b), num_constraints: 100, num_variables: 25 };
+        let (first_pk, first_vk) = MarlinInst::circuit_setup(&universal_srs, &circ).unwrap();
+        let (second_pk, second_vk) = MarlinInst::circuit_setup(&universal_srs, &circ).unwrap();
+
+        // Ensure both indices share the same precomputation.
+        assert!(Arc::ptr_eq(&first_pk.circuit.fft_precomputation, &second_pk.circuit.fft_precomputation));
+        assert!(Arc::ptr_eq(&first_pk.circuit.ifft_precomputation, &second_pk.circuit.ifft_precomputation));
+
+        // Prove with each index, with independently-seeded RNGs, in the non-hiding mode for deterministic proofs.
+        let first_proof = MarlinInst::prove(&first_pk, &circ, &mut test_crypto_rng()).unwrap();
+        let second_proof = MarlinInst::prove(&second_pk, &circ, &mut test_crypto_rng()).unwrap();
+
+        // Ensure the proofs verify, and are identical.
+        assert!(MarlinInst::verify(&first_vk, [c, d], &first_proof).unwrap());
+        assert!(MarlinInst::verify(&second_vk, [c, d], &second_proof).unwrap());
+        assert_eq!(first_proof.to_bytes_le().unwrap(), second_proof.to_bytes_le().unwrap());
+    }
+}
+
+mod marlin_count_nonzeros {
+    use super::*;
+    use crate::snark::marlin::{ahp::indexer::ConstraintSystem as IndexerConstraintSystem, AHPForR1CS, MarlinHidingMode};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::rand::{test_crypto_rng, UniformRand};
+
+    #[test]
+    fn count_nonzeros_matches_index_info() {
+        let rng = &mut test_crypto_rng();
+
+        for (num_constraints, num_variables) in [(10, 5), (25, 100), (100, 25)] {
+            let circ = Circuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables };
+
+            // Ensure the counts match the index, which pads the matrices.
+            let counts = AHPForR1CS::<Fr, MarlinHidingMode>::count_nonzeros(&circ).unwrap();
+            let index_info = AHPForR1CS::<Fr, MarlinHidingMode>::index(&circ).unwrap().index_info;
+            assert_eq!((index_info.num_non_zero_a, index_info.num_non_zero_b, index_info.num_non_zero_c), counts);
+
+            // Ensure the counts match the circuit, which has one entry in each row of each matrix.
+            assert_eq!((num_constraints, num_constraints, num_constraints), counts);
+
+            // Ensure the counts match a constraint system that the circuit is synthesized into directly.
+            let mut cs = IndexerConstraintSystem::new();
+            circ.generate_constraints(&mut cs).unwrap();
+            assert_eq!(counts, cs.count_nonzeros());
+        }
+    }
+}
+
+mod marlin_proof_bytes {
+    use super::*;
+    use crate::snark::marlin::{
+        fiat_shamir::FiatShamirChaChaRng,
+        AHPForR1CS,
+        MarlinHidingMode,
+        MarlinSNARK,
+        Proof,
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_utilities::{
+        rand::{test_crypto_rng, UniformRand},
+        serialize::CanonicalSerialize,
+        FromBytes,
+        ToBytes,
+    };
+
+    use blake2::Blake2s256;
+    use core::ops::MulAssign;
+
+    type MarlinInst = MarlinSNARK<Bls12_377, FiatShamirChaChaRng<Fr, Fq, Blake2s256>, MarlinHidingMode, [Fr]>;
+
+    #[test]
+    fn proof_bytes_round_trip() {
+        let rng = &mut test_crypto_rng();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(&max_degree, rng).unwrap();
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let mut d = c;
+        d.mul_assign(&b);
+
+        let circ = Circuit { a: Some(a), b: Some(b), num_constraints: 100, num_variables: 25 };
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circ).unwrap();
+        let proof = MarlinInst::prove(&index_pk, &circ, rng).unwrap();
+
+        // Ensure the proof round-trips, and the commitments are compressed.
+        let bytes = proof.to_bytes_le().unwrap();
+        assert_eq!(proof.compressed_size(), bytes.len());
+        assert!(bytes.len() < proof.uncompressed_size());
+        let candidate = Proof::<Bls12_377>::from_bytes_le(&bytes).unwrap();
+        assert_eq!(proof, candidate);
+        assert!(MarlinInst::verify(&index_vk, [c, d], &candidate).unwrap());
+
+        // Ensure every truncation of the bytes yields an error, instead of panicking.
+        for length in 0..bytes.len() {
+            assert!(Proof::<Bls12_377>::from_bytes_le(&bytes[..length]).is_err(), "Truncated to {length} bytes");
+        }
+
+        // Ensure trailing bytes yield an error.
+        let mut extended = bytes.clone();
+        extended.push(0u8);
+        assert!(Proof::<Bls12_377>::from_bytes_le(&extended).is_err());
+
+        // Ensure a malformed batch size yields an error, instead of panicking.
+        let mut malformed = bytes;
+        malformed[..8].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(Proof::<Bls12_377>::from_bytes_le(&malformed).is_err());
+    }
+}
+
 mod marlin_recursion {
     use super::*;
     use crate::{
@@ -32,6 +32,12 @@ pub struct Member<P: Program> {
 }
 
 impl<P: Program> Member<P> {
+    /// Initializes a new member with the given name and annotation.
+    #[inline]
+    pub(crate) fn new(name: Identifier<P>, annotation: Annotation<P>) -> Self {
+        Self { name, annotation }
+    }
+
     /// Returns the name of the member.
     #[inline]
     pub fn name(&self) -> &Identifier<P> {
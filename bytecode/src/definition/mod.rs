@@ -15,8 +15,9 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod member;
+pub(crate) use member::Member;
 
-use crate::{definition::member::Member, Annotation, Identifier, Program, Sanitizer, Value};
+use crate::{Annotation, Identifier, Program, Sanitizer, Value};
 use snarkvm_circuit::prelude::*;
 use snarkvm_utilities::{error, has_duplicates, FromBytes, ToBytes};
 
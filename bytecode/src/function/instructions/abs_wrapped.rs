@@ -78,6 +78,11 @@ impl<P: Program> Operation<P> for AbsWrapped<P> {
 
         registers.assign(self.operation.destination(), result);
     }
+
+    #[inline]
+    fn can_halt() -> bool {
+        false
+    }
 }
 
 impl<P: Program> Parser for AbsWrapped<P> {
@@ -83,6 +83,11 @@ impl<P: Program> Operation<P> for And<P> {
 
         registers.assign(self.operation.destination(), result);
     }
+
+    #[inline]
+    fn can_halt() -> bool {
+        false
+    }
 }
 
 impl<P: Program> Parser for And<P> {
@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Casts a field element into an integer, halting if the field element exceeds the range of the integer.
+pub type CastChecked<P> = Cast<P, Checked>;
+
+pub struct Checked;
+impl CastOpcode for Checked {
+    const OPCODE: &'static str = "cast";
+    const CAN_HALT: bool = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{function::instructions::cast::tests::check_cast, Process};
+    use snarkvm_circuit::AleoV0 as Circuit;
+
+    type P = Process;
+
+    /// Evaluates `cast r0 into r1 as {cast_type}` on the given input, returning `true` if the circuit is satisfied.
+    fn is_satisfied(input: &str, cast_type: &str) -> bool {
+        let registers = Registers::<P>::default();
+        registers.define(&Register::from_str("r0"));
+        registers.define(&Register::from_str("r1"));
+        registers.assign(&Register::from_str("r0"), Value::<P>::from_str(input));
+
+        CastChecked::<P>::from_str(&format!("r0 into r1 as {cast_type}")).evaluate(&registers);
+        let is_satisfied = Circuit::is_satisfied();
+        Circuit::reset();
+        is_satisfied
+    }
+
+    #[test]
+    fn test_parse() {
+        let (_, instruction) = Instruction::<P>::parse("cast r0 into r1 as u8;").unwrap();
+        assert!(matches!(instruction, Instruction::CastChecked(_)));
+    }
+
+    #[test]
+    fn test_in_range() {
+        check_cast::<Checked>("0field.constant", "u8", "0u8.constant");
+        check_cast::<Checked>("1field.public", "u16", "1u16.private");
+        check_cast::<Checked>("255field.constant", "u8", "255u8.constant");
+        check_cast::<Checked>("127field.private", "i8", "127i8.private");
+        check_cast::<Checked>("-1field.private", "i8", "-1i8.private");
+        check_cast::<Checked>("-128field.constant", "i8", "-128i8.constant");
+        check_cast::<Checked>("-32768field.public", "i16", "-32768i16.private");
+        check_cast::<Checked>(
+            "-170141183460469231731687303715884105728field.private",
+            "i128",
+            "-170141183460469231731687303715884105728i128.private",
+        );
+        check_cast::<Checked>("65535field.public", "u16", "65535u16.private");
+        check_cast::<Checked>("4294967295field.private", "u32", "4294967295u32.private");
+        check_cast::<Checked>("18446744073709551615field.constant", "u64", "18446744073709551615u64.constant");
+        check_cast::<Checked>(
+            "340282366920938463463374607431768211455field.private",
+            "u128",
+            "340282366920938463463374607431768211455u128.private",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Field element exceeds the range of 'u8'")]
+    fn test_u8_out_of_range_halts() {
+        check_cast::<Checked>("256field.constant", "u8", "0u8.constant");
+    }
+
+    #[test]
+    #[should_panic(expected = "Field element exceeds the range of 'i16'")]
+    fn test_i16_out_of_range_halts() {
+        check_cast::<Checked>("65536field.constant", "i16", "0i16.constant");
+    }
+
+    #[test]
+    #[should_panic(expected = "Field element exceeds the range of 'u128'")]
+    fn test_u128_out_of_range_halts() {
+        check_cast::<Checked>("340282366920938463463374607431768211456field.constant", "u128", "0u128.constant");
+    }
+
+    #[test]
+    #[should_panic(expected = "Field element exceeds the range of 'i8'")]
+    fn test_i8_above_max_halts() {
+        check_cast::<Checked>("128field.constant", "i8", "0i8.constant");
+    }
+
+    #[test]
+    #[should_panic(expected = "Field element exceeds the range of 'i8'")]
+    fn test_i8_below_min_halts() {
+        check_cast::<Checked>("-129field.constant", "i8", "0i8.constant");
+    }
+
+    #[test]
+    fn test_out_of_range_is_unsatisfied() {
+        for mode in ["public", "private"] {
+            // Ensure the bounds of the integer types are accepted.
+            assert!(is_satisfied(&format!("255field.{mode}"), "u8"));
+            assert!(is_satisfied(&format!("127field.{mode}"), "i8"));
+            assert!(is_satisfied(&format!("-128field.{mode}"), "i8"));
+            assert!(is_satisfied(&format!("-9223372036854775808field.{mode}"), "i64"));
+
+            // Ensure field elements beyond the bounds of the integer types are rejected.
+            assert!(!is_satisfied(&format!("256field.{mode}"), "u8"));
+            assert!(!is_satisfied(&format!("-1field.{mode}"), "u8"));
+            assert!(!is_satisfied(&format!("128field.{mode}"), "i8"));
+            assert!(!is_satisfied(&format!("255field.{mode}"), "i8"));
+            assert!(!is_satisfied(&format!("-129field.{mode}"), "i8"));
+            assert!(!is_satisfied(&format!("9223372036854775808field.{mode}"), "i64"));
+            assert!(!is_satisfied(&format!("-9223372036854775809field.{mode}"), "i64"));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a field")]
+    fn test_non_field_halts() {
+        check_cast::<Checked>("1u8.constant", "u8", "1u8.constant");
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Casts a field element into an integer, reducing the field element modulo `2^BITS` of the integer.
+pub type CastLossy<P> = Cast<P, Lossy>;
+
+pub struct Lossy;
+impl CastOpcode for Lossy {
+    const OPCODE: &'static str = "cast.lossy";
+    const CAN_HALT: bool = false;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{function::instructions::cast::tests::check_cast, Process};
+
+    type P = Process;
+
+    #[test]
+    fn test_parse() {
+        let (_, instruction) = Instruction::<P>::parse("cast.lossy r0 into r1 as u8;").unwrap();
+        assert!(matches!(instruction, Instruction::CastLossy(_)));
+    }
+
+    #[test]
+    fn test_in_range() {
+        check_cast::<Lossy>("0field.constant", "u8", "0u8.constant");
+        check_cast::<Lossy>("255field.public", "u8", "255u8.private");
+        check_cast::<Lossy>("255field.private", "i8", "-1i8.private");
+        check_cast::<Lossy>("65535field.constant", "u16", "65535u16.constant");
+    }
+
+    #[test]
+    fn test_boundary() {
+        check_cast::<Lossy>("256field.constant", "u8", "0u8.constant");
+        check_cast::<Lossy>("257field.public", "u8", "1u8.private");
+        check_cast::<Lossy>("384field.private", "i8", "-128i8.private");
+        check_cast::<Lossy>("65536field.private", "u16", "0u16.private");
+        check_cast::<Lossy>("4294967296field.constant", "i32", "0i32.constant");
+        check_cast::<Lossy>("18446744073709551617field.public", "u64", "1u64.private");
+        check_cast::<Lossy>("340282366920938463463374607431768211456field.private", "u128", "0u128.private");
+        check_cast::<Lossy>("340282366920938463463374607431768211457field.constant", "i128", "1i128.constant");
+    }
+}
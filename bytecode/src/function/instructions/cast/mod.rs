@@ -0,0 +1,330 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+pub(super) mod checked;
+pub(super) use checked::*;
+
+pub(super) mod lossy;
+pub(super) use lossy::*;
+
+use crate::{
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    Program,
+    Value,
+};
+use snarkvm_circuit::{
+    Eject,
+    Environment,
+    Field,
+    FromBits,
+    Inject,
+    Literal,
+    Parser,
+    ParserResult,
+    ToLowerBits,
+    TypeName,
+    I128,
+    I16,
+    I32,
+    I64,
+    I8,
+    U128,
+    U16,
+    U32,
+    U64,
+    U8,
+};
+use snarkvm_utilities::{error, FromBytes, ToBits, ToBytes};
+
+use core::{fmt, marker::PhantomData};
+use nom::{branch::alt, bytes::complete::tag, combinator::map, sequence::preceded};
+use std::io::{Read, Result as IoResult, Write};
+
+pub trait CastOpcode {
+    const OPCODE: &'static str;
+    /// Whether the cast halts if the field element exceeds the range of the integer.
+    const CAN_HALT: bool;
+}
+
+/// The integer type that a field element is cast into.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CastType {
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+}
+
+impl CastType {
+    /// Returns the type name of the integer type.
+    fn type_name<E: Environment>(&self) -> &'static str {
+        match self {
+            Self::I8 => I8::<E>::type_name(),
+            Self::I16 => I16::<E>::type_name(),
+            Self::I32 => I32::<E>::type_name(),
+            Self::I64 => I64::<E>::type_name(),
+            Self::I128 => I128::<E>::type_name(),
+            Self::U8 => U8::<E>::type_name(),
+            Self::U16 => U16::<E>::type_name(),
+            Self::U32 => U32::<E>::type_name(),
+            Self::U64 => U64::<E>::type_name(),
+            Self::U128 => U128::<E>::type_name(),
+        }
+    }
+
+    /// Returns `true` if the integer type is signed.
+    fn is_signed(&self) -> bool {
+        matches!(self, Self::I8 | Self::I16 | Self::I32 | Self::I64 | Self::I128)
+    }
+}
+
+/// A generic cast instruction, converting a field element into an integer.
+pub struct Cast<P: Program, Op: CastOpcode> {
+    operation: UnaryOperation<P>,
+    cast_type: CastType,
+    _phantom: PhantomData<Op>,
+}
+
+impl<P: Program, Op: CastOpcode> Opcode for Cast<P, Op> {
+    /// Returns the opcode as a string.
+    #[inline]
+    fn opcode() -> &'static str {
+        Op::OPCODE
+    }
+}
+
+impl<P: Program, Op: CastOpcode> Cast<P, Op> {
+    /// Returns the operands of the instruction.
+    pub fn operands(&self) -> Vec<Operand<P>> {
+        self.operation.operands()
+    }
+
+    /// Returns the destination register of the instruction.
+    pub fn destination(&self) -> &Register<P> {
+        self.operation.destination()
+    }
+
+    /// Returns the integer type to cast into.
+    pub fn cast_type(&self) -> CastType {
+        self.cast_type
+    }
+}
+
+impl<P: Program, Op: CastOpcode> Operation<P> for Cast<P, Op> {
+    /// Evaluates the operation.
+    #[inline]
+    fn evaluate(&self, registers: &Registers<P>) {
+        // Load the field element from the operand.
+        let field = match registers.load(self.operation.first()) {
+            Value::Literal(Literal::Field(field)) => field,
+            Value::Literal(literal) => {
+                P::halt(format!("Invalid '{}' instruction: expected a field, found {literal}", Self::opcode()))
+            }
+            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+        };
+
+        // Casts the field element into the given integer type.
+        macro_rules! cast {
+            ($integer:ident) => {{
+                match Self::opcode() {
+                    Checked::OPCODE => {
+                        let num_bits = $integer::<P::Environment>::size_in_bits() as usize;
+                        // For signed integers, offset the field element by `2^(num_bits - 1)`,
+                        // such that the range `MIN..=MAX` maps onto the range `0..2^num_bits`.
+                        let field = match self.cast_type.is_signed() {
+                            true => field + Field::constant((1u128 << (num_bits - 1)).into()),
+                            false => field,
+                        };
+                        // Ensure the constant field element is within the range of the integer type.
+                        if field.is_constant() && field.eject_value().to_bits_le().iter().skip(num_bits).any(|bit| *bit)
+                        {
+                            P::halt(format!(
+                                "Field element exceeds the range of '{}' in the '{}' instruction",
+                                self.cast_type.type_name::<P::Environment>(),
+                                Self::opcode()
+                            ))
+                        }
+                        // Extract the lower bits of the field element, enforcing the upper bits are zero.
+                        let mut bits_le = field.to_lower_bits_le(num_bits);
+                        // For signed integers, flip the most significant bit to remove the offset.
+                        if self.cast_type.is_signed() {
+                            bits_le[num_bits - 1] = !&bits_le[num_bits - 1];
+                        }
+                        Literal::$integer($integer::from_bits_le(&bits_le))
+                    }
+                    Lossy::OPCODE => Literal::$integer($integer::from_field_lossy(&field)),
+                    _ => P::halt("Invalid option provided for the `cast` instruction"),
+                }
+            }};
+        }
+
+        let result = match self.cast_type {
+            CastType::I8 => cast!(I8),
+            CastType::I16 => cast!(I16),
+            CastType::I32 => cast!(I32),
+            CastType::I64 => cast!(I64),
+            CastType::I128 => cast!(I128),
+            CastType::U8 => cast!(U8),
+            CastType::U16 => cast!(U16),
+            CastType::U32 => cast!(U32),
+            CastType::U64 => cast!(U64),
+            CastType::U128 => cast!(U128),
+        };
+
+        registers.assign(self.operation.destination(), result);
+    }
+
+    /// Returns `true` if the cast halts on a field element that exceeds the range of the integer.
+    #[inline]
+    fn can_halt() -> bool {
+        Op::CAN_HALT
+    }
+}
+
+impl<P: Program, Op: CastOpcode> Parser for Cast<P, Op> {
+    type Environment = P::Environment;
+
+    /// Parses a string into a cast operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the operation from the string.
+        let (string, operation) = UnaryOperation::parse(string)?;
+        // Parse the " as " and the integer type from the string.
+        let (string, cast_type) = preceded(
+            tag(" as "),
+            alt((
+                map(tag(I8::<P::Environment>::type_name()), |_| CastType::I8),
+                map(tag(I16::<P::Environment>::type_name()), |_| CastType::I16),
+                map(tag(I32::<P::Environment>::type_name()), |_| CastType::I32),
+                map(tag(I64::<P::Environment>::type_name()), |_| CastType::I64),
+                map(tag(I128::<P::Environment>::type_name()), |_| CastType::I128),
+                map(tag(U8::<P::Environment>::type_name()), |_| CastType::U8),
+                map(tag(U16::<P::Environment>::type_name()), |_| CastType::U16),
+                map(tag(U32::<P::Environment>::type_name()), |_| CastType::U32),
+                map(tag(U64::<P::Environment>::type_name()), |_| CastType::U64),
+                map(tag(U128::<P::Environment>::type_name()), |_| CastType::U128),
+            )),
+        )(string)?;
+
+        Ok((string, Self { operation, cast_type, _phantom: PhantomData }))
+    }
+}
+
+impl<P: Program, Op: CastOpcode> fmt::Display for Cast<P, Op> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} as {}", self.operation, self.cast_type.type_name::<P::Environment>())
+    }
+}
+
+impl<P: Program, Op: CastOpcode> FromBytes for Cast<P, Op> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let operation = UnaryOperation::read_le(&mut reader)?;
+        let cast_type = match u8::read_le(&mut reader)? {
+            0 => CastType::I8,
+            1 => CastType::I16,
+            2 => CastType::I32,
+            3 => CastType::I64,
+            4 => CastType::I128,
+            5 => CastType::U8,
+            6 => CastType::U16,
+            7 => CastType::U32,
+            8 => CastType::U64,
+            9 => CastType::U128,
+            variant => return Err(error(format!("Failed to deserialize cast type variant {variant}"))),
+        };
+        Ok(Self { operation, cast_type, _phantom: PhantomData })
+    }
+}
+
+impl<P: Program, Op: CastOpcode> ToBytes for Cast<P, Op> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.operation.write_le(&mut writer)?;
+        (self.cast_type as u8).write_le(&mut writer)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<P: Program, Op: CastOpcode> Into<Instruction<P>> for Cast<P, Op> {
+    /// Converts the operation into an instruction.
+    fn into(self) -> Instruction<P> {
+        match Self::opcode() {
+            Checked::OPCODE => Instruction::CastChecked(CastChecked {
+                operation: self.operation,
+                cast_type: self.cast_type,
+                _phantom: PhantomData,
+            }),
+            Lossy::OPCODE => Instruction::CastLossy(CastLossy {
+                operation: self.operation,
+                cast_type: self.cast_type,
+                _phantom: PhantomData,
+            }),
+            _ => P::halt("Invalid option provided for the `cast` instruction"),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+    use crate::{function::Register, Process};
+
+    type P = Process;
+
+    /// Evaluates `opcode r0 into r1 as {cast_type}` on the given input, and checks the output.
+    pub(crate) fn check_cast<Op: CastOpcode>(input: &str, cast_type: &str, expected: &str) {
+        let registers = Registers::<P>::default();
+        registers.define(&Register::from_str("r0"));
+        registers.define(&Register::from_str("r1"));
+        registers.assign(&Register::from_str("r0"), Value::<P>::from_str(input));
+
+        Cast::<P, Op>::from_str(&format!("r0 into r1 as {cast_type}")).evaluate(&registers);
+        let candidate = registers.load(&Register::from_str("r1"));
+        assert_eq!(
+            Value::<P>::from_str(expected),
+            candidate,
+            "Expected '{} {input} as {cast_type}' to output {expected} but got {candidate}",
+            Op::OPCODE
+        );
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        for cast_type in ["i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128"] {
+            let expected = format!("r0 into r1 as {cast_type}");
+            let (string, instruction) = Cast::<P, Lossy>::parse(&expected).unwrap();
+            assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+            assert_eq!(expected, instruction.to_string());
+            assert_eq!(cast_type, instruction.cast_type().type_name::<<P as Parser>::Environment>());
+        }
+        assert!(Cast::<P, Lossy>::parse("r0 into r1 as field").is_err());
+        assert!(Cast::<P, Lossy>::parse("r0 into r1").is_err());
+    }
+
+    #[test]
+    fn test_bytes() {
+        for cast_type in ["i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128"] {
+            let expected = Cast::<P, Checked>::from_str(&format!("r0 into r1 as {cast_type}"));
+            let candidate = Cast::<P, Checked>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+            assert_eq!(expected.to_string(), candidate.to_string());
+        }
+    }
+}
@@ -16,14 +16,17 @@
 
 use crate::{
     function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    HaltReason,
     LiteralType,
     Program,
     Value,
 };
 use snarkvm_circuit::{
     count,
+    prelude::Z,
     Count,
     DivChecked,
+    Eject,
     Field,
     Literal,
     Metrics,
@@ -61,6 +64,64 @@ impl<P: Program> Div<P> {
     pub fn destination(&self) -> &Register<P> {
         self.operation.destination()
     }
+
+    /// Returns the number of constants, public inputs, private inputs, and constraints of the instruction,
+    /// broken down into the division, the division-by-zero check, and the overflow check.
+    pub fn count_breakdown(case: &<Self as Metrics<Self>>::Case) -> DivCount {
+        macro_rules! integer_breakdown {
+            ($integer:ident, $mode_a:expr, $mode_b:expr) => {{
+                let case = (*$mode_a, *$mode_b);
+                DivCount {
+                    division: $integer::<P::Environment>::div_checked_division_count(&case),
+                    zero_check: $integer::<P::Environment>::div_checked_zero_check_count(&case),
+                    overflow_check: $integer::<P::Environment>::div_checked_overflow_count(&case),
+                }
+            }};
+        }
+
+        match case {
+            (LiteralType::Field(mode_a), LiteralType::Field(mode_b)) => DivCount {
+                division: count!(
+                    Field<P::Environment>,
+                    DivCircuit<Field<P::Environment>, Output = Field<P::Environment>>,
+                    &(*mode_a, *mode_b)
+                ),
+                // Division by zero halts during synthesis, and field division cannot overflow.
+                zero_check: Count::zero(),
+                overflow_check: Count::zero(),
+            },
+            (LiteralType::I8(mode_a), LiteralType::I8(mode_b)) => integer_breakdown!(I8, mode_a, mode_b),
+            (LiteralType::I16(mode_a), LiteralType::I16(mode_b)) => integer_breakdown!(I16, mode_a, mode_b),
+            (LiteralType::I32(mode_a), LiteralType::I32(mode_b)) => integer_breakdown!(I32, mode_a, mode_b),
+            (LiteralType::I64(mode_a), LiteralType::I64(mode_b)) => integer_breakdown!(I64, mode_a, mode_b),
+            (LiteralType::I128(mode_a), LiteralType::I128(mode_b)) => integer_breakdown!(I128, mode_a, mode_b),
+            (LiteralType::U8(mode_a), LiteralType::U8(mode_b)) => integer_breakdown!(U8, mode_a, mode_b),
+            (LiteralType::U16(mode_a), LiteralType::U16(mode_b)) => integer_breakdown!(U16, mode_a, mode_b),
+            (LiteralType::U32(mode_a), LiteralType::U32(mode_b)) => integer_breakdown!(U32, mode_a, mode_b),
+            (LiteralType::U64(mode_a), LiteralType::U64(mode_b)) => integer_breakdown!(U64, mode_a, mode_b),
+            (LiteralType::U128(mode_a), LiteralType::U128(mode_b)) => integer_breakdown!(U128, mode_a, mode_b),
+            _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
+        }
+    }
+}
+
+/// The number of constants, public inputs, private inputs, and constraints of a `div` instruction,
+/// broken down by the components of the instruction.
+#[derive(Copy, Clone, Debug)]
+pub struct DivCount {
+    /// The cost of the division itself.
+    pub division: Count,
+    /// The cost of the division-by-zero check.
+    pub zero_check: Count,
+    /// The cost of the overflow check (`MIN / -1`), which only applies to signed integers.
+    pub overflow_check: Count,
+}
+
+impl DivCount {
+    /// Returns the total cost of the `div` instruction.
+    pub fn total(&self) -> Count {
+        self.division + self.zero_check + self.overflow_check
+    }
 }
 
 impl<P: Program> Opcode for Div<P> {
@@ -78,13 +139,32 @@ impl<P: Program> Operation<P> for Div<P> {
         // Load the values for the first and second operands.
         let first = match registers.load(self.operation.first()) {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => P::halt_with(HaltReason::NotALiteral(name.to_string())),
         };
         let second = match registers.load(self.operation.second()) {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => P::halt_with(HaltReason::NotALiteral(name.to_string())),
         };
 
+        // Halt on division by a constant zero.
+        let is_zero = match &second {
+            Literal::Field(b) => b.is_constant() && b.eject_value().is_zero(),
+            Literal::I8(b) => b.is_constant() && b.eject_value() == 0,
+            Literal::I16(b) => b.is_constant() && b.eject_value() == 0,
+            Literal::I32(b) => b.is_constant() && b.eject_value() == 0,
+            Literal::I64(b) => b.is_constant() && b.eject_value() == 0,
+            Literal::I128(b) => b.is_constant() && b.eject_value() == 0,
+            Literal::U8(b) => b.is_constant() && b.eject_value() == 0,
+            Literal::U16(b) => b.is_constant() && b.eject_value() == 0,
+            Literal::U32(b) => b.is_constant() && b.eject_value() == 0,
+            Literal::U64(b) => b.is_constant() && b.eject_value() == 0,
+            Literal::U128(b) => b.is_constant() && b.eject_value() == 0,
+            _ => false,
+        };
+        if is_zero {
+            P::halt_with(HaltReason::DivisionByZero)
+        }
+
         // Perform the operation.
         let result = match (first, second) {
             (Literal::Field(a), Literal::Field(b)) => Literal::Field(a / b),
@@ -98,7 +178,7 @@ impl<P: Program> Operation<P> for Div<P> {
             (Literal::U32(a), Literal::U32(b)) => Literal::U32(a.div_checked(&b)),
             (Literal::U64(a), Literal::U64(b)) => Literal::U64(a.div_checked(&b)),
             (Literal::U128(a), Literal::U128(b)) => Literal::U128(a.div_checked(&b)),
-            _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
+            _ => P::halt_with(HaltReason::InvalidOperandType(Self::opcode())),
         };
 
         registers.assign(self.operation.destination(), result);
@@ -109,19 +189,7 @@ impl<P: Program> Metrics<Self> for Div<P> {
     type Case = (LiteralType<P::Environment>, LiteralType<P::Environment>);
 
     fn count(case: &Self::Case) -> Count {
-        crate::match_count!(match DivCircuit::count(case) {
-            (Field, Field) => Field,
-            (I8, I8) => I8,
-            (I16, I16) => I16,
-            (I32, I32) => I32,
-            (I64, I64) => I64,
-            (I128, I128) => I128,
-            (U8, U8) => U8,
-            (U16, U16) => U16,
-            (U32, U32) => U32,
-            (U64, U64) => U64,
-            (U128, U128) => U128,
-        })
+        Self::count_breakdown(case).total()
     }
 }
 
@@ -166,6 +234,7 @@ impl<P: Program> Into<Instruction<P>> for Div<P> {
 mod tests {
     use super::*;
     use crate::{function::Register, test_instruction_halts, test_modes, Identifier, Process};
+    use snarkvm_circuit::Mode;
 
     type P = Process;
 
@@ -268,6 +337,102 @@ mod tests {
     test_instruction_halts!(boolean_halts, Div, "Invalid 'div' instruction", "true.constant", "true.constant");
     test_instruction_halts!(string_halts, Div, "Invalid 'div' instruction", "\"hello\".constant", "\"world\".constant");
 
+    #[test]
+    fn test_count_breakdown() {
+        type E = <P as Program>::Aleo;
+
+        macro_rules! check_count_breakdown {
+            ($type_:ident, $operation:ident, $is_signed:expr) => {{
+                for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+                    for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                        let case = (LiteralType::$type_(mode_a), LiteralType::$type_(mode_b));
+                        let breakdown = Div::<P>::count_breakdown(&case);
+
+                        // Ensure the components sum to the total count of the circuit operation.
+                        let total = breakdown.total();
+                        let expected = count!($type_<E>, $operation<$type_<E>, Output = $type_<E>>, &(mode_a, mode_b));
+                        assert_eq!(
+                            (expected.0, expected.1, expected.2, expected.3),
+                            (total.0, total.1, total.2, total.3)
+                        );
+
+                        // Ensure division by zero halts during synthesis, instead of adding to the circuit.
+                        let (zero, zero_check) = (Count::zero(), breakdown.zero_check);
+                        assert_eq!(
+                            (zero.0, zero.1, zero.2, zero.3),
+                            (zero_check.0, zero_check.1, zero_check.2, zero_check.3)
+                        );
+
+                        // Ensure only signed integers include the overflow check, unless both operands are constant.
+                        let has_overflow_check = $is_signed && !(mode_a.is_constant() && mode_b.is_constant());
+                        assert_eq!(has_overflow_check, breakdown.overflow_check.3 != zero.3);
+                    }
+                }
+            }};
+        }
+
+        check_count_breakdown!(Field, DivCircuit, false);
+        check_count_breakdown!(I8, DivChecked, true);
+        check_count_breakdown!(I16, DivChecked, true);
+        check_count_breakdown!(I32, DivChecked, true);
+        check_count_breakdown!(I64, DivChecked, true);
+        check_count_breakdown!(I128, DivChecked, true);
+        check_count_breakdown!(U8, DivChecked, false);
+        check_count_breakdown!(U16, DivChecked, false);
+        check_count_breakdown!(U32, DivChecked, false);
+        check_count_breakdown!(U64, DivChecked, false);
+        check_count_breakdown!(U128, DivChecked, false);
+    }
+
+    #[test]
+    fn test_division_by_zero_halt_reason() {
+        let cases = [("1u8.constant", "0u8.constant"), ("1i64.private", "0i64.public"), ("1field", "0field")];
+        for (first, second) in cases {
+            let registers = Registers::<P>::default();
+            registers.define(&Register::from_str("r0"));
+            registers.define(&Register::from_str("r1"));
+            registers.define(&Register::from_str("r2"));
+            registers.assign(&Register::from_str("r0"), Value::<P>::from_str(first));
+            registers.assign(&Register::from_str("r1"), Value::<P>::from_str(second));
+
+            let result = HaltReason::catch(|| Div::from_str("r0 r1 into r2").evaluate(&registers));
+            assert_eq!(Err(HaltReason::DivisionByZero), result);
+        }
+    }
+
+    #[test]
+    fn test_overflow_halt_reason() {
+        let cases = [
+            (format!("{}i8", i8::MIN), "-1i8.constant"),
+            (format!("{}i32", i32::MIN), "-1i32.constant"),
+            (format!("{}i128", i128::MIN), "-1i128.constant"),
+        ];
+        for (first, second) in cases {
+            let registers = Registers::<P>::default();
+            registers.define(&Register::from_str("r0"));
+            registers.define(&Register::from_str("r1"));
+            registers.define(&Register::from_str("r2"));
+            registers.assign(&Register::from_str("r0"), Value::<P>::from_str(&first));
+            registers.assign(&Register::from_str("r1"), Value::<P>::from_str(second));
+
+            let result = HaltReason::catch(|| Div::from_str("r0 r1 into r2").evaluate(&registers));
+            assert_eq!(Err(HaltReason::Overflow), result);
+        }
+    }
+
+    #[test]
+    fn test_invalid_operand_type_halt_reason() {
+        let registers = Registers::<P>::default();
+        registers.define(&Register::from_str("r0"));
+        registers.define(&Register::from_str("r1"));
+        registers.define(&Register::from_str("r2"));
+        registers.assign(&Register::from_str("r0"), Value::<P>::from_str("true.constant"));
+        registers.assign(&Register::from_str("r1"), Value::<P>::from_str("true.constant"));
+
+        let result = HaltReason::catch(|| Div::from_str("r0 r1 into r2").evaluate(&registers));
+        assert_eq!(Err(HaltReason::InvalidOperandType("div")), result);
+    }
+
     #[test]
     #[should_panic(expected = "message is not a literal")]
     fn test_definition_halts() {
@@ -69,6 +69,11 @@ impl<P: Program> Operation<P> for Double<P> {
 
         registers.assign(self.operation.destination(), result);
     }
+
+    #[inline]
+    fn can_halt() -> bool {
+        false
+    }
 }
 
 impl<P: Program> Parser for Double<P> {
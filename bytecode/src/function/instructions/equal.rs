@@ -100,6 +100,11 @@ impl<P: Program> Operation<P> for Equal<P> {
 
         registers.assign(self.operation.destination(), result);
     }
+
+    #[inline]
+    fn can_halt() -> bool {
+        false
+    }
 }
 
 impl<P: Program> Metrics<Self> for Equal<P> {
@@ -249,6 +254,20 @@ mod tests {
     binary_instruction_test!(u128_ne, Equal, "1u128.public", "2u128.public", "false.private");
 
     test_instruction_halts!(string_halts, Equal, "Invalid 'eq' instruction", "\"hello\"", "\"hello\"");
+    test_instruction_halts!(field_group_halts, Equal, "Invalid 'eq' instruction", "1field.public", "2group.public");
+    test_instruction_halts!(i8_u8_halts, Equal, "Invalid 'eq' instruction", "1i8.public", "1u8.public");
+    test_instruction_halts!(
+        address_boolean_halts,
+        Equal,
+        "Invalid 'eq' instruction",
+        "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public",
+        "true.public"
+    );
+
+    #[test]
+    fn test_matches_native() {
+        crate::function::instructions::tests::test_equality::<Equal<Process>>(core::convert::identity);
+    }
 
     #[test]
     #[should_panic(expected = "message is not a literal")]
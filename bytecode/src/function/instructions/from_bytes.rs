@@ -0,0 +1,185 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::to_bytes::{num_field_bytes, BYTES_IDENTIFIER, CONSTANT_BYTES_IDENTIFIER};
+use crate::{
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    Program,
+    Value,
+};
+use snarkvm_circuit::{Field, FromBits, Literal, Parser, ParserResult, ToBits};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use core::fmt;
+use nom::combinator::map;
+use std::io::{Read, Result as IoResult, Write};
+
+/// Reconstructs a field element from the little-endian bytes in `first`, storing the outcome in `destination`.
+///
+/// The input must be a `to_bytes` or `to_bytes_constant` value, as produced by the `to.bytes` instruction.
+/// The reconstruction is range-checked, so the bytes must encode a field element less than the modulus.
+pub struct FieldFromBytes<P: Program> {
+    operation: UnaryOperation<P>,
+}
+
+impl<P: Program> FieldFromBytes<P> {
+    /// Returns the operands of the instruction.
+    pub fn operands(&self) -> Vec<Operand<P>> {
+        self.operation.operands()
+    }
+
+    /// Returns the destination register of the instruction.
+    pub fn destination(&self) -> &Register<P> {
+        self.operation.destination()
+    }
+}
+
+impl<P: Program> Opcode for FieldFromBytes<P> {
+    /// Returns the opcode as a string.
+    #[inline]
+    fn opcode() -> &'static str {
+        "from.bytes"
+    }
+}
+
+impl<P: Program> Operation<P> for FieldFromBytes<P> {
+    /// Evaluates the operation.
+    #[inline]
+    fn evaluate(&self, registers: &Registers<P>) {
+        // Load the bytes from the operand.
+        let bytes = match registers.load(self.operation.first()) {
+            Value::Definition(name, members)
+                if name.as_str() == BYTES_IDENTIFIER || name.as_str() == CONSTANT_BYTES_IDENTIFIER =>
+            {
+                members
+            }
+            value => P::halt(format!("Invalid '{}' instruction: expected bytes, found {value}", Self::opcode())),
+        };
+
+        // Ensure the number of bytes matches the size of a field element.
+        if bytes.len() != num_field_bytes::<P>() {
+            P::halt(format!(
+                "Invalid '{}' instruction: expected {} bytes, found {}",
+                Self::opcode(),
+                num_field_bytes::<P>(),
+                bytes.len()
+            ))
+        }
+
+        // Unpack the bytes into bits.
+        let bits_le = bytes
+            .iter()
+            .flat_map(|byte| match byte {
+                Value::Literal(Literal::U8(byte)) => byte.to_bits_le(),
+                _ => P::halt(format!("Invalid '{}' instruction: expected a u8, found {byte}", Self::opcode())),
+            })
+            .collect::<Vec<_>>();
+
+        // Reconstruct the field element, enforcing the excess bits are zero and the value is less than the modulus.
+        let result = Literal::Field(Field::from_bits_le(&bits_le));
+
+        registers.assign(self.operation.destination(), result);
+    }
+}
+
+impl<P: Program> Parser for FieldFromBytes<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into a 'from.bytes' operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the operation from the string.
+        map(UnaryOperation::parse, |operation| Self { operation })(string)
+    }
+}
+
+impl<P: Program> fmt::Display for FieldFromBytes<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.operation)
+    }
+}
+
+impl<P: Program> FromBytes for FieldFromBytes<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self { operation: UnaryOperation::read_le(&mut reader)? })
+    }
+}
+
+impl<P: Program> ToBytes for FieldFromBytes<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.operation.write_le(&mut writer)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<P: Program> Into<Instruction<P>> for FieldFromBytes<P> {
+    /// Converts the operation into an instruction.
+    fn into(self) -> Instruction<P> {
+        Instruction::FieldFromBytes(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_instruction_halts, Identifier, Process};
+    use snarkvm_circuit::{AleoV0 as Circuit, Environment, Inject, Mode, U8};
+
+    type P = Process;
+
+    /// Evaluates `from.bytes` on the given bytes, returning `true` if the circuit is satisfied.
+    fn is_satisfied(bytes: &[u8], mode: Mode) -> bool {
+        let bytes = bytes.iter().map(|byte| Value::Literal(Literal::U8(U8::new(mode, *byte)))).collect();
+
+        let registers = Registers::<P>::default();
+        registers.define(&Register::from_str("r0"));
+        registers.define(&Register::from_str("r1"));
+        let bytes = Value::Definition(Identifier::from_str(BYTES_IDENTIFIER), bytes);
+        registers.assign(&Register::from_str("r0"), bytes);
+
+        FieldFromBytes::from_str("r0 into r1").evaluate(&registers);
+        let is_satisfied = Circuit::is_satisfied();
+        Circuit::reset();
+        is_satisfied
+    }
+
+    #[test]
+    fn test_parse() {
+        let (_, instruction) = Instruction::<P>::parse("from.bytes r0 into r1;").unwrap();
+        assert!(matches!(instruction, Instruction::FieldFromBytes(_)));
+    }
+
+    #[test]
+    fn test_out_of_range_is_unsatisfied() {
+        // Ensure the largest field element is accepted.
+        let modulus_minus_one = -<Circuit as Environment>::BaseField::from(1u64);
+        assert!(is_satisfied(&modulus_minus_one.to_bytes_le().unwrap(), Mode::Private));
+
+        // Ensure bytes that are greater than or equal to the modulus are rejected.
+        let mut modulus = modulus_minus_one.to_bytes_le().unwrap();
+        modulus[0] += 1;
+        assert!(!is_satisfied(&modulus, Mode::Private));
+        assert!(!is_satisfied(&[u8::MAX; 32], Mode::Public));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 32 bytes, found 31")]
+    fn test_too_few_bytes_halts() {
+        is_satisfied(&[0u8; 31], Mode::Constant);
+    }
+
+    test_instruction_halts!(field_halts, FieldFromBytes, "Invalid 'from.bytes' instruction", "1field.constant");
+}
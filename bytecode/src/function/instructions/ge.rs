@@ -84,6 +84,11 @@ impl<P: Program> Operation<P> for GreaterThanOrEqual<P> {
 
         registers.assign(self.operation.destination(), result);
     }
+
+    #[inline]
+    fn can_halt() -> bool {
+        false
+    }
 }
 
 impl<P: Program> Parser for GreaterThanOrEqual<P> {
@@ -184,6 +189,13 @@ mod tests {
     binary_instruction_test!(u128_gt, GreaterThanOrEqual, "2u128.public", "1u128.public", "true.private");
     binary_instruction_test!(u128_lt, GreaterThanOrEqual, "1u128.public", "2u128.public", "false.private");
 
+    #[test]
+    fn test_matches_native() {
+        crate::function::instructions::tests::test_comparison::<GreaterThanOrEqual<Process>>(
+            core::cmp::Ordering::is_ge,
+        );
+    }
+
     test_instruction_halts!(
         address_halts,
         GreaterThanOrEqual,
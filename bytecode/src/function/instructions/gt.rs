@@ -84,6 +84,11 @@ impl<P: Program> Operation<P> for GreaterThan<P> {
 
         registers.assign(self.operation.destination(), result);
     }
+
+    #[inline]
+    fn can_halt() -> bool {
+        false
+    }
 }
 
 impl<P: Program> Parser for GreaterThan<P> {
@@ -172,6 +177,11 @@ mod tests {
     test_modes!(u128, GreaterThan, "1u128", "0u128", "true");
     binary_instruction_test!(u128_lt, GreaterThan, "0u128.public", "1u128.public", "false.private");
 
+    #[test]
+    fn test_matches_native() {
+        crate::function::instructions::tests::test_comparison::<GreaterThan<Process>>(core::cmp::Ordering::is_gt);
+    }
+
     test_instruction_halts!(
         address_halts,
         GreaterThan,
@@ -84,6 +84,11 @@ impl<P: Program> Operation<P> for LessThanOrEqual<P> {
 
         registers.assign(self.operation.destination(), result);
     }
+
+    #[inline]
+    fn can_halt() -> bool {
+        false
+    }
 }
 
 impl<P: Program> Parser for LessThanOrEqual<P> {
@@ -184,6 +189,11 @@ mod tests {
     binary_instruction_test!(u128_lt, LessThanOrEqual, "1u128.public", "2u128.public", "true.private");
     binary_instruction_test!(u128_gt, LessThanOrEqual, "2u128.public", "1u128.public", "false.private");
 
+    #[test]
+    fn test_matches_native() {
+        crate::function::instructions::tests::test_comparison::<LessThanOrEqual<Process>>(core::cmp::Ordering::is_le);
+    }
+
     test_instruction_halts!(
         address_halts,
         LessThanOrEqual,
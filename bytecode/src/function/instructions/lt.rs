@@ -84,6 +84,11 @@ impl<P: Program> Operation<P> for LessThan<P> {
 
         registers.assign(self.operation.destination(), result);
     }
+
+    #[inline]
+    fn can_halt() -> bool {
+        false
+    }
 }
 
 impl<P: Program> Parser for LessThan<P> {
@@ -172,6 +177,11 @@ mod tests {
     test_modes!(u128, LessThan, "0u128", "1u128", "true");
     binary_instruction_test!(u128_gt, LessThan, "1u128.public", "0u128.public", "false.private");
 
+    #[test]
+    fn test_matches_native() {
+        crate::function::instructions::tests::test_comparison::<LessThan<Process>>(core::cmp::Ordering::is_lt);
+    }
+
     test_instruction_halts!(
         address_halts,
         LessThan,
@@ -29,6 +29,9 @@ use and::*;
 mod add_wrapped;
 use add_wrapped::*;
 
+mod cast;
+use cast::*;
+
 mod commit;
 use commit::*;
 
@@ -44,6 +47,9 @@ use double::*;
 mod equal;
 use equal::*;
 
+mod from_bytes;
+use from_bytes::*;
+
 mod gt;
 use gt::*;
 
@@ -119,6 +125,10 @@ use sub_wrapped::*;
 mod ternary;
 use ternary::*;
 
+mod to_bytes;
+pub(crate) use to_bytes::bytes_definition;
+use to_bytes::*;
+
 mod xor;
 use xor::*;
 
@@ -126,6 +136,7 @@ use crate::{
     function::{parsers::Operand, registers::Registers, Register},
     Program,
     Sanitizer,
+    Value,
 };
 use snarkvm_circuit::{Parser, ParserResult};
 use snarkvm_utilities::{error, FromBytes, ToBytes};
@@ -137,7 +148,8 @@ use nom::{
     combinator::map,
     sequence::{pair, preceded},
 };
-use std::io::{Read, Result as IoResult, Write};
+use once_cell::sync::OnceCell;
+use std::io::{ErrorKind, Read, Result as IoResult, Write};
 
 /// Creates a match statement that produces the count for a binary instruction.
 ///
@@ -184,6 +196,18 @@ pub trait Operation<P: Program>: Parser + Into<Instruction<P>> {
     /// Evaluates the operation.
     ///
     fn evaluate(&self, registers: &Registers<P>);
+
+    ///
+    /// Returns `true` if the operation may halt on operands of valid types,
+    /// such as on overflow, division by zero, or an input that is out of range.
+    /// Note: Every operation halts on operands of invalid types, regardless of this classification.
+    ///
+    fn can_halt() -> bool
+    where
+        Self: Sized,
+    {
+        true
+    }
 }
 
 pub enum Instruction<P: Program> {
@@ -197,6 +221,10 @@ pub enum Instruction<P: Program> {
     AddWrapped(AddWrapped<P>),
     /// Performs a bitwise AND operation on `first` and `second`, storing the outcome in `destination`.
     And(And<P>),
+    /// Casts `first` from a field element into an integer, checking that `first` is within the range of the integer.
+    CastChecked(CastChecked<P>),
+    /// Casts `first` from a field element into an integer, reducing `first` modulo `2^BITS` of the integer.
+    CastLossy(CastLossy<P>),
     /// Performs a BHP commitment taking a 256-bit value as input.
     CommitBHP256(CommitBHP256<P>),
     /// Performs a BHP commitment taking a 512-bit value as input.
@@ -217,6 +245,10 @@ pub enum Instruction<P: Program> {
     Double(Double<P>),
     /// Checks if `first` is equal to `second`, storing the outcome in `destination`.
     Equal(Equal<P>),
+    /// Reconstructs a field element from the little-endian bytes in `first`, storing the outcome in `destination`.
+    FieldFromBytes(FieldFromBytes<P>),
+    /// Decomposes the field element `first` into its little-endian bytes, storing the outcome in `destination`.
+    FieldToBytes(FieldToBytes<P>),
     /// Checks if `first` is greater than `second`, storing the result in `destination`.
     GreaterThan(GreaterThan<P>),
     /// Checks if `first` is greater than or equal to `second`, storing the result in `destination`.
@@ -328,6 +360,8 @@ macro_rules! instruction {
             Add,
             AddWrapped,
             And,
+            CastChecked,
+            CastLossy,
             CommitBHP256,
             CommitBHP512,
             CommitBHP768,
@@ -338,6 +372,8 @@ macro_rules! instruction {
             DivWrapped,
             Double,
             Equal,
+            FieldFromBytes,
+            FieldToBytes,
             GreaterThan,
             GreaterThanOrEqual,
             HashBHP256,
@@ -433,6 +469,26 @@ impl<P: Program> Instruction<P> {
         instruction!(self, |InstructionMember| InstructionMember::<P>::opcode())
     }
 
+    /// Returns the opcodes of all instructions, in the order of the instruction variants.
+    #[inline]
+    pub fn opcodes() -> &'static [&'static str] {
+        /// Creates the list of opcodes for the given instructions.
+        ///
+        /// ## Example
+        /// ```ignore
+        /// instruction_opcodes!(self, |_instruction| {}, { Add, Sub, Mul, Div })
+        /// ```
+        macro_rules! instruction_opcodes {
+            ($object:expr, |_instruction| $_operation:block, { $( $variant:ident, )+ }) => {{
+                vec![ $( $variant::<P>::opcode(), )+ ]
+            }};
+        }
+
+        // Note: The opcodes do not depend on the program, so the list is shared across all programs.
+        static OPCODES: OnceCell<Vec<&'static str>> = OnceCell::new();
+        OPCODES.get_or_init(|| instruction!(instruction_opcodes!(self, _instruction)))
+    }
+
     /// Returns the operands of the instruction.
     #[inline]
     pub(crate) fn operands(&self) -> Vec<Operand<P>> {
@@ -445,11 +501,70 @@ impl<P: Program> Instruction<P> {
         instruction!(self, |instruction| instruction.destination())
     }
 
+    /// Returns the registers read and written by the instruction, without evaluating it.
+    #[inline]
+    pub fn dependencies(&self) -> (Vec<Register<P>>, Vec<Register<P>>) {
+        // Retrieve the operands that are registers, as the remaining operands are values.
+        let reads = self
+            .operands()
+            .into_iter()
+            .filter_map(|operand| match operand {
+                Operand::Register(register) => Some(register),
+                Operand::Value(..) => None,
+            })
+            .collect();
+        (reads, vec![self.destination().clone()])
+    }
+
+    /// Returns an instruction that assigns the given constant value to the destination register,
+    /// or `None` if the value is not a constant literal that `ternary` supports.
+    #[inline]
+    pub(crate) fn constant(value: Value<P>, destination: Register<P>) -> Option<Self> {
+        match (value.is_constant(), value) {
+            (true, Value::Literal(literal)) => Ternary::constant(literal, destination).map(Into::into),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the instruction may halt on operands of valid types.
+    /// Instructions that return `false` (e.g. `xor`) may skip halt handling once their operands are type-checked.
+    #[inline]
+    pub fn can_halt(&self) -> bool {
+        instruction!(self, |InstructionMember| <InstructionMember<P> as Operation<P>>::can_halt())
+    }
+
     /// Evaluates the instruction.
     #[inline]
     pub(crate) fn evaluate(&self, registers: &Registers<P>) {
         instruction!(self, |instruction| instruction.evaluate(registers))
     }
+
+    /// Returns an iterator that lazily decodes instructions from the given reader, one at a time.
+    /// The iterator ends cleanly at EOF, and ends after yielding the first decoding error.
+    pub fn read_stream<R: Read>(mut reader: R) -> impl Iterator<Item = IoResult<Self>> {
+        let mut is_done = false;
+        core::iter::from_fn(move || {
+            if is_done {
+                return None;
+            }
+            // Read the first byte, to distinguish EOF from a truncated instruction.
+            let mut first_byte = [0u8; 1];
+            let result = loop {
+                match reader.read(&mut first_byte) {
+                    Ok(0) => {
+                        is_done = true;
+                        return None;
+                    }
+                    Ok(_) => break Self::read_le(first_byte.as_slice().chain(&mut reader)),
+                    Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                    Err(error) => break Err(error),
+                }
+            };
+            // Stop decoding after the first error, as the reader is no longer aligned to an instruction.
+            is_done = result.is_err();
+            Some(result)
+        })
+    }
 }
 
 impl<P: Program> Parser for Instruction<P> {
@@ -528,7 +643,7 @@ impl<P: Program> FromBytes for Instruction<P> {
 
                 // Build the cases for all instructions.
                 $(
-                    if INSTRUCTION_VARIANTS[variant as usize] == stringify!($variant) {
+                    if INSTRUCTION_VARIANTS.get(variant as usize) == Some(&stringify!($variant)) {
                         // Read the instruction.
                         let instruction = $variant::read_le(&mut $reader)?;
                         // Return the instruction.
@@ -581,15 +696,145 @@ impl<P: Program> ToBytes for Instruction<P> {
 
 #[cfg(test)]
 mod tests {
+    use super::Instruction;
     use crate::{
         function::{instructions::Opcode, Operation, Register, Registers},
         Parser,
         Process,
         Value,
     };
+    use snarkvm_utilities::{FromBytes, ToBytes};
 
     type P = Process;
 
+    #[test]
+    fn test_read_stream() {
+        let instructions = [
+            "add r0 r1 into r2;",
+            "sub r2 r1 into r3;",
+            "mul r3 r3 into r4;",
+            "ternary r5 r3 r4 into r6;",
+            "hash.ped64 r6 into r7;",
+            "not r7 into r8;",
+        ]
+        .iter()
+        .map(|string| Instruction::<P>::from_str(string))
+        .collect::<Vec<_>>();
+
+        let mut bytes = Vec::new();
+        for instruction in &instructions {
+            instruction.write_le(&mut bytes).unwrap();
+        }
+
+        // Decode the instructions from a buffer, one after another.
+        let mut buffer = &bytes[..];
+        let mut expected = Vec::new();
+        while !buffer.is_empty() {
+            expected.push(Instruction::<P>::read_le(&mut buffer).unwrap());
+        }
+
+        // Ensure the stream matches the buffered decoding, and ends cleanly at EOF.
+        let candidate = Instruction::<P>::read_stream(&bytes[..]).collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(instructions.len(), expected.len());
+        assert_eq!(instructions.len(), candidate.len());
+        for ((instruction, expected), candidate) in instructions.iter().zip(&expected).zip(&candidate) {
+            assert_eq!(instruction.to_string(), expected.to_string());
+            assert_eq!(expected.to_string(), candidate.to_string());
+        }
+
+        // Ensure an empty reader yields no instructions.
+        assert_eq!(0, Instruction::<P>::read_stream(&[][..]).count());
+
+        // Ensure a truncated stream yields the complete instructions, then a single error.
+        let mut stream = Instruction::<P>::read_stream(&bytes[..bytes.len() - 1]);
+        for instruction in &instructions[..instructions.len() - 1] {
+            assert_eq!(instruction.to_string(), stream.next().unwrap().unwrap().to_string());
+        }
+        assert!(stream.next().unwrap().is_err());
+        assert!(stream.next().is_none());
+
+        // Ensure an unknown instruction variant yields an error, instead of panicking.
+        let mut stream = Instruction::<P>::read_stream(&[0xff, 0xff, 0x00][..]);
+        assert!(stream.next().unwrap().is_err());
+        assert!(stream.next().is_none());
+    }
+
+    #[test]
+    fn test_opcodes() {
+        let opcodes = Instruction::<P>::opcodes();
+
+        // Ensure the opcodes are unique.
+        let unique = opcodes.iter().collect::<std::collections::HashSet<_>>();
+        assert_eq!(opcodes.len(), unique.len());
+
+        // Ensure each opcode parses a minimal instruction.
+        let operands = ["r0 into r1", "r0 r1 into r2", "r0 r1 r2 into r3", "r0 into r1 as u8"];
+        for opcode in opcodes {
+            let is_parsed = operands.iter().any(|operands| {
+                match Instruction::<P>::parse(&format!("{opcode} {operands};")) {
+                    Ok((remainder, instruction)) => remainder.is_empty() && instruction.opcode() == *opcode,
+                    Err(_) => false,
+                }
+            });
+            assert!(is_parsed, "Failed to parse an instruction for opcode '{opcode}'");
+        }
+    }
+
+    #[test]
+    fn test_dependencies() {
+        for (instruction, reads, writes) in [
+            ("div r0 r1 into r2;", vec!["r0", "r1"], vec!["r2"]),
+            ("shl.w r3 r4 into r5;", vec!["r3", "r4"], vec!["r5"]),
+            ("shl.w r0 1u8 into r1;", vec!["r0"], vec!["r1"]),
+            ("not r0.owner into r1;", vec!["r0.owner"], vec!["r1"]),
+            ("ternary r0 r1 r2 into r3;", vec!["r0", "r1", "r2"], vec!["r3"]),
+            ("cast r6 into r7 as u8;", vec!["r6"], vec!["r7"]),
+        ] {
+            let instruction = Instruction::<P>::from_str(instruction);
+            let (candidate_reads, candidate_writes) = instruction.dependencies();
+            assert_eq!(reads.into_iter().map(Register::from_str).collect::<Vec<_>>(), candidate_reads);
+            assert_eq!(writes.into_iter().map(Register::from_str).collect::<Vec<_>>(), candidate_writes);
+        }
+    }
+
+    #[test]
+    fn test_can_halt() {
+        for (instruction, expected) in [
+            // Checked operations halt on overflow, division by zero, or an out-of-range input.
+            ("abs r0 into r1;", true),
+            ("add r0 r1 into r2;", true),
+            ("div r0 r1 into r2;", true),
+            ("div.w r0 r1 into r2;", true),
+            ("inv r0 into r1;", true),
+            ("mul r0 r1 into r2;", true),
+            ("neg r0 into r1;", true),
+            ("pow r0 r1 into r2;", true),
+            ("shl r0 r1 into r2;", true),
+            ("shr r0 r1 into r2;", true),
+            ("sub r0 r1 into r2;", true),
+            ("cast r0 into r1 as u8;", true),
+            ("hash.ped64 r0 into r1;", true),
+            // Wrapped shifts halt on a shift amount of an unsupported type.
+            ("shl.w r0 r1 into r2;", true),
+            ("shr.w r0 r1 into r2;", true),
+            // Pure operations only halt on operands of invalid types.
+            ("abs.w r0 into r1;", false),
+            ("add.w r0 r1 into r2;", false),
+            ("and r0 r1 into r2;", false),
+            ("nand r0 r1 into r2;", false),
+            ("nor r0 r1 into r2;", false),
+            ("not r0 into r1;", false),
+            ("or r0 r1 into r2;", false),
+            ("xor r0 r1 into r2;", false),
+            ("eq r0 r1 into r2;", false),
+            ("lt r0 r1 into r2;", false),
+            ("ternary r0 r1 r2 into r3;", false),
+            ("cast.lossy r0 into r1 as u8;", false),
+        ] {
+            assert_eq!(expected, Instruction::<P>::from_str(instruction).can_halt(), "{instruction}");
+        }
+    }
+
     pub fn test_binary<Op: Operation<P> + Opcode>(a_str: &str, b_str: &str, expected_str: &str) {
         let a = Value::<P>::from_str(a_str);
         let b = Value::<P>::from_str(b_str);
@@ -616,6 +861,68 @@ mod tests {
         );
     }
 
+    /// Checks the given comparison instruction against the native ordering,
+    /// on the boundary values of every integer type.
+    pub fn test_comparison<Op: Operation<P> + Opcode>(is_satisfied: fn(core::cmp::Ordering) -> bool) {
+        macro_rules! check {
+            ($type:ty, $values:expr) => {{
+                let values: &[$type] = &$values;
+                for a in values {
+                    for b in values {
+                        let type_name = stringify!($type);
+                        test_binary::<Op>(
+                            &format!("{a}{type_name}.public"),
+                            &format!("{b}{type_name}.private"),
+                            &format!("{}.private", is_satisfied(a.cmp(b))),
+                        );
+                    }
+                }
+            }};
+        }
+        check!(i8, [i8::MIN, i8::MIN + 1, -1, 0, 1, i8::MAX - 1, i8::MAX]);
+        check!(i16, [i16::MIN, -256, -1, 0, 1, 255, i16::MAX]);
+        check!(i32, [i32::MIN, -65536, -1, 0, 1, 65535, i32::MAX]);
+        check!(i64, [i64::MIN, -(1 << 32), -1, 0, 1, 1 << 32, i64::MAX]);
+        check!(i128, [i128::MIN, -(1 << 64), -1, 0, 1, 1 << 64, i128::MAX]);
+        check!(u8, [0, 1, 127, 128, u8::MAX]);
+        check!(u16, [0, 1, 255, 256, u16::MAX]);
+        check!(u32, [0, 1, 65535, 65536, u32::MAX]);
+        check!(u64, [0, 1, u32::MAX as u64, 1 << 32, u64::MAX]);
+        check!(u128, [0, 1, u64::MAX as u128, 1 << 64, u128::MAX]);
+    }
+
+    /// Checks the given equality instruction against native equality, on distinct values of every literal type.
+    pub fn test_equality<Op: Operation<P> + Opcode>(output: fn(bool) -> bool) {
+        let values_per_type = [
+            vec![
+                "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah",
+                "aleo1t9r2aalldn3al4346l3pdplj8prrz5svvahsrl64gp4023342sxsrhs2yg",
+            ],
+            vec!["true", "false"],
+            vec!["0field", "1field", "2field"],
+            vec!["0group", "2group"],
+            vec!["-128i8", "-1i8", "0i8", "1i8", "127i8"],
+            vec!["-1i16", "0i16", "1i16"],
+            vec!["-1i32", "0i32", "1i32"],
+            vec!["-1i64", "0i64", "1i64"],
+            vec!["-1i128", "0i128", "1i128"],
+            vec!["0scalar", "1scalar", "2scalar"],
+            vec!["0u8", "1u8", "255u8"],
+            vec!["0u16", "1u16"],
+            vec!["0u32", "1u32"],
+            vec!["0u64", "1u64"],
+            vec!["0u128", "1u128"],
+        ];
+        for values in values_per_type.iter() {
+            for a in values {
+                for b in values {
+                    let expected = format!("{}.private", output(a == b));
+                    test_binary::<Op>(&format!("{a}.public"), &format!("{b}.private"), &expected);
+                }
+            }
+        }
+    }
+
     pub fn test_unary<Op: Operation<P> + Opcode>(input_str: &str, expected_str: &str) {
         let input = Value::<P>::from_str(input_str);
         let expected = Value::<P>::from_str(expected_str);
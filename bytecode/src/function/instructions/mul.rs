@@ -404,6 +404,52 @@ mod tests {
         );
     }
 
+    /// Returns the string representation of the given scalar multiple of the generator.
+    fn generator_times(scalar: &str) -> String {
+        use snarkvm_curves::{edwards_bls12::EdwardsAffine, AffineCurve, ProjectiveCurve};
+
+        let generator = EdwardsAffine::prime_subgroup_generator();
+        let scalar = std::str::FromStr::from_str(scalar).unwrap();
+        format!("{}group", (generator * scalar).to_affine().to_x_coordinate())
+    }
+
+    test_modes!(group_times_scalar, Mul, generator_times("1"), "3scalar", generator_times("3"));
+    test_modes!(scalar_times_group, Mul, "3scalar", generator_times("1"), generator_times("3"));
+
+    #[test]
+    fn test_group_scalar_mul_matches_native() {
+        use crate::function::instructions::tests::test_binary;
+        use snarkvm_curves::{
+            edwards_bls12::{EdwardsAffine, Fr},
+            AffineCurve,
+            ProjectiveCurve,
+        };
+        use snarkvm_utilities::{test_rng, UniformRand};
+
+        let generator = EdwardsAffine::prime_subgroup_generator();
+        let group = format!("{}group", generator.to_x_coordinate());
+
+        for _ in 0..10 {
+            // Sample a random scalar, and compute the expected point natively.
+            let scalar = Fr::rand(&mut test_rng());
+            let expected = format!("{}group", (generator * scalar).to_affine().to_x_coordinate());
+
+            for mode in ["constant", "public", "private"] {
+                let expected_mode = if mode == "constant" { "constant" } else { "private" };
+                test_binary::<Mul<P>>(
+                    &format!("{group}.{mode}"),
+                    &format!("{scalar}scalar.{mode}"),
+                    &format!("{expected}.{expected_mode}"),
+                );
+                test_binary::<Mul<P>>(
+                    &format!("{scalar}scalar.{mode}"),
+                    &format!("{group}.{mode}"),
+                    &format!("{expected}.{expected_mode}"),
+                );
+            }
+        }
+    }
+
     test_modes!(i8, Mul, "1i8", "2i8", "2i8");
     test_modes!(i16, Mul, "1i16", "2i16", "2i16");
     test_modes!(i32, Mul, "1i32", "2i32", "2i32");
@@ -493,6 +539,27 @@ mod tests {
         "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.constant",
         "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.constant"
     );
+    test_instruction_halts!(
+        group_and_group_halts,
+        Mul,
+        "Invalid 'mul' instruction",
+        "2group.constant",
+        "2group.constant"
+    );
+    test_instruction_halts!(
+        group_and_field_halts,
+        Mul,
+        "Invalid 'mul' instruction",
+        "2group.constant",
+        "1field.constant"
+    );
+    test_instruction_halts!(
+        scalar_and_scalar_halts,
+        Mul,
+        "Invalid 'mul' instruction",
+        "1scalar.constant",
+        "1scalar.constant"
+    );
     test_instruction_halts!(boolean_halts, Mul, "Invalid 'mul' instruction", "true.constant", "true.constant");
     test_instruction_halts!(string_halts, Mul, "Invalid 'mul' instruction", "\"hello\".constant", "\"world\".constant");
 
@@ -73,6 +73,11 @@ impl<P: Program> Operation<P> for Nor<P> {
 
         registers.assign(self.operation.destination(), result);
     }
+
+    #[inline]
+    fn can_halt() -> bool {
+        false
+    }
 }
 
 impl<P: Program> Parser for Nor<P> {
@@ -79,6 +79,11 @@ impl<P: Program> Operation<P> for Not<P> {
 
         registers.assign(self.operation.destination(), result);
     }
+
+    #[inline]
+    fn can_halt() -> bool {
+        false
+    }
 }
 
 impl<P: Program> Parser for Not<P> {
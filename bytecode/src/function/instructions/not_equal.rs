@@ -87,6 +87,11 @@ impl<P: Program> Operation<P> for NotEqual<P> {
 
         registers.assign(self.operation.destination(), result);
     }
+
+    #[inline]
+    fn can_halt() -> bool {
+        false
+    }
 }
 
 impl<P: Program> Parser for NotEqual<P> {
@@ -197,6 +202,20 @@ mod tests {
     binary_instruction_test!(u128_ne, NotEqual, "1u128.public", "2u128.public", "true.private");
 
     test_instruction_halts!(string_halts, NotEqual, "Invalid 'neq' instruction", "\"hello\"", "\"hello\"");
+    test_instruction_halts!(field_group_halts, NotEqual, "Invalid 'neq' instruction", "1field.public", "2group.public");
+    test_instruction_halts!(i8_u8_halts, NotEqual, "Invalid 'neq' instruction", "1i8.public", "1u8.public");
+    test_instruction_halts!(
+        address_boolean_halts,
+        NotEqual,
+        "Invalid 'neq' instruction",
+        "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public",
+        "true.public"
+    );
+
+    #[test]
+    fn test_matches_native() {
+        crate::function::instructions::tests::test_equality::<NotEqual<Process>>(|is_equal| !is_equal);
+    }
 
     #[test]
     #[should_panic(expected = "message is not a literal")]
@@ -19,7 +19,7 @@ use crate::{
     Program,
     Value,
 };
-use snarkvm_circuit::{Literal, Parser, ParserResult, Pow as PowCircuit, PowChecked};
+use snarkvm_circuit::{Literal, Parser, ParserResult, Pow as PowCircuit, PowChecked, ToField};
 use snarkvm_utilities::{FromBytes, ToBytes};
 
 use core::fmt;
@@ -68,6 +68,9 @@ impl<P: Program> Operation<P> for Pow<P> {
         // Perform the operation.
         let result = match (first, second) {
             (Literal::Field(a), Literal::Field(b)) => Literal::Field(a.pow(b)),
+            (Literal::Field(a), Literal::U8(b)) => Literal::Field(a.pow(b.to_field())),
+            (Literal::Field(a), Literal::U16(b)) => Literal::Field(a.pow(b.to_field())),
+            (Literal::Field(a), Literal::U32(b)) => Literal::Field(a.pow(b.to_field())),
             (Literal::I8(a), Literal::U8(b)) => Literal::I8(a.pow_checked(&b)),
             (Literal::I8(a), Literal::U16(b)) => Literal::I8(a.pow_checked(&b)),
             (Literal::I8(a), Literal::U32(b)) => Literal::I8(a.pow_checked(&b)),
@@ -155,6 +158,12 @@ mod tests {
     binary_instruction_test!(field_pow_1, Pow, "2field.public", "1field.public", "2field.private");
     binary_instruction_test!(field_pow_0, Pow, "2field.public", "0field.public", "1field.private");
 
+    test_modes!(field_pow_u8, Pow, "2field", "3u8", "8field");
+    test_modes!(field_pow_u16, Pow, "2field", "3u16", "8field");
+    test_modes!(field_pow_u32, Pow, "2field", "3u32", "8field");
+    binary_instruction_test!(field_pow_u8_0, Pow, "2field.public", "0u8.public", "1field.private");
+    binary_instruction_test!(field_pow_u32_max, Pow, "1field.public", "4294967295u32.public", "1field.private");
+
     test_modes!(i8_pow_u8, Pow, "2i8", "2u8", "4i8");
     test_modes!(i8_pow_u16, Pow, "2i8", "2u16", "4i8");
     test_modes!(i8_pow_u32, Pow, "2i8", "2u32", "4i8");
@@ -266,6 +275,14 @@ mod tests {
         "2u8.constant"
     );
 
+    test_instruction_halts!(
+        u8_small_overflow_halts,
+        Pow,
+        "Integer overflow on exponentiation of two constants",
+        "16u8.constant",
+        "2u8.constant"
+    );
+
     test_instruction_halts!(
         address_halts,
         Pow,
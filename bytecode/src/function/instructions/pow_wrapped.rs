@@ -102,6 +102,11 @@ impl<P: Program> Operation<P> for PowWrapped<P> {
 
         registers.assign(self.operation.destination(), result);
     }
+
+    #[inline]
+    fn can_halt() -> bool {
+        false
+    }
 }
 
 impl<P: Program> Parser for PowWrapped<P> {
@@ -16,6 +16,7 @@
 
 use crate::{
     function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    HaltReason,
     Program,
     Value,
 };
@@ -58,11 +59,11 @@ impl<P: Program> Operation<P> for ShlWrapped<P> {
         // Load the values for the first and second operands.
         let first = match registers.load(self.operation.first()) {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => P::halt_with(HaltReason::NotALiteral(name.to_string())),
         };
         let second = match registers.load(self.operation.second()) {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => P::halt_with(HaltReason::NotALiteral(name.to_string())),
         };
 
         // Perform the operation.
@@ -97,7 +98,7 @@ impl<P: Program> Operation<P> for ShlWrapped<P> {
             (Literal::U128(a), Literal::U8(b)) => Literal::U128(a.shl_wrapped(&b)),
             (Literal::U128(a), Literal::U16(b)) => Literal::U128(a.shl_wrapped(&b)),
             (Literal::U128(a), Literal::U32(b)) => Literal::U128(a.shl_wrapped(&b)),
-            _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
+            _ => P::halt_with(HaltReason::InvalidOperandType(Self::opcode())),
         };
 
         registers.assign(self.operation.destination(), result);
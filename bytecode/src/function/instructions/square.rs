@@ -69,6 +69,11 @@ impl<P: Program> Operation<P> for Square<P> {
 
         registers.assign(self.operation.destination(), result);
     }
+
+    #[inline]
+    fn can_halt() -> bool {
+        false
+    }
 }
 
 impl<P: Program> Parser for Square<P> {
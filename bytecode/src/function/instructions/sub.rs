@@ -342,9 +342,68 @@ mod tests {
         "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.constant",
         "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.constant"
     );
+    test_instruction_halts!(
+        group_and_field_halts,
+        Sub,
+        "Invalid 'sub' instruction",
+        "2group.constant",
+        "1field.constant"
+    );
+    test_instruction_halts!(
+        group_and_scalar_halts,
+        Sub,
+        "Invalid 'sub' instruction",
+        "2group.constant",
+        "1scalar.constant"
+    );
     test_instruction_halts!(boolean_halts, Sub, "Invalid 'sub' instruction", "true.constant", "true.constant");
     test_instruction_halts!(string_halts, Sub, "Invalid 'sub' instruction", "\"hello\".constant", "\"world\".constant");
 
+    #[test]
+    fn test_group_subtraction_matches_native() {
+        use crate::function::instructions::tests::test_binary;
+        use snarkvm_curves::{
+            edwards_bls12::{EdwardsAffine, Fr},
+            AffineCurve,
+            ProjectiveCurve,
+        };
+        use snarkvm_utilities::{test_rng, UniformRand};
+
+        let generator = EdwardsAffine::prime_subgroup_generator();
+
+        for _ in 0..10 {
+            // Sample two random points, and compute the expected difference natively.
+            let a = (generator * Fr::rand(&mut test_rng())).to_affine();
+            let b = (generator * Fr::rand(&mut test_rng())).to_affine();
+            let expected = (a.to_projective() - b.to_projective()).to_affine();
+
+            let (a, b) = (format!("{}group", a.to_x_coordinate()), format!("{}group", b.to_x_coordinate()));
+            let negated_expected = format!("{}group", (-expected).to_x_coordinate());
+            let expected = format!("{}group", expected.to_x_coordinate());
+
+            for mode in ["constant", "public", "private"] {
+                let expected_mode = if mode == "constant" { "constant" } else { "private" };
+                // Ensure the subtraction matches the native result, and that `b - a == -(a - b)`.
+                test_binary::<Sub<Process>>(
+                    &format!("{a}.{mode}"),
+                    &format!("{b}.{mode}"),
+                    &format!("{expected}.{expected_mode}"),
+                );
+                test_binary::<Sub<Process>>(
+                    &format!("{b}.{mode}"),
+                    &format!("{a}.{mode}"),
+                    &format!("{negated_expected}.{expected_mode}"),
+                );
+                // Ensure `P - P == 0group`.
+                test_binary::<Sub<Process>>(
+                    &format!("{a}.{mode}"),
+                    &format!("{a}.{mode}"),
+                    &format!("0group.{expected_mode}"),
+                );
+            }
+        }
+    }
+
     #[test]
     #[should_panic(expected = "message is not a literal")]
     fn test_definition_halts() {
@@ -101,6 +101,11 @@ impl<P: Program> Operation<P> for SubWrapped<P> {
 
         registers.assign(self.operation.destination(), result);
     }
+
+    #[inline]
+    fn can_halt() -> bool {
+        false
+    }
 }
 
 impl<P: Program> Metrics<Self> for SubWrapped<P> {
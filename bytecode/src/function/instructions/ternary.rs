@@ -23,7 +23,9 @@ use snarkvm_circuit::{
     Boolean,
     Field,
     Group,
+    Inject,
     Literal,
+    Mode,
     Parser,
     ParserResult,
     Scalar,
@@ -51,6 +53,21 @@ pub struct Ternary<P: Program> {
 }
 
 impl<P: Program> Ternary<P> {
+    /// Initializes a new `ternary` instruction that assigns the given constant literal to `destination`,
+    /// by selecting the literal on a constant `true` condition.
+    ///
+    /// Returns `None` if the literal type is not supported by `ternary` (i.e. addresses and strings).
+    pub(crate) fn constant(literal: Literal<P::Environment>, destination: Register<P>) -> Option<Self> {
+        match literal {
+            Literal::Address(..) | Literal::String(..) => None,
+            literal => {
+                let condition = Operand::Value(Literal::Boolean(Boolean::new(Mode::Constant, true)).into());
+                let literal = Operand::Value(literal.into());
+                Some(Self { operation: TernaryOperation::new(condition, literal.clone(), literal, destination) })
+            }
+        }
+    }
+
     /// Returns the operands of the instruction.
     pub fn operands(&self) -> Vec<Operand<P>> {
         self.operation.operands()
@@ -137,6 +154,11 @@ impl<P: Program> Operation<P> for Ternary<P> {
 
         registers.assign(self.operation.destination(), result);
     }
+
+    #[inline]
+    fn can_halt() -> bool {
+        false
+    }
 }
 
 impl<P: Program> Parser for Ternary<P> {
@@ -0,0 +1,278 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    definition::Member,
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    Annotation,
+    Definition,
+    Identifier,
+    LiteralType,
+    Program,
+    Value,
+};
+use snarkvm_circuit::{
+    Boolean,
+    Eject,
+    Environment,
+    FromBits,
+    Inject,
+    Literal,
+    Mode,
+    Parser,
+    ParserResult,
+    PrimeField,
+    ToBits,
+    U8,
+};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use core::fmt;
+use nom::combinator::map;
+use std::io::{Read, Result as IoResult, Write};
+
+/// The name of the built-in definition for the bytes of a public or private field element.
+pub(super) const BYTES_IDENTIFIER: &str = "to_bytes";
+/// The name of the built-in definition for the bytes of a constant field element.
+pub(super) const CONSTANT_BYTES_IDENTIFIER: &str = "to_bytes_constant";
+
+/// Returns the number of bytes in the little-endian encoding of a field element,
+/// which is the size of the base field in bits, rounded up to the nearest byte.
+pub(super) fn num_field_bytes<P: Program>() -> usize {
+    (<<P::Environment as Environment>::BaseField as PrimeField>::size_in_bits() + 7) / 8
+}
+
+/// Returns the built-in definition with the given name, if it is one of the definitions output by `to.bytes`.
+///
+/// The `to_bytes` and `to_bytes_constant` definitions are structs with the members `b0`, `b1`, ..., one for
+/// each little-endian byte of a field element. The members of `to_bytes` are `u8.private`, as output by
+/// `to.bytes` on a public or private field, and the members of `to_bytes_constant` are `u8.constant`,
+/// as output by `to.bytes` on a constant field. These definitions are built into every program,
+/// so they may be used as input and output annotations, and they cannot be redeclared.
+pub(crate) fn bytes_definition<P: Program>(name: &Identifier<P>) -> Option<Definition<P>> {
+    let mode = match name.as_str() {
+        BYTES_IDENTIFIER => Mode::Private,
+        CONSTANT_BYTES_IDENTIFIER => Mode::Constant,
+        _ => return None,
+    };
+    let members = (0..num_field_bytes::<P>())
+        .map(|i| Member::new(Identifier::from_str(&format!("b{i}")), Annotation::Literal(LiteralType::U8(mode))))
+        .collect();
+    Some(Definition::Struct(name.clone(), members))
+}
+
+/// Decomposes the field element `first` into its little-endian bytes, storing the outcome in `destination`.
+///
+/// The outcome is a `to_bytes` value (or `to_bytes_constant`, for a constant field element),
+/// with `u8` members, one for each byte of the field element. See `bytes_definition` for the definitions.
+/// The number of bytes is the size of the base field in bits, rounded up to the nearest byte,
+/// and matches the length of the console encoding from `ToBytes::to_bytes_le`.
+pub struct FieldToBytes<P: Program> {
+    operation: UnaryOperation<P>,
+}
+
+impl<P: Program> FieldToBytes<P> {
+    /// Returns the operands of the instruction.
+    pub fn operands(&self) -> Vec<Operand<P>> {
+        self.operation.operands()
+    }
+
+    /// Returns the destination register of the instruction.
+    pub fn destination(&self) -> &Register<P> {
+        self.operation.destination()
+    }
+}
+
+impl<P: Program> Opcode for FieldToBytes<P> {
+    /// Returns the opcode as a string.
+    #[inline]
+    fn opcode() -> &'static str {
+        "to.bytes"
+    }
+}
+
+impl<P: Program> Operation<P> for FieldToBytes<P> {
+    /// Evaluates the operation.
+    #[inline]
+    fn evaluate(&self, registers: &Registers<P>) {
+        // Load the field element from the operand.
+        let field = match registers.load(self.operation.first()) {
+            Value::Literal(Literal::Field(field)) => field,
+            Value::Literal(literal) => {
+                P::halt(format!("Invalid '{}' instruction: expected a field, found {literal}", Self::opcode()))
+            }
+            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+        };
+
+        // Decompose the field element into bits, and pad the bits up to a multiple of 8.
+        let mut bits_le = field.to_bits_le();
+        bits_le.resize(num_field_bytes::<P>() * 8, Boolean::constant(false));
+
+        // Pack the bits into bytes.
+        let bytes = bits_le.chunks(8).map(|bits_le| Value::Literal(Literal::U8(U8::from_bits_le(bits_le)))).collect();
+        let name = match field.is_constant() {
+            true => CONSTANT_BYTES_IDENTIFIER,
+            false => BYTES_IDENTIFIER,
+        };
+        let result = Value::Definition(Identifier::from_str(name), bytes);
+
+        registers.assign(self.operation.destination(), result);
+    }
+}
+
+impl<P: Program> Parser for FieldToBytes<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into a 'to.bytes' operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the operation from the string.
+        map(UnaryOperation::parse, |operation| Self { operation })(string)
+    }
+}
+
+impl<P: Program> fmt::Display for FieldToBytes<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.operation)
+    }
+}
+
+impl<P: Program> FromBytes for FieldToBytes<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self { operation: UnaryOperation::read_le(&mut reader)? })
+    }
+}
+
+impl<P: Program> ToBytes for FieldToBytes<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.operation.write_le(&mut writer)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<P: Program> Into<Instruction<P>> for FieldToBytes<P> {
+    /// Converts the operation into an instruction.
+    fn into(self) -> Instruction<P> {
+        Instruction::FieldToBytes(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{function::instructions::FieldFromBytes, test_instruction_halts, Function, Process};
+    use snarkvm_circuit::{AleoV0 as Circuit, Eject, Field, Mode};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    type P = Process;
+
+    const ITERATIONS: usize = 100;
+
+    /// Evaluates `to.bytes` and `from.bytes` on the given field element, returning the bytes and the recovered field.
+    fn check_round_trip(field: Field<Circuit>) {
+        let expected = field.eject_value();
+        let is_constant = field.is_constant();
+
+        let registers = Registers::<P>::default();
+        registers.define(&Register::from_str("r0"));
+        registers.define(&Register::from_str("r1"));
+        registers.define(&Register::from_str("r2"));
+        registers.assign(&Register::from_str("r0"), Value::Literal(Literal::Field(field)));
+
+        FieldToBytes::from_str("r0 into r1").evaluate(&registers);
+        FieldFromBytes::from_str("r1 into r2").evaluate(&registers);
+
+        // Ensure the bytes match the console encoding.
+        let value = registers.load(&Register::from_str("r1"));
+        let bytes = match value.clone() {
+            Value::Definition(name, members) => {
+                // Ensure the bytes match their built-in definition.
+                let expected_name = match is_constant {
+                    true => CONSTANT_BYTES_IDENTIFIER,
+                    false => BYTES_IDENTIFIER,
+                };
+                assert_eq!(expected_name, name.as_str());
+                assert!(bytes_definition::<P>(&name).unwrap().matches(&value));
+                members
+                    .into_iter()
+                    .map(|member| match member {
+                        Value::Literal(Literal::U8(byte)) => byte.eject_value(),
+                        _ => panic!("Expected a u8 member, found {member}"),
+                    })
+                    .collect::<Vec<_>>()
+            }
+            value => panic!("Expected a bytes value, found {value}"),
+        };
+        assert_eq!(num_field_bytes::<P>(), bytes.len());
+        assert_eq!(expected.to_bytes_le().unwrap(), bytes);
+
+        // Ensure the field element is recovered.
+        match registers.load(&Register::from_str("r2")) {
+            Value::Literal(Literal::Field(candidate)) => assert_eq!(expected, candidate.eject_value()),
+            value => panic!("Expected a field, found {value}"),
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_parse() {
+        let (_, instruction) = Instruction::<P>::parse("to.bytes r0 into r1;").unwrap();
+        assert!(matches!(instruction, Instruction::FieldToBytes(_)));
+    }
+
+    #[test]
+    fn test_num_field_bytes() {
+        assert_eq!(32, num_field_bytes::<P>());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let rng = &mut test_rng();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_round_trip(Field::new(mode, <Circuit as Environment>::BaseField::from(0u64)));
+            check_round_trip(Field::new(mode, -<Circuit as Environment>::BaseField::from(1u64)));
+            for _ in 0..ITERATIONS {
+                check_round_trip(Field::new(mode, UniformRand::rand(rng)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_function_output() {
+        let function = Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as field.private;
+    input r1 as field.constant;
+    to.bytes r0 into r2;
+    to.bytes r1 into r3;
+    output r2 as to_bytes;
+    output r3 as to_bytes_constant;",
+        );
+
+        // Ensure the bytes are output with their built-in definitions.
+        let inputs = [Value::from_str("5field.private"), Value::from_str("5field.constant")];
+        let outputs = function.evaluate(&inputs);
+        assert!(P::get_definition(&Identifier::from_str(BYTES_IDENTIFIER)).unwrap().matches(&outputs[0]));
+        assert!(P::get_definition(&Identifier::from_str(CONSTANT_BYTES_IDENTIFIER)).unwrap().matches(&outputs[1]));
+        Circuit::reset();
+    }
+
+    test_instruction_halts!(u8_halts, FieldToBytes, "Invalid 'to.bytes' instruction", "1u8.constant");
+    test_instruction_halts!(group_halts, FieldToBytes, "Invalid 'to.bytes' instruction", "2group.constant");
+}
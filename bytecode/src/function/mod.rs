@@ -18,12 +18,14 @@ mod input;
 use input::*;
 
 mod instructions;
+pub(crate) use instructions::bytes_definition;
 use instructions::*;
 
 mod output;
 use output::*;
 
 mod parsers;
+use parsers::Operand;
 
 mod register;
 pub(super) use register::*;
@@ -35,7 +37,7 @@ use crate::{Annotation, Identifier, Program, Sanitizer, Value};
 use snarkvm_circuit::prelude::*;
 use snarkvm_utilities::{error, FromBytes, ToBytes};
 
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 use std::{
     cell::RefCell,
     io::{Read, Result as IoResult, Write},
@@ -213,6 +215,64 @@ impl<P: Program> Function<P> {
         self.outputs.borrow_mut().insert(output);
     }
 
+    /// Folds every instruction whose operands are all constant, by evaluating it on its constant operands
+    /// and replacing it with a single instruction that assigns the constant output to its destination register.
+    /// An operand is constant if it is a constant value, or a register holding the output of a folded instruction.
+    /// As the operands are constant, the evaluation adds no constraints to the circuit.
+    ///
+    /// Instructions whose output is not a literal supported by `ternary` (i.e. definitions, addresses,
+    /// and strings) are left in place. Returns the number of folded instructions.
+    ///
+    /// # Errors
+    /// This method will halt if a folded instruction halts (i.e. a constant division by zero),
+    /// as the instruction would otherwise halt on every evaluation of the function.
+    #[inline]
+    pub fn fold_constants(&self) -> usize {
+        // The map of destination registers to the constant outputs of their folded instructions.
+        let mut folded = IndexMap::new();
+        let mut num_folded = 0;
+
+        for instruction in self.instructions.borrow_mut().iter_mut() {
+            // Ensure every operand is constant.
+            let is_constant = instruction.operands().iter().all(|operand| match operand {
+                Operand::Value(value) => value.is_constant(),
+                Operand::Register(register @ Register::Locator(..)) => folded.contains_key(register),
+                Operand::Register(Register::Member(..)) => false,
+            });
+            if !is_constant {
+                continue;
+            }
+
+            // Initialize scratch registers up to the destination register, assigning the folded outputs.
+            // Registers must be assigned in order, so the remaining registers are assigned a placeholder.
+            // The placeholders are never loaded, as every register operand was checked to hold a folded output.
+            let destination = instruction.destination().clone();
+            let registers = Registers::<P>::new();
+            (0..=*destination.locator()).for_each(|locator| registers.define(&Register::Locator(locator)));
+            for locator in 0..*destination.locator() {
+                let register = Register::Locator(locator);
+                match folded.get(&register) {
+                    Some(value) => registers.assign(&register, Value::clone(value)),
+                    None => registers.assign(&register, Literal::Boolean(Boolean::constant(false))),
+                }
+            }
+
+            // Evaluate the instruction, and replace it with a constant assignment of its output.
+            instruction.evaluate(&registers);
+            let value = registers.load(&destination);
+            if let Some(constant) = Instruction::constant(value.clone(), destination.clone()) {
+                // Skip counting instructions that are already constant assignments, i.e. from a previous pass.
+                if constant.to_string() != instruction.to_string() {
+                    *instruction = constant;
+                    num_folded += 1;
+                }
+                folded.insert(destination, value);
+            }
+        }
+
+        num_folded
+    }
+
     /// Evaluates the function on the given inputs.
     ///
     /// # Errors
@@ -518,6 +578,55 @@ function foo:
         assert_eq!(expected.to_string(), candidate[0].to_string());
     }
 
+    #[test]
+    fn test_function_fold_constants() {
+        let function = Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as u8.private;
+    add 1u8.constant 2u8.constant into r1;
+    add r1 3u8.constant into r2;
+    add r0 r2 into r3;
+    output r1 as u8.constant;
+    output r2 as u8.constant;
+    output r3 as u8.private;",
+        );
+        let expected = function.evaluate(&[Value::from_str("4u8.private")]);
+
+        // Fold the constant instructions.
+        assert_eq!(2, function.fold_constants());
+        let instructions = function.instructions.borrow().iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "ternary true.constant 3u8.constant 3u8.constant into r1;",
+                "ternary true.constant 6u8.constant 6u8.constant into r2;",
+                "add r0 r2 into r3;",
+            ],
+            instructions
+        );
+
+        // Ensure folding again is a no-op.
+        assert_eq!(0, function.fold_constants());
+
+        // Ensure the folded function is equivalent.
+        let candidate = function.evaluate(&[Value::from_str("4u8.private")]);
+        assert_eq!(expected, candidate);
+        assert_eq!("10u8.private", candidate[2].to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero error")]
+    fn test_function_fold_constants_halts() {
+        let function = Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as u8.private;
+    div 1u8.constant 0u8.constant into r1;
+    output r1 as u8.constant;",
+        );
+        function.fold_constants();
+    }
+
     #[test]
     fn test_function_parse() {
         let function = Function::<P>::parse(
@@ -19,6 +19,7 @@ use snarkvm_circuit::prelude::*;
 use snarkvm_utilities::{error, FromBytes, ToBytes};
 
 use core::fmt;
+use nom::error::{VerboseError, VerboseErrorKind};
 use std::io::{Read, Result as IoResult, Write};
 
 /// The operand enum represents the complete set of options for operands in an instruction.
@@ -108,6 +109,21 @@ impl<P: Program> Operand<P> {
     pub fn is_register(&self) -> bool {
         matches!(self, Operand::Register(_))
     }
+
+    /// Parses a string into a destination register.
+    ///
+    /// # Errors
+    /// This function returns a non-recoverable parse error if the destination is a value,
+    /// as the destination of an instruction must always be a register.
+    #[inline]
+    pub(crate) fn parse_destination(string: &str) -> ParserResult<Register<P>> {
+        match Self::parse(string) {
+            Ok((_, Self::Value(..))) => Err(nom::Err::Failure(VerboseError {
+                errors: vec![(string, VerboseErrorKind::Context("The destination must be a register, found a value"))],
+            })),
+            _ => Register::parse(string),
+        }
+    }
 }
 
 impl<P: Program> Parser for Operand<P> {
@@ -228,4 +244,51 @@ mod tests {
         let operand = Operand::<P>::parse("r0.owner").unwrap().1;
         assert_eq!(format!("{operand}"), "r0.owner");
     }
+
+    #[test]
+    fn test_operand_parse_destination() {
+        // Ensure register destinations continue to parse.
+        assert_eq!(("", Register::<P>::from_str("r0")), Operand::<P>::parse_destination("r0").unwrap());
+        assert_eq!((";", Register::<P>::from_str("r1.owner")), Operand::<P>::parse_destination("r1.owner;").unwrap());
+
+        // Ensure value destinations fail with a descriptive, non-recoverable error.
+        for destination in ["5u8;", "1field.private", "true", "message { 2group.public }"] {
+            match Operand::<P>::parse_destination(destination) {
+                Err(nom::Err::Failure(error)) => assert_eq!(
+                    vec![(destination, VerboseErrorKind::Context("The destination must be a register, found a value"))],
+                    error.errors
+                ),
+                result => panic!("Expected a parse failure for '{destination}', found {result:?}"),
+            }
+        }
+
+        // Ensure a malformed destination fails with a recoverable error.
+        assert!(matches!(Operand::<P>::parse_destination("x0"), Err(nom::Err::Error(..))));
+    }
+
+    #[test]
+    fn test_instruction_with_value_destination() {
+        use crate::function::Instruction;
+
+        // Ensure instructions with a register destination continue to parse.
+        for instruction in ["add r0 r1 into r2;", "neg r0 into r1;", "ternary r0 r1 r2 into r3;"] {
+            let (remainder, candidate) = Instruction::<P>::parse(instruction).unwrap();
+            assert!(remainder.is_empty());
+            assert_eq!(instruction, candidate.to_string());
+        }
+
+        // Ensure instructions with a value destination fail with a descriptive error.
+        for instruction in ["add r0 r1 into 5u8;", "neg r0 into 1field;", "ternary r0 r1 r2 into true;"] {
+            match Instruction::<P>::parse(instruction) {
+                Err(nom::Err::Failure(error)) => {
+                    let message = nom::error::convert_error(instruction, error);
+                    assert!(message.contains("The destination must be a register, found a value"), "{message}");
+                }
+                result => panic!(
+                    "Expected a parse failure for '{instruction}', found {:?}",
+                    result.map(|(_, i)| i.to_string())
+                ),
+            }
+        }
+    }
 }
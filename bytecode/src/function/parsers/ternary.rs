@@ -30,6 +30,11 @@ pub(crate) struct TernaryOperation<P: Program> {
 }
 
 impl<P: Program> TernaryOperation<P> {
+    /// Initializes a new ternary operation.
+    pub(crate) fn new(condition: Operand<P>, first: Operand<P>, second: Operand<P>, destination: Register<P>) -> Self {
+        Self { condition, first, second, destination }
+    }
+
     /// Returns the operands.
     pub fn operands(&self) -> Vec<Operand<P>> {
         vec![self.condition.clone(), self.first.clone(), self.second.clone()]
@@ -74,7 +79,7 @@ impl<P: Program> Parser for TernaryOperation<P> {
         // Parse the space from the string.
         let (string, _) = tag(" into ")(string)?;
         // Parse the destination register from the string.
-        let (string, destination) = Register::parse(string)?;
+        let (string, destination) = Operand::parse_destination(string)?;
 
         Ok((string, Self { destination, condition, first, second }))
     }
@@ -55,7 +55,7 @@ impl<P: Program> Parser for UnaryOperation<P> {
         // Parse the " into " from the string.
         let (string, _) = tag(" into ")(string)?;
         // Parse the destination register from the string.
-        let (string, destination) = Register::parse(string)?;
+        let (string, destination) = Operand::parse_destination(string)?;
 
         Ok((string, Self { destination, first }))
     }
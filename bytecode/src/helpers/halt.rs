@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use core::{cell::RefCell, fmt};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+thread_local! {
+    /// The reason for the most recent halt on this thread, if it was halted with a reason.
+    static HALT_REASON: RefCell<Option<HaltReason>> = const { RefCell::new(None) };
+}
+
+/// The reason a program halted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HaltReason {
+    /// The divisor of a division is zero.
+    DivisionByZero,
+    /// The division of two signed integer constants overflows (i.e. `MIN / -1`), as reported by the circuit.
+    Overflow,
+    /// The operand types are invalid for the instruction with the given opcode.
+    InvalidOperandType(&'static str),
+    /// The value with the given name is not a literal.
+    NotALiteral(String),
+    /// The program halted with the given message, without a structured reason.
+    Other(String),
+}
+
+impl HaltReason {
+    /// Records the given reason as the most recent halt on this thread.
+    pub(crate) fn record(reason: HaltReason) {
+        HALT_REASON.with(|halt_reason| *halt_reason.borrow_mut() = Some(reason));
+    }
+
+    /// Runs the given operation, returning the reason if it halts.
+    ///
+    /// Note that any state shared with the operation may be left partially updated if the operation halts.
+    pub fn catch<R, F: FnOnce() -> R>(operation: F) -> Result<R, HaltReason> {
+        // Clear any previously-recorded reason.
+        HALT_REASON.with(|halt_reason| halt_reason.borrow_mut().take());
+
+        catch_unwind(AssertUnwindSafe(operation)).map_err(|payload| {
+            match HALT_REASON.with(|halt_reason| halt_reason.borrow_mut().take()) {
+                Some(reason) => reason,
+                // Otherwise, recover the halt message from the panic payload.
+                None => match (payload.downcast_ref::<String>(), payload.downcast_ref::<&str>()) {
+                    (Some(message), _) => HaltReason::from_message(message),
+                    (None, Some(message)) => HaltReason::from_message(message),
+                    (None, None) => HaltReason::Other("Unknown halt".to_string()),
+                },
+            }
+        })
+    }
+
+    /// Returns the reason for a halt with the given message, such as a halt from within a circuit gadget,
+    /// which reports the message of the matching reason.
+    fn from_message(message: &str) -> Self {
+        [Self::DivisionByZero, Self::Overflow]
+            .into_iter()
+            .find(|reason| reason.to_string() == message)
+            .unwrap_or_else(|| Self::Other(message.to_string()))
+    }
+}
+
+impl fmt::Display for HaltReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::DivisionByZero => write!(f, "Division by zero error"),
+            Self::Overflow => write!(f, "Overflow or underflow on division of two integer constants"),
+            Self::InvalidOperandType(opcode) => write!(f, "Invalid '{opcode}' instruction"),
+            Self::NotALiteral(name) => write!(f, "{name} is not a literal"),
+            Self::Other(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Process, Program};
+
+    #[test]
+    fn test_catch() {
+        assert_eq!(Ok(1), HaltReason::catch(|| 1));
+
+        let result = HaltReason::catch(|| Process::halt_with::<()>(HaltReason::DivisionByZero));
+        assert_eq!(Err(HaltReason::DivisionByZero), result);
+
+        let result = HaltReason::catch(|| Process::halt::<_, ()>("message"));
+        assert_eq!(Err(HaltReason::Other("message".to_string())), result);
+
+        // Ensure a recorded reason does not leak into a later halt.
+        let result = HaltReason::catch(|| Process::halt_with::<()>(HaltReason::NotALiteral("r0".to_string())));
+        assert_eq!(Err(HaltReason::NotALiteral("r0".to_string())), result);
+        let result = HaltReason::catch(|| Process::halt::<_, ()>("later"));
+        assert_eq!(Err(HaltReason::Other("later".to_string())), result);
+
+        // Ensure a halt with the message of a reason recovers the reason.
+        let result = HaltReason::catch(|| Process::halt::<_, ()>("Division by zero error"));
+        assert_eq!(Err(HaltReason::DivisionByZero), result);
+    }
+}
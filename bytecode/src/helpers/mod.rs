@@ -17,6 +17,9 @@
 pub(super) mod annotation;
 pub(super) use annotation::*;
 
+mod halt;
+pub use halt::*;
+
 pub mod identifier;
 pub use identifier::*;
 
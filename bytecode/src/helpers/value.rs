@@ -295,4 +295,59 @@ mod tests {
         let candidate = Value::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
         assert_eq!(expected, candidate);
     }
+
+    #[test]
+    fn test_literal_display_and_parse() {
+        use snarkvm_utilities::{test_rng, Rng, UniformRand};
+
+        type E = <P as Program>::Aleo;
+
+        /// Samples a random literal of each type, in the given mode.
+        fn sample_literals(mode: Mode, rng: &mut impl Rng) -> Vec<Literal<E>> {
+            // Sample a random alphanumeric string, as strings are displayed without escaping.
+            const ALPHANUMERIC: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+            let length = rng.gen_range(0..32);
+            let string = (0..length).map(|_| ALPHANUMERIC[rng.gen_range(0..ALPHANUMERIC.len())] as char).collect();
+
+            vec![
+                Literal::Address(Address::new(mode, <E as Environment>::Affine::rand(rng))),
+                Literal::Boolean(Boolean::new(mode, rng.gen())),
+                Literal::Field(Field::new(mode, UniformRand::rand(rng))),
+                Literal::Group(Group::new(mode, <E as Environment>::Affine::rand(rng))),
+                Literal::I8(I8::new(mode, rng.gen())),
+                Literal::I16(I16::new(mode, rng.gen())),
+                Literal::I32(I32::new(mode, rng.gen())),
+                Literal::I64(I64::new(mode, rng.gen())),
+                Literal::I128(I128::new(mode, rng.gen())),
+                Literal::U8(U8::new(mode, rng.gen())),
+                Literal::U16(U16::new(mode, rng.gen())),
+                Literal::U32(U32::new(mode, rng.gen())),
+                Literal::U64(U64::new(mode, rng.gen())),
+                Literal::U128(U128::new(mode, rng.gen())),
+                Literal::Scalar(Scalar::new(mode, UniformRand::rand(rng))),
+                Literal::String(StringType::new(mode, string)),
+            ]
+        }
+
+        let rng = &mut test_rng();
+
+        for _ in 0..100 {
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                let literals = sample_literals(mode, rng);
+                assert_eq!(16, literals.len(), "Update this test to cover all literal types");
+
+                for literal in literals {
+                    let expected = Value::<P>::Literal(literal);
+                    let expected_string = expected.to_string();
+                    assert!(expected_string.ends_with(&format!(".{mode}")), "Missing mode in '{expected_string}'");
+
+                    // Ensure the displayed value parses to the same value and mode.
+                    let (remainder, candidate) = Value::<P>::parse(&expected_string).unwrap();
+                    assert!(remainder.is_empty(), "Failed to fully parse '{expected_string}'");
+                    assert_eq!(expected, candidate, "Failed to round trip '{expected_string}'");
+                    assert_eq!(expected_string, candidate.to_string());
+                }
+            }
+        }
+    }
 }
@@ -80,4 +80,10 @@ pub trait Program: Copy + Clone + Debug + Eq + PartialEq + Hash + Parser<Environ
     fn halt<S: Into<String>, T>(message: S) -> T {
         Self::Aleo::halt(message)
     }
+
+    /// Halts the program with the given reason, which is recoverable via `HaltReason::catch`.
+    fn halt_with<T>(reason: HaltReason) -> T {
+        HaltReason::record(reason.clone());
+        Self::halt(reason.to_string())
+    }
 }
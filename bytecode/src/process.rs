@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Annotation, Definition, Function, Identifier, Program, Sanitizer};
+use crate::{function::bytes_definition, Annotation, Definition, Function, Identifier, Program, Sanitizer};
 use snarkvm_circuit::{prelude::*, AleoV0};
 
 use indexmap::IndexMap;
@@ -42,9 +42,16 @@ impl Program for Process {
     /// # Errors
     /// This method will halt if the definition was previously added.
     /// This method will halt if the definition name is already in use by a definition or function.
+    /// This method will halt if the definition name is reserved for a built-in definition.
     /// This method will halt if any definitions in the definition's members are not already defined.
     #[inline]
     fn new_definition(definition: Definition<Self>) {
+        // Ensure the definition name is not reserved for a built-in definition.
+        let name = definition.name();
+        if bytes_definition::<Self>(name).is_some() {
+            Self::halt(format!("Definition '{name}' is reserved for a built-in definition"))
+        }
+
         FUNCTIONS.with(|functions| {
             // Ensure the definition name was not previously used.
             let name = definition.name();
@@ -57,7 +64,8 @@ impl Program for Process {
             // Note: This design ensures cyclic definitions are not possible.
             for member in definition.members() {
                 if let Annotation::Definition(definition_name) = member.annotation() {
-                    if !definitions.borrow().contains_key(definition_name) {
+                    let is_built_in = bytes_definition::<Self>(definition_name).is_some();
+                    if !definitions.borrow().contains_key(definition_name) && !is_built_in {
                         Self::halt(format!("Definition '{definition_name}' does not exist yet"))
                     }
                 }
@@ -82,7 +90,7 @@ impl Program for Process {
         DEFINITIONS.with(|definitions| {
             // Ensure the function name was not previously used.
             let name = function.name();
-            if definitions.borrow().contains_key(name) {
+            if definitions.borrow().contains_key(name) || bytes_definition::<Self>(name).is_some() {
                 Self::halt(format!("Function '{name}' already used by a definition"))
             }
         });
@@ -96,9 +104,11 @@ impl Program for Process {
         });
     }
 
-    /// Returns `true` if the process contains a definition with the given name.
+    /// Returns `true` if the process contains a definition with the given name,
+    /// including the built-in definitions (i.e. `to_bytes` and `to_bytes_constant`).
     fn contains_definition(name: &Identifier<Self>) -> bool {
         DEFINITIONS.with(|definitions| definitions.borrow().contains_key(name))
+            || bytes_definition::<Self>(name).is_some()
     }
 
     /// Returns `true` if the process contains a function with the given name.
@@ -106,9 +116,12 @@ impl Program for Process {
         FUNCTIONS.with(|functions| functions.borrow().contains_key(name))
     }
 
-    /// Returns the definition with the given name.
+    /// Returns the definition with the given name, including the built-in definitions
+    /// (i.e. `to_bytes` and `to_bytes_constant`).
     fn get_definition(name: &Identifier<Self>) -> Option<Definition<Self>> {
-        DEFINITIONS.with(|definitions| definitions.borrow().get(name).cloned())
+        DEFINITIONS
+            .with(|definitions| definitions.borrow().get(name).cloned())
+            .or_else(|| bytes_definition::<Self>(name))
     }
 
     /// Returns the function with the given name.
@@ -193,6 +206,27 @@ struct message:
         assert_eq!(Some(definition), Process::get_definition(&Identifier::from_str("message")),);
     }
 
+    #[test]
+    fn test_process_built_in_definitions() {
+        // Ensure the built-in definitions for the bytes of a field element are present.
+        for name in ["to_bytes", "to_bytes_constant"] {
+            let definition = Process::get_definition(&Identifier::from_str(name)).unwrap();
+            assert!(Process::contains_definition(&Identifier::from_str(name)));
+            assert_eq!(name, definition.name().as_str());
+            assert_eq!(32, definition.members().len());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Definition 'to_bytes' is reserved for a built-in definition")]
+    fn test_process_built_in_definition_is_reserved() {
+        Process::new_definition(Definition::<Process>::from_str(
+            r"
+struct to_bytes:
+    first as u8.private;",
+        ));
+    }
+
     #[test]
     fn test_process_function() {
         // Create a new function.
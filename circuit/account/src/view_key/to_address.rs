@@ -60,6 +60,36 @@ mod tests {
         Ok(())
     }
 
+    fn check_address_from_bits_le(mode: Mode) -> Result<()> {
+        use snarkvm_circuit_types::Boolean;
+
+        for i in 0..ITERATIONS {
+            // Generate a private key, compute key, view key, and address.
+            let (_private_key, _compute_key, _view_key, address) = generate_account()?;
+
+            // Compute the bits of the address x-coordinate.
+            let bits_le = Address::<Circuit>::constant(*address).to_bits_le().eject_value();
+            Circuit::reset();
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                // Inject the bits of the address x-coordinate.
+                let candidate = bits_le.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+                let candidate = Address::<Circuit>::from_bits_le(&candidate);
+                assert_eq!(*address, candidate.to_group().eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_address_from_bits_le() -> Result<()> {
+        check_address_from_bits_le(Mode::Constant)?;
+        check_address_from_bits_le(Mode::Public)?;
+        check_address_from_bits_le(Mode::Private)
+    }
+
     #[test]
     fn test_to_address_constant() -> Result<()> {
         check_to_address(Mode::Constant, 1000, 0, 0, 0)
@@ -16,7 +16,9 @@
 
 use super::*;
 
-impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Commit for BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8> Commit
+    for BHP<E, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
+{
     type Input = Boolean<E>;
     type Output = Field<E>;
     type Randomizer = Scalar<E>;
@@ -27,6 +29,17 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Commit for BH
     }
 }
 
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8> CommitmentScheme
+    for BHP<E, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
+{
+    type Boolean = Boolean<E>;
+
+    /// Returns `true` if the given BHP commitment opens to the given input and randomizer.
+    fn verify(&self, input: &[Self::Input], randomizer: &Self::Randomizer, commitment: &Self::Output) -> Self::Boolean {
+        self.commit(input, randomizer).is_equal(commitment)
+    }
+}
+
 #[cfg(all(test, console))]
 mod tests {
     use super::*;
@@ -51,7 +64,7 @@ mod tests {
         let native = console::BHP::<<Circuit as Environment>::Affine, NUM_WINDOWS, WINDOW_SIZE>::setup(DOMAIN)?;
         let circuit = BHP::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::new(Mode::Constant, native.clone());
         // Determine the number of inputs.
-        let num_input_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+        let num_input_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE as usize;
 
         for i in 0..ITERATIONS {
             // Sample a random input.
@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8>
+    BHP<E, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
+{
+    /// Returns the BHP commitment of each input and its randomizer as an affine group element.
+    ///
+    /// This is equivalent to calling `commit_uncompressed` on each input, but consecutive
+    /// inputs of the same length share the constants that encode their length.
+    pub fn commit_many(&self, inputs: &[Vec<Boolean<E>>], randomizers: &[Scalar<E>]) -> Vec<Group<E>> {
+        // Ensure there is one randomizer for each input.
+        if inputs.len() != randomizers.len() {
+            E::halt(format!("Expected {} randomizers for BHP, found {}", inputs.len(), randomizers.len()))
+        }
+
+        // Initialize a cache for the length encoding of the previous input.
+        let mut length: Option<(usize, Vec<Boolean<E>>)> = None;
+
+        inputs
+            .iter()
+            .zip_eq(randomizers)
+            .map(|(input, randomizer)| {
+                // Retrieve the length encoding, constructing it if the length has changed.
+                let (_, length_bits_le) = match length.take() {
+                    Some((num_bits, length_bits_le)) if num_bits == input.len() => {
+                        length.insert((num_bits, length_bits_le))
+                    }
+                    _ => length.insert((input.len(), U64::constant(input.len() as u64).to_bits_le())),
+                };
+                let hash = self.hash_uncompressed_with_length(input, length_bits_le);
+
+                // Compute h^r.
+                randomizer
+                    .to_bits_le()
+                    .iter()
+                    .zip_eq(self.hasher.random_base())
+                    .map(|(bit, power)| Group::ternary(bit, power, &Group::zero()))
+                    .fold(hash, |acc, x| acc + x)
+            })
+            .collect()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use anyhow::Result;
+
+    const NUM_INPUTS: usize = 4;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    fn check_commit_many<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(mode: Mode) -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP::<<Circuit as Environment>::Affine, NUM_WINDOWS, WINDOW_SIZE>::setup(DOMAIN)?;
+        let circuit = BHP::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::new(Mode::Constant, native);
+        // Determine the number of inputs.
+        let num_input_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE as usize;
+
+        // Sample the inputs and randomizers.
+        let native_inputs = (0..NUM_INPUTS)
+            .map(|_| (0..num_input_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>())
+            .collect::<Vec<_>>();
+        let native_randomizers =
+            (0..NUM_INPUTS).map(|_| <Circuit as Environment>::ScalarField::rand(&mut test_rng())).collect::<Vec<_>>();
+
+        // Allocates the inputs and randomizers in the circuit.
+        let allocate = || {
+            let inputs =
+                native_inputs.iter().map(|input| Inject::new(mode, input.clone())).collect::<Vec<Vec<Boolean<_>>>>();
+            let randomizers =
+                native_randomizers.iter().map(|randomizer| Scalar::new(mode, *randomizer)).collect::<Vec<Scalar<_>>>();
+            (inputs, randomizers)
+        };
+
+        // Compute the commitments one at a time.
+        let (expected, num_constants) = Circuit::scope(format!("BHP {mode} loop"), || {
+            let (inputs, randomizers) = allocate();
+            let expected = inputs
+                .iter()
+                .zip_eq(&randomizers)
+                .map(|(input, randomizer)| circuit.commit_uncompressed(input, randomizer).eject_value())
+                .collect::<Vec<_>>();
+            assert!(Circuit::is_satisfied_in_scope());
+            (expected, Circuit::num_constants_in_scope())
+        });
+        Circuit::reset();
+
+        // Compute the commitments together.
+        Circuit::scope(format!("BHP {mode} many"), || {
+            let (inputs, randomizers) = allocate();
+            let candidates = circuit.commit_many(&inputs, &randomizers);
+            assert_eq!(expected, candidates.eject_value());
+            assert!(Circuit::is_satisfied_in_scope());
+            assert!(Circuit::num_constants_in_scope() < num_constants);
+        });
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_many_constant() -> Result<()> {
+        check_commit_many::<32, 48>(Mode::Constant)
+    }
+
+    #[test]
+    fn test_commit_many_public() -> Result<()> {
+        check_commit_many::<32, 48>(Mode::Public)
+    }
+
+    #[test]
+    fn test_commit_many_private() -> Result<()> {
+        check_commit_many::<32, 48>(Mode::Private)
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 2 randomizers for BHP, found 1")]
+    fn test_commit_many_mismatched_randomizers() {
+        let native = console::BHP::<<Circuit as Environment>::Affine, 32, 48>::setup(DOMAIN).unwrap();
+        let circuit = BHP::<Circuit, 32, 48>::new(Mode::Constant, native);
+        let input = vec![Boolean::constant(true); 32 * 48 * 3];
+        circuit.commit_many(&[input.clone(), input], &[Scalar::constant(UniformRand::rand(&mut test_rng()))]);
+    }
+}
@@ -16,8 +16,8 @@
 
 use super::*;
 
-impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> CommitUncompressed
-    for BHP<E, NUM_WINDOWS, WINDOW_SIZE>
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8> CommitUncompressed
+    for BHP<E, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
 {
     type Input = Boolean<E>;
     type Output = Group<E>;
@@ -61,7 +61,7 @@ mod tests {
         let native = console::BHP::<<Circuit as Environment>::Affine, NUM_WINDOWS, WINDOW_SIZE>::setup(DOMAIN)?;
         let circuit = BHP::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::new(Mode::Constant, native.clone());
         // Determine the number of inputs.
-        let num_input_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+        let num_input_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE as usize;
 
         for i in 0..ITERATIONS {
             // Sample a random input.
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8>
+    BHP<E, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
+{
+    /// Returns the BHP hash of the given input as both an affine group element and its x-coordinate.
+    ///
+    /// This is equivalent to calling `hash_uncompressed` and `hash`, but computes the digest only once.
+    pub fn hash_both(&self, input: &[Boolean<E>]) -> (Group<E>, Field<E>) {
+        let group = self.hash_uncompressed(input);
+        let x_coordinate = group.to_x_coordinate();
+        (group, x_coordinate)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_curves::AffineCurve;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    fn check_hash_both<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(mode: Mode) -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP::<<Circuit as Environment>::Affine, NUM_WINDOWS, WINDOW_SIZE>::setup(DOMAIN)?;
+        let circuit = BHP::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::new(Mode::Constant, native);
+        // Determine the number of inputs.
+        let num_input_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE as usize;
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..num_input_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+
+            // Compute the hashes separately.
+            let (expected_group, expected_field, separate_count) = Circuit::scope(format!("BHP {mode} {i}"), || {
+                // Prepare the circuit input.
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+                let group = circuit.hash_uncompressed(&circuit_input);
+                let field = circuit.hash(&circuit_input);
+                assert!(Circuit::is_satisfied_in_scope());
+                (group.eject_value(), field.eject_value(), Circuit::num_constraints_in_scope())
+            });
+            Circuit::reset();
+
+            // Compute the hashes together.
+            Circuit::scope(format!("BHP both {mode} {i}"), || {
+                // Prepare the circuit input.
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+                let (group, field) = circuit.hash_both(&circuit_input);
+                assert_eq!(expected_group, group.eject_value());
+                assert_eq!(expected_field, field.eject_value());
+                assert_eq!(expected_group.to_x_coordinate(), field.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+                match mode.is_constant() {
+                    true => assert_eq!(0, Circuit::num_constraints_in_scope()),
+                    false => assert!(Circuit::num_constraints_in_scope() < separate_count),
+                }
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_both_constant() -> Result<()> {
+        check_hash_both::<32, 48>(Mode::Constant)
+    }
+
+    #[test]
+    fn test_hash_both_public() -> Result<()> {
+        check_hash_both::<32, 48>(Mode::Public)
+    }
+
+    #[test]
+    fn test_hash_both_private() -> Result<()> {
+        check_hash_both::<32, 48>(Mode::Private)
+    }
+}
@@ -16,8 +16,8 @@
 
 use super::*;
 
-impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompressed
-    for BHP<E, NUM_WINDOWS, WINDOW_SIZE>
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8> HashUncompressed
+    for BHP<E, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
 {
     type Input = Boolean<E>;
     type Output = Group<E>;
@@ -27,8 +27,24 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
     /// This uncompressed variant of the BHP hash function is provided to support
     /// the BHP commitment scheme, as it is typically not used by applications.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Self::Output {
+        self.hash_uncompressed_with_length(input, &U64::constant(input.len() as u64).to_bits_le())
+    }
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8>
+    BHP<E, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
+{
+    /// Returns the BHP hash of the given input as an affine group element,
+    /// using the given **little-endian** bits of `input.len()` as a `u64`.
+    ///
+    /// This allows callers hashing many inputs of the same length to share the length encoding.
+    pub(super) fn hash_uncompressed_with_length(
+        &self,
+        input: &[Boolean<E>],
+        length_bits_le: &[Boolean<E>],
+    ) -> Group<E> {
         // The number of hasher bits to fit.
-        let num_hasher_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+        let num_hasher_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * CHUNK_SIZE as usize;
         // The number of data bits in the output.
         let num_data_bits = E::BaseField::size_in_data_bits();
         // The maximum number of input bits per iteration.
@@ -46,7 +62,7 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
                 // Construct the first iteration as: [ 0...0 || DOMAIN || LENGTH(INPUT) || INPUT[0..BLOCK_SIZE] ].
                 true => {
                     preimage.extend(self.domain.clone());
-                    preimage.extend(U64::constant(input.len() as u64).to_bits_le());
+                    preimage.extend_from_slice(length_bits_le);
                     preimage.extend_from_slice(input_bits);
                 }
                 // Construct the subsequent iterations as: [ PREVIOUS_HASH[0..DATA_BITS] || INPUT[I * BLOCK_SIZE..(I + 1) * BLOCK_SIZE] ].
@@ -114,7 +130,7 @@ mod tests {
         let native = console::BHP::<<Circuit as Environment>::Affine, NUM_WINDOWS, WINDOW_SIZE>::setup(DOMAIN)?;
         let circuit = BHP::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::new(Mode::Constant, native.clone());
         // Determine the number of inputs.
-        let num_input_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+        let num_input_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE as usize;
 
         for i in 0..ITERATIONS {
             // Sample a random input.
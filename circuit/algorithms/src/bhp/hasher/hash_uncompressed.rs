@@ -16,8 +16,8 @@
 
 use super::*;
 
-impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompressed
-    for BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE>
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8> HashUncompressed
+    for BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
 {
     type Input = Boolean<E>;
     type Output = Group<E>;
@@ -33,14 +33,15 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
         }
 
         // Ensure the input size is within the parameter size.
+        let chunk_size = CHUNK_SIZE as usize;
         let mut input = input.to_vec();
         match input.len() <= Self::MAX_BITS {
             true => {
-                // Pad the input to a multiple of `BHP_CHUNK_SIZE` for hashing.
-                if input.len() % BHP_CHUNK_SIZE != 0 {
-                    let padding = BHP_CHUNK_SIZE - (input.len() % BHP_CHUNK_SIZE);
+                // Pad the input to a multiple of `CHUNK_SIZE` for hashing.
+                if input.len() % chunk_size != 0 {
+                    let padding = chunk_size - (input.len() % chunk_size);
                     input.resize(input.len() + padding, Boolean::constant(false));
-                    assert_eq!(input.len() % BHP_CHUNK_SIZE, 0, "Input must be a multiple of {BHP_CHUNK_SIZE}");
+                    assert_eq!(input.len() % chunk_size, 0, "Input must be a multiple of {CHUNK_SIZE}");
                 }
             }
             false => E::halt(format!("Inputs to this BHP cannot exceed {} bits", Self::MAX_BITS)),
@@ -84,55 +85,87 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
             (sum_x, sum_y)
         };
 
-        // Compute sum of h_i^{sum of (1-2*c_{i,j,2})*(1+c_{i,j,0}+2*c_{i,j,1})*2^{4*(j-1)} for all j in segment}
-        // for all i. Described in section 5.4.1.7 in the Zcash protocol specification.
+        // Computes the coefficients of the multilinear polynomial that interpolates the given lookup table,
+        // such that `lookup[i] == sum_{j ⊆ i} coefficients[j]`, where `j ⊆ i` denotes the bits of `j` are set in `i`.
+        // For a 3-bit BHP, this yields `(l_0, l_1 - l_0, l_2 - l_0, l_3 - l_2 - l_1 + l_0)`.
+        let interpolate = |lookup: &[Field<E>]| {
+            let mut coefficients = lookup.to_vec();
+            for j in 0..(chunk_size - 1) {
+                for i in 0..coefficients.len() {
+                    if (i >> j) & 1 == 1 {
+                        coefficients[i] = &coefficients[i] - &coefficients[i ^ (1 << j)];
+                    }
+                }
+            }
+            coefficients
+        };
+
+        // Compute sum of h_i^{sum of (1-2*c_{i,j,k-1})*(1+c_{i,j,0}+...+2^{k-2}*c_{i,j,k-2})*2^{(k+1)*(j-1)} for all j in segment}
+        // for all i, where `k` is the chunk size. Described in section 5.4.1.7 in the Zcash protocol specification.
         //
         // Note: `.zip()` is used here (as opposed to `.zip_eq()`) as the input can be less than
-        // `NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE` in length, which is the parameter size here.
+        // `NUM_WINDOWS * WINDOW_SIZE * CHUNK_SIZE` in length, which is the parameter size here.
         input
-            .chunks(WINDOW_SIZE as usize * BHP_CHUNK_SIZE)
+            .chunks(WINDOW_SIZE as usize * chunk_size)
             .zip(self.bases.iter())
             .map(|(bits, bases)| {
                 // Initialize accumulating sum variables for the x- and y-coordinates.
                 let mut sum = None;
 
-                // One iteration costs 5 constraints.
-                bits.chunks(BHP_CHUNK_SIZE).zip(bases).for_each(|(chunk_bits, base_lookups)| {
+                // For a 3-bit BHP, one iteration costs 5 constraints.
+                bits.chunks(chunk_size).zip(bases).for_each(|(chunk_bits, base_lookups)| {
                     // Unzip the base lookups into `x_bases` and `y_bases`.
                     let (x_bases, y_bases) = base_lookups;
 
-                    // Cast each input chunk bit as a field element.
-                    let bit_0 = Field::from_boolean(&chunk_bits[0]);
-                    let bit_1 = Field::from_boolean(&chunk_bits[1]);
-                    let bit_2 = Field::from_boolean(&chunk_bits[2]);
-                    let bit_0_and_1 = Field::from_boolean(&(&chunk_bits[0] & &chunk_bits[1])); // 1 constraint
+                    // Split the chunk into the magnitude bits and the sign bit.
+                    let (magnitude_bits, sign_bit) = chunk_bits.split_at(chunk_size - 1);
+                    let sign_bit = &sign_bit[0];
+
+                    // Compute the products of each subset of the magnitude bits, where the `i`-th product
+                    // is the product of the bits set in `i`. For a 3-bit BHP, this costs 1 constraint.
+                    let mut products: Vec<Boolean<E>> = Vec::with_capacity(Self::BHP_LOOKUP_SIZE);
+                    products.push(Boolean::constant(true));
+                    for i in 1..Self::BHP_LOOKUP_SIZE {
+                        // Retrieve the index of the most significant bit set in `i`.
+                        let msb = (usize::BITS - 1 - i.leading_zeros()) as usize;
+                        // Compute the product as the product of the remaining bits, and the most significant bit.
+                        let product = match i == (1 << msb) {
+                            true => magnitude_bits[msb].clone(),
+                            false => &products[i ^ (1 << msb)] & &magnitude_bits[msb],
+                        };
+                        products.push(product);
+                    }
+                    // Cast each product as a field element.
+                    let products = products.iter().skip(1).map(Field::from_boolean).collect::<Vec<_>>();
 
                     // Compute the x-coordinate of the Montgomery curve point.
-                    let montgomery_x: Field<E> = &x_bases[0]
-                        + &bit_0 * (&x_bases[1] - &x_bases[0])
-                        + &bit_1 * (&x_bases[2] - &x_bases[0])
-                        + &bit_0_and_1 * (&x_bases[3] - &x_bases[2] - &x_bases[1] + &x_bases[0]);
+                    let x_coefficients = interpolate(x_bases);
+                    let montgomery_x: Field<E> = products
+                        .iter()
+                        .zip_eq(x_coefficients.iter().skip(1))
+                        .fold(x_coefficients[0].clone(), |acc, (product, coefficient)| acc + product * coefficient);
 
                     // Compute the y-coordinate of the Montgomery curve point.
                     let montgomery_y = {
                         // Compute the y-coordinate of the Montgomery curve point, without any negation.
-                        let y: Field<E> = &y_bases[0]
-                            + bit_0 * (&y_bases[1] - &y_bases[0])
-                            + bit_1 * (&y_bases[2] - &y_bases[0])
-                            + bit_0_and_1 * (&y_bases[3] - &y_bases[2] - &y_bases[1] + &y_bases[0]);
+                        let y_coefficients = interpolate(y_bases);
+                        let y: Field<E> = products
+                            .iter()
+                            .zip_eq(y_coefficients.iter().skip(1))
+                            .fold(y_coefficients[0].clone(), |acc, (product, coefficient)| acc + product * coefficient);
 
                         // Determine the correct sign of the y-coordinate, as a witness.
                         //
                         // Instead of using `Field::ternary`, we create a witness & custom constraint to reduce
                         // the number of nonzero entries in the circuit, improving setup & proving time for Marlin.
-                        let montgomery_y: Field<E> = witness!(|chunk_bits, y| if chunk_bits[2] { -y } else { y });
+                        let montgomery_y: Field<E> = witness!(|sign_bit, y| if sign_bit { -y } else { y });
 
                         // Ensure the conditional negation of `witness_y` is correct as follows (1 constraint):
-                        //     `(bit_2 - 1/2) * (-2 * y) == montgomery_y`
+                        //     `(sign_bit - 1/2) * (-2 * y) == montgomery_y`
                         // which is equivalent to:
-                        //     if `bit_2 == 0`, then `montgomery_y = -1/2 * -2 * y = y`
-                        //     if `bit_2 == 1`, then `montgomery_y = 1/2 * -2 * y = -y`
-                        E::enforce(|| (bit_2 - &one_half, -y.double(), &montgomery_y)); // 1 constraint
+                        //     if `sign_bit == 0`, then `montgomery_y = -1/2 * -2 * y = y`
+                        //     if `sign_bit == 1`, then `montgomery_y = 1/2 * -2 * y = -y`
+                        E::enforce(|| (Field::from_boolean(sign_bit) - &one_half, -y.double(), &montgomery_y)); // 1 constraint
 
                         montgomery_y
                     };
@@ -195,7 +228,7 @@ mod tests {
         let primitive = console::BHP::<<Circuit as Environment>::Affine, NUM_WINDOWS, WINDOW_SIZE>::setup(MESSAGE)?;
         let circuit = BHPHasher::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::new(Mode::Constant, primitive);
         // Determine the number of inputs.
-        let num_input_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+        let num_input_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE as usize;
 
         for i in 0..ITERATIONS {
             // Sample a random input.
@@ -229,4 +262,60 @@ mod tests {
     fn test_hash_uncompressed_private() -> Result<()> {
         check_hash_uncompressed::<32, 48>(Mode::Private, 129, 0, 7898, 7898)
     }
+
+    fn check_hash_uncompressed_with_chunk_size<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8>(
+        mode: Mode,
+    ) -> Result<()> {
+        use console::HashUncompressed as H;
+
+        // Initialize the native BHP hasher.
+        let native = console::bhp::hasher::BHPHasher::<
+            <Circuit as Environment>::Affine,
+            NUM_WINDOWS,
+            WINDOW_SIZE,
+            CHUNK_SIZE,
+        >::setup(MESSAGE)?;
+
+        // Initialize the circuit BHP hasher.
+        let primitive =
+            console::BHP::<<Circuit as Environment>::Affine, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>::setup(MESSAGE)?;
+        let circuit = BHPHasher::<Circuit, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>::new(Mode::Constant, primitive);
+        // Determine the number of inputs.
+        let num_input_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * CHUNK_SIZE as usize;
+
+        for i in 0..ITERATIONS {
+            // Sample a random input, including lengths that are not a multiple of the chunk size.
+            let input = (0..num_input_bits - (i % 3)).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash_uncompressed(&input).expect("Failed to hash native input");
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash_uncompressed(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_uncompressed_chunk_size_2() -> Result<()> {
+        check_hash_uncompressed_with_chunk_size::<8, 32, 2>(Mode::Constant)?;
+        check_hash_uncompressed_with_chunk_size::<8, 32, 2>(Mode::Private)
+    }
+
+    #[test]
+    fn test_hash_uncompressed_chunk_size_4() -> Result<()> {
+        check_hash_uncompressed_with_chunk_size::<8, 32, 4>(Mode::Constant)?;
+        check_hash_uncompressed_with_chunk_size::<8, 32, 4>(Mode::Private)
+    }
+
+    #[test]
+    fn test_hash_uncompressed_chunk_size_5() -> Result<()> {
+        check_hash_uncompressed_with_chunk_size::<8, 32, 5>(Mode::Private)
+    }
 }
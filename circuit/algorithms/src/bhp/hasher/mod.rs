@@ -23,28 +23,34 @@ use crate::HashUncompressed;
 use snarkvm_circuit_types::prelude::*;
 use snarkvm_curves::{MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters};
 
-/// The BHP chunk size (this implementation is for a 3-bit BHP).
-const BHP_CHUNK_SIZE: usize = 3;
+/// The default BHP chunk size (i.e. a 3-bit BHP).
+pub(super) const BHP_CHUNK_SIZE: u8 = 3;
 
 /// The x-coordinate and y-coordinate of each base on the Montgomery curve.
 type BaseLookups<E> = (Vec<Field<E>>, Vec<Field<E>>);
 
 /// BHP is a collision-resistant hash function that takes a variable-length input.
 /// The BHP hasher is used to process one internal iteration of the BHP hash function.
-pub struct BHPHasher<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> {
+///
+/// Each chunk of `CHUNK_SIZE` bits is interpreted as a signed digit, where the lower
+/// `CHUNK_SIZE - 1` bits encode the magnitude, and the most significant bit encodes the sign.
+/// By default, this is a 3-bit BHP.
+pub struct BHPHasher<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8 = BHP_CHUNK_SIZE> {
     /// The bases for the BHP hash.
     bases: Vec<Vec<BaseLookups<E>>>,
     /// The random base for the BHP commitment.
     random_base: Vec<Group<E>>,
 }
 
-impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE> {
-    /// The BHP lookup size per iteration.
-    const BHP_LOOKUP_SIZE: usize = 4;
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8>
+    BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
+{
+    /// The BHP lookup size per iteration (one entry for each magnitude).
+    const BHP_LOOKUP_SIZE: usize = 1 << (CHUNK_SIZE - 1);
     /// The maximum number of input bits.
-    const MAX_BITS: usize = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+    const MAX_BITS: usize = NUM_WINDOWS as usize * WINDOW_SIZE as usize * CHUNK_SIZE as usize;
     /// The minimum number of input bits (at least one window).
-    const MIN_BITS: usize = WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+    const MIN_BITS: usize = WINDOW_SIZE as usize * CHUNK_SIZE as usize;
 
     #[cfg(test)]
     /// Returns the bases.
@@ -59,8 +65,10 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E,
 }
 
 #[cfg(console)]
-impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Inject for BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE> {
-    type Primitive = console::BHP<E::Affine, NUM_WINDOWS, WINDOW_SIZE>;
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8> Inject
+    for BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
+{
+    type Primitive = console::BHP<E::Affine, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>;
 
     /// Initializes a new instance of a BHP circuit with the given BHP variant.
     fn new(_mode: Mode, bhp: Self::Primitive) -> Self {
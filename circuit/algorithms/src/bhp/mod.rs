@@ -15,17 +15,19 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod hasher;
-use hasher::BHPHasher;
+use hasher::{BHPHasher, BHP_CHUNK_SIZE};
 
 mod commit;
+mod commit_many;
 mod commit_uncompressed;
 mod hash;
+mod hash_both;
 mod hash_uncompressed;
 
 #[cfg(all(test, console))]
 use snarkvm_circuit_types::environment::assert_scope;
 
-use crate::{Commit, CommitUncompressed, Hash, HashUncompressed};
+use crate::{Commit, CommitUncompressed, CommitmentScheme, Hash, HashUncompressed};
 use snarkvm_circuit_types::prelude::*;
 
 /// BHP256 is a collision-resistant hash function that takes a 256-bit input.
@@ -37,9 +39,6 @@ pub type BHP768<G> = BHP<G, 15, 23>; // Supports inputs up to 783 bits (3 u8 + 3
 /// BHP1024 is a collision-resistant hash function that takes a 1024-bit input.
 pub type BHP1024<G> = BHP<G, 8, 54>; // Supports inputs up to 1044 bits (4 u8 + 4 Fq).
 
-/// The BHP chunk size (this implementation is for a 3-bit BHP).
-const BHP_CHUNK_SIZE: usize = 3;
-
 /// BHP is a collision-resistant hash function that takes a variable-length input.
 /// The BHP hash function does *not* behave like a random oracle, see Poseidon for one.
 ///
@@ -54,16 +53,20 @@ const BHP_CHUNK_SIZE: usize = 3;
 /// ```text
 /// DIGEST_N+1 = BHP([ DIGEST_N[0..DATA_BITS] || INPUT[(N+1)*BLOCK_SIZE..(N+2)*BLOCK_SIZE] ]);
 /// ```
-pub struct BHP<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> {
+///
+/// The `CHUNK_SIZE` is the number of input bits processed per base (by default, this is a 3-bit BHP).
+pub struct BHP<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8 = BHP_CHUNK_SIZE> {
     /// The domain separator for the BHP hash function.
     domain: Vec<Boolean<E>>,
     /// The internal BHP hasher used to process one iteration.
-    hasher: BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE>,
+    hasher: BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>,
 }
 
 #[cfg(console)]
-impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Inject for BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
-    type Primitive = console::BHP<E::Affine, NUM_WINDOWS, WINDOW_SIZE>;
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8> Inject
+    for BHP<E, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
+{
+    type Primitive = console::BHP<E::Affine, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>;
 
     /// Initializes a new instance of a BHP circuit with the given BHP variant.
     fn new(_mode: Mode, bhp: Self::Primitive) -> Self {
@@ -80,7 +83,7 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Inject for BH
         let domain = Vec::constant(bhp.domain().to_vec());
 
         // Initialize the BHP hasher.
-        let hasher = BHPHasher::<E, NUM_WINDOWS, WINDOW_SIZE>::constant(bhp);
+        let hasher = BHPHasher::<E, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>::constant(bhp);
 
         Self { domain, hasher }
     }
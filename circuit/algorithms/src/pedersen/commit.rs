@@ -27,6 +27,15 @@ impl<E: Environment, const NUM_BITS: u8> Commit for Pedersen<E, NUM_BITS> {
     }
 }
 
+impl<E: Environment, const NUM_BITS: u8> CommitmentScheme for Pedersen<E, NUM_BITS> {
+    type Boolean = Boolean<E>;
+
+    /// Returns `true` if the given Pedersen commitment opens to the given input and randomizer.
+    fn verify(&self, input: &[Self::Input], randomizer: &Self::Randomizer, commitment: &Self::Output) -> Self::Boolean {
+        self.commit(input, randomizer).is_equal(commitment)
+    }
+}
+
 impl<E: Environment, const NUM_BITS: u8>
     Metrics<dyn Commit<Input = Boolean<E>, Output = Field<E>, Randomizer = Scalar<E>>> for Pedersen<E, NUM_BITS>
 {
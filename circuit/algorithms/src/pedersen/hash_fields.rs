@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns the Pedersen hash of the given field elements, where each field element
+    /// is decomposed into its canonical **little-endian** bits, and the bits are concatenated.
+    ///
+    /// The output is equal to calling `hash` on the decomposed bits.
+    /// This method halts if the total number of bits exceeds `NUM_BITS`.
+    pub fn hash_fields(&self, fields: &[Field<E>]) -> Field<E> {
+        // Decompose the field elements into little-endian bits.
+        let input = fields.iter().flat_map(|field| field.to_bits_le()).collect::<Vec<_>>();
+        // Ensure the input size is within the parameter size.
+        if input.len() > NUM_BITS as usize {
+            E::halt(format!(
+                "The Pedersen hash input cannot exceed {NUM_BITS} bits, found {} bits from {} field elements.",
+                input.len(),
+                fields.len()
+            ))
+        }
+        // Compute the Pedersen hash.
+        self.hash(&input)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 10;
+    const MESSAGE: &str = "PedersenCircuit0";
+    // Note: A single base field element has 253 bits.
+    const NUM_BITS: u8 = 253;
+
+    fn check_hash_fields(mode: Mode) {
+        use console::Hash as H;
+
+        // Initialize the Pedersen hash.
+        let native = console::Pedersen::<<Circuit as Environment>::Affine, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample a random field element.
+            let input = <Circuit as Environment>::BaseField::rand(&mut test_rng());
+            // Compute the expected hash, and ensure it matches decomposing the field element manually.
+            let expected = native.hash_fields(&[input]).expect("Failed to hash native input");
+            assert_eq!(
+                expected,
+                native.hash(&snarkvm_utilities::ToBits::to_bits_le(&input)).expect("Failed to hash native input")
+            );
+            // Prepare the circuit input.
+            let circuit_input = vec![Field::<Circuit>::new(mode, input)];
+
+            Circuit::scope(format!("Pedersen {mode} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash_fields(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+
+                // Ensure the hash matches decomposing the field element manually.
+                let expected = circuit.hash(&circuit_input[0].to_bits_le());
+                assert_eq!(expected.eject_value(), candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_hash_fields_constant() {
+        check_hash_fields(Mode::Constant);
+    }
+
+    #[test]
+    fn test_hash_fields_public() {
+        check_hash_fields(Mode::Public);
+    }
+
+    #[test]
+    fn test_hash_fields_private() {
+        check_hash_fields(Mode::Private);
+    }
+
+    #[test]
+    #[should_panic(expected = "found 506 bits from 2 field elements")]
+    fn test_hash_fields_exceeds_window() {
+        // Initialize the Pedersen hash.
+        let native = console::Pedersen::<<Circuit as Environment>::Affine, NUM_BITS>::setup(MESSAGE);
+        let circuit = Pedersen::<Circuit, NUM_BITS>::constant(native);
+
+        // Ensure two field elements exceed the window of a single field element.
+        let input = Field::<Circuit>::new(Mode::Private, <Circuit as Environment>::BaseField::rand(&mut test_rng()));
+        let _candidate = circuit.hash_fields(&[input.clone(), input]);
+    }
+}
@@ -17,12 +17,13 @@
 mod commit;
 mod commit_uncompressed;
 mod hash;
+mod hash_fields;
 mod hash_uncompressed;
 
 #[cfg(all(test, console))]
 use snarkvm_circuit_types::environment::{assert_count, assert_output_mode, assert_scope};
 
-use crate::{Commit, CommitUncompressed, Hash, HashUncompressed};
+use crate::{Commit, CommitUncompressed, CommitmentScheme, Hash, HashUncompressed};
 use snarkvm_circuit_types::prelude::*;
 use snarkvm_curves::ProjectiveCurve;
 
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Absorbs the given bytes into state, packed into field elements as
+    /// `[ LENGTH(BYTES) || BYTES[0..N] || BYTES[N..2N] || ... ]`,
+    /// where `N = E::BaseField::size_in_data_bits() / 8`,
+    /// and each chunk of bytes is read as a **little-endian** integer.
+    ///
+    /// The leading length ensures inputs of different lengths (i.e. with trailing zeros) do not collide.
+    /// This packing matches `PoseidonSponge::absorb_bytes` in the console.
+    pub fn absorb_bytes(&self, state: &mut [Field<E>], mode: &mut DuplexSpongeMode, bytes: &[U8<E>]) {
+        // Determine the number of bytes that fit in the data bits of a field element.
+        let num_bytes_per_field = E::BaseField::size_in_data_bits() / 8;
+
+        // Pack the bytes into field elements, prefixed with the number of bytes.
+        let mut input = Vec::with_capacity(1 + bytes.len() / num_bytes_per_field + 1);
+        input.push(Field::constant((bytes.len() as u128).into()));
+        input.extend(
+            bytes
+                .chunks(num_bytes_per_field)
+                .map(|chunk| Field::from_bits_le(&chunk.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>())),
+        );
+
+        // Absorb the packed field elements.
+        self.absorb(state, mode, &input)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonCircuit0";
+
+    /// Returns the first squeezed element after absorbing the given bytes into a fresh sponge.
+    fn squeeze_bytes<const RATE: usize>(poseidon: &Poseidon<Circuit, RATE>, bytes: &[U8<Circuit>]) -> Field<Circuit> {
+        let mut state = vec![Field::zero(); RATE + CAPACITY];
+        let mut mode = DuplexSpongeMode::Absorbing { next_absorb_index: 0 };
+        poseidon.absorb_bytes(&mut state, &mut mode, bytes);
+        poseidon.squeeze(&mut state, &mut mode, 1).remove(0)
+    }
+
+    fn check_absorb_bytes<const RATE: usize>(mode: Mode) -> Result<()> {
+        use console::AlgebraicSponge;
+
+        let native = console::Poseidon::<<Circuit as Environment>::BaseField, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native.clone());
+
+        for num_bytes in [0, 1, 30, 31, 32, 62, 63, 100] {
+            // Prepare the bytes.
+            let native_bytes = (0..num_bytes).map(|_| u8::rand(&mut test_rng())).collect::<Vec<_>>();
+            let bytes = native_bytes.iter().map(|byte| U8::<Circuit>::new(mode, *byte)).collect::<Vec<_>>();
+
+            // Compute the native output.
+            let mut sponge = console::PoseidonSponge::<_, RATE, CAPACITY>::new(native.parameters());
+            sponge.absorb_bytes(&native_bytes)?;
+            let expected = sponge.squeeze(1)[0];
+
+            // Compute the circuit output.
+            Circuit::scope(format!("Poseidon {mode} {num_bytes}"), || {
+                let candidate = squeeze_bytes(&poseidon, &bytes);
+                assert_eq!(expected, candidate.eject_value(), "(rate = {RATE}, num_bytes = {num_bytes})");
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_absorb_bytes() -> Result<()> {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_absorb_bytes::<2>(mode)?;
+            check_absorb_bytes::<4>(mode)?;
+            check_absorb_bytes::<8>(mode)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_absorb_bytes_length() -> Result<()> {
+        let native = console::Poseidon::<<Circuit as Environment>::BaseField, 4>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, 4>::constant(native);
+
+        // Ensure inputs that only differ in their length (i.e. trailing zeros) produce different outputs.
+        let zeros = |num_bytes: usize| vec![U8::<Circuit>::new(Mode::Private, 0); num_bytes];
+        assert_ne!(
+            squeeze_bytes(&poseidon, &zeros(0)).eject_value(),
+            squeeze_bytes(&poseidon, &zeros(1)).eject_value()
+        );
+        assert_ne!(
+            squeeze_bytes(&poseidon, &zeros(3)).eject_value(),
+            squeeze_bytes(&poseidon, &zeros(4)).eject_value()
+        );
+        assert_ne!(
+            squeeze_bytes(&poseidon, &zeros(31)).eject_value(),
+            squeeze_bytes(&poseidon, &zeros(32)).eject_value()
+        );
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+}
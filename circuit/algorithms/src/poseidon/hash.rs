@@ -20,6 +20,10 @@ impl<E: Environment, const RATE: usize> Hash for Poseidon<E, RATE> {
     type Input = Field<E>;
     type Output = Field<E>;
 
+    /// Returns the hash of the given input.
+    ///
+    /// An empty input is valid: the preimage always contains the domain and the input length,
+    /// so `hash(&[])` is a deterministic digest that matches the console `hash(&[])`.
     #[inline]
     fn hash(&self, input: &[Self::Input]) -> Self::Output {
         self.hash_many(input, 1)[0].clone()
@@ -109,4 +113,35 @@ mod tests {
         check_hash(Mode::Private, 9, 1, 0, 1060, 1060)?;
         check_hash(Mode::Private, 10, 1, 0, 1060, 1060)
     }
+
+    fn check_hash_empty_input<const RATE: usize>() -> Result<()> {
+        use console::Hash as H;
+
+        let native = console::Poseidon::<<Circuit as Environment>::BaseField, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native.clone());
+
+        // Compute the native hash.
+        let expected = native.hash(&[])?;
+
+        // Compute the circuit hash.
+        Circuit::scope(format!("Poseidon empty input (rate = {RATE})"), || {
+            let candidate = poseidon.hash(&[]);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(candidate.is_constant());
+            assert_scope!(format!("(rate = {RATE})"), 1, 0, 0, 0);
+        });
+        Circuit::reset();
+
+        // Ensure the digest is deterministic.
+        assert_eq!(expected, poseidon.hash(&[]).eject_value());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_empty_input() -> Result<()> {
+        check_hash_empty_input::<2>()?;
+        check_hash_empty_input::<4>()?;
+        check_hash_empty_input::<8>()
+    }
 }
@@ -40,9 +40,21 @@ impl<E: Environment, const RATE: usize> HashMany for Poseidon<E, RATE> {
 }
 
 impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Returns the number of permutations performed by `hash_many` on an input of the given length.
+    /// This allows the cost of a hash to be estimated without synthesizing it.
+    pub fn num_permutations(input_len: usize, num_outputs: usize) -> usize {
+        // The preimage is the domain, the input length, `RATE - 2` zeros, and the input.
+        let preimage_len = RATE + input_len;
+        // Absorbing permutes once for each block of the preimage, except the last block.
+        let num_absorb_permutations = (preimage_len + RATE - 1) / RATE - 1;
+        // Squeezing permutes once for each block of the output.
+        let num_squeeze_permutations = (num_outputs + RATE - 1) / RATE;
+        num_absorb_permutations + num_squeeze_permutations
+    }
+
     /// Absorbs the input elements into state.
     #[inline]
-    fn absorb(&self, state: &mut [Field<E>], mode: &mut DuplexSpongeMode, input: &[Field<E>]) {
+    pub(super) fn absorb(&self, state: &mut [Field<E>], mode: &mut DuplexSpongeMode, input: &[Field<E>]) {
         if !input.is_empty() {
             // Determine the absorb index.
             let (mut absorb_index, should_permute) = match *mode {
@@ -88,7 +100,12 @@ impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
 
     /// Squeeze the specified number of state elements into the output.
     #[inline]
-    fn squeeze(&self, state: &mut [Field<E>], mode: &mut DuplexSpongeMode, num_outputs: u16) -> Vec<Field<E>> {
+    pub(super) fn squeeze(
+        &self,
+        state: &mut [Field<E>],
+        mode: &mut DuplexSpongeMode,
+        num_outputs: u16,
+    ) -> Vec<Field<E>> {
         let mut output = vec![Field::zero(); num_outputs as usize];
         if num_outputs != 0 {
             self.squeeze_internal(state, mode, &mut output);
@@ -131,10 +148,8 @@ impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
             let num_squeezed = RATE - squeeze_index;
             remaining[..num_squeezed].clone_from_slice(&state[start..(start + num_squeezed)]);
 
-            // Unless we are done with squeezing in this call, permute.
-            if remaining.len() != RATE {
-                self.permute(state);
-            }
+            // As there are elements remaining to be squeezed, permute.
+            self.permute(state);
             // Repeat with the updated output slice and squeeze index.
             remaining = &mut remaining[num_squeezed..];
             squeeze_index = 0;
@@ -179,7 +194,7 @@ impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
 
     /// Apply the permutation for all rounds in-place.
     #[inline]
-    fn permute(&self, state: &mut [Field<E>]) {
+    pub(super) fn permute(&self, state: &mut [Field<E>]) {
         // Determine the partial rounds range bound.
         let full_rounds_over_2 = self.full_rounds / 2;
         let partial_round_range = full_rounds_over_2..(full_rounds_over_2 + self.partial_rounds);
@@ -243,6 +258,61 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_num_permutations() -> Result<()> {
+        fn check_num_permutations<const RATE: usize>() -> Result<()> {
+            let native = console::Poseidon::<<Circuit as Environment>::BaseField, RATE>::setup(DOMAIN)?;
+            let poseidon = Poseidon::<Circuit, RATE>::constant(native);
+
+            // Returns a private state, such that every permutation on it costs the same number of constraints.
+            let private_state = || {
+                (0..RATE + CAPACITY)
+                    .map(|_| Field::<Circuit>::new(Mode::Private, UniformRand::rand(&mut test_rng())))
+                    .collect::<Vec<_>>()
+            };
+
+            // Compute the number of constraints in one permutation.
+            let num_constraints_per_permutation = Circuit::scope("Poseidon permute", || {
+                poseidon.permute(&mut private_state());
+                Circuit::num_constraints_in_scope()
+            });
+            assert!(num_constraints_per_permutation > 0);
+            Circuit::reset();
+
+            for num_inputs in 0..=(3 * RATE + 1) {
+                for num_outputs in 0..=(2 * RATE + 1) {
+                    Circuit::scope(format!("Poseidon {RATE} {num_inputs} {num_outputs}"), || {
+                        // Mirror `hash_many`, with a preimage of `RATE + num_inputs` elements.
+                        let mut state = private_state();
+                        let mut mode = DuplexSpongeMode::Absorbing { next_absorb_index: 0 };
+                        let preimage = (0..RATE + num_inputs)
+                            .map(|_| Field::<Circuit>::new(Mode::Private, UniformRand::rand(&mut test_rng())))
+                            .collect::<Vec<_>>();
+                        poseidon.absorb(&mut state, &mut mode, &preimage);
+                        poseidon.squeeze(&mut state, &mut mode, num_outputs as u16);
+
+                        // Count the permutations from the number of constraints.
+                        let num_constraints = Circuit::num_constraints_in_scope();
+                        assert_eq!(0, num_constraints % num_constraints_per_permutation);
+                        let candidate = (num_constraints / num_constraints_per_permutation) as usize;
+
+                        let expected = Poseidon::<Circuit, RATE>::num_permutations(num_inputs, num_outputs);
+                        assert_eq!(
+                            expected, candidate,
+                            "(rate = {RATE}, inputs = {num_inputs}, outputs = {num_outputs})"
+                        );
+                    });
+                    Circuit::reset();
+                }
+            }
+            Ok(())
+        }
+
+        check_num_permutations::<2>()?;
+        check_num_permutations::<4>()?;
+        check_num_permutations::<8>()
+    }
+
     #[test]
     fn test_hash_many_constant() -> Result<()> {
         for num_inputs in 0..=RATE {
@@ -300,4 +370,47 @@ mod tests {
         }
         Ok(())
     }
+
+    fn check_hash_many_consistency<const RATE: usize>(mode: Mode) -> Result<()> {
+        use console::HashMany as H;
+
+        let native = console::Poseidon::<<Circuit as Environment>::BaseField, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native.clone());
+
+        // Check all output counts up to 3 * RATE, such that squeezing crosses the permutation boundaries.
+        for num_inputs in [0, 1, RATE - 1, RATE, RATE + 1, 2 * RATE + 1] {
+            for num_outputs in 1..=(3 * RATE as u16) {
+                // Prepare the preimage.
+                let native_input = (0..num_inputs)
+                    .map(|_| <Circuit as Environment>::BaseField::rand(&mut test_rng()))
+                    .collect::<Vec<_>>();
+                let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+                // Compute the native hash.
+                let expected = native.hash_many(&native_input, num_outputs);
+                assert_eq!(num_outputs as usize, expected.len());
+                // Ensure the native outputs are a prefix of the native outputs for a larger output count.
+                assert_eq!(expected, native.hash_many(&native_input, 3 * RATE as u16)[..num_outputs as usize]);
+
+                // Compute the circuit hash.
+                Circuit::scope(format!("Poseidon {mode} {num_inputs} {num_outputs}"), || {
+                    let candidate = poseidon.hash_many(&input, num_outputs);
+                    assert_eq!(expected, candidate.eject_value(), "(rate = {RATE}, num_outputs = {num_outputs})");
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_many_consistency() -> Result<()> {
+        for mode in [Mode::Constant, Mode::Private] {
+            check_hash_many_consistency::<2>(mode)?;
+            check_hash_many_consistency::<4>(mode)?;
+            check_hash_many_consistency::<8>(mode)?;
+        }
+        Ok(())
+    }
 }
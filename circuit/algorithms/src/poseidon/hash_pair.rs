@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Poseidon<E, 2> {
+    /// Returns the Poseidon hash of the two given field elements.
+    ///
+    /// This is equivalent to `hash(&[left, right])`, but it absorbs the pair directly into the state,
+    /// skipping the allocation of the preimage and the variable-length absorb logic.
+    #[inline]
+    pub fn hash_pair(&self, left: &Field<E>, right: &Field<E>) -> Field<E> {
+        // Initialize a new sponge with the preimage header: [ DOMAIN || LENGTH(INPUT) ].
+        let mut state = vec![Field::zero(); 2 + CAPACITY];
+        state[CAPACITY] = self.domain.clone();
+        state[CAPACITY + 1] = Field::constant(2u128.into());
+
+        // Absorb the pair into the rate portion of the state.
+        self.permute(&mut state);
+        state[CAPACITY] += left;
+        state[CAPACITY + 1] += right;
+
+        // Squeeze the first output.
+        self.permute(&mut state);
+        state[CAPACITY].clone()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonCircuit0";
+    const ITERATIONS: usize = 10;
+
+    fn check_hash_pair(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        use console::Hash as H;
+
+        let native = console::Poseidon2::<<Circuit as Environment>::BaseField>::setup(DOMAIN)?;
+        let poseidon = Poseidon2::<Circuit>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample the pair.
+            let native_left = <Circuit as Environment>::BaseField::rand(&mut test_rng());
+            let native_right = <Circuit as Environment>::BaseField::rand(&mut test_rng());
+
+            // Compute the native hash.
+            let expected = native.hash(&[native_left, native_right])?;
+
+            // Compute the generic circuit hash.
+            let (generic_constraints, generic_private) = Circuit::scope(format!("Poseidon {mode} {i}"), || {
+                let left = Field::<Circuit>::new(mode, native_left);
+                let right = Field::<Circuit>::new(mode, native_right);
+                let candidate = poseidon.hash(&[left, right]);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+                (Circuit::num_constraints_in_scope(), Circuit::num_private_in_scope())
+            });
+            Circuit::reset();
+
+            // Compute the specialized circuit hash.
+            Circuit::scope(format!("Poseidon pair {mode} {i}"), || {
+                let left = Field::<Circuit>::new(mode, native_left);
+                let right = Field::<Circuit>::new(mode, native_right);
+                let candidate = poseidon.hash_pair(&left, &right);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                // Ensure the specialized path is no worse than the generic path.
+                assert!(Circuit::num_constraints_in_scope() <= generic_constraints);
+                assert!(Circuit::num_private_in_scope() <= generic_private);
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_pair_constant() -> Result<()> {
+        check_hash_pair(Mode::Constant, 3, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_hash_pair_public() -> Result<()> {
+        check_hash_pair(Mode::Public, 1, 2, 270, 270)
+    }
+
+    #[test]
+    fn test_hash_pair_private() -> Result<()> {
+        check_hash_pair(Mode::Private, 1, 0, 272, 270)
+    }
+}
@@ -24,12 +24,8 @@ impl<E: Environment, const RATE: usize> HashToScalar for Poseidon<E, RATE> {
     /// This method uses truncation (up to data bits) to project onto the scalar field.
     #[inline]
     fn hash_to_scalar(&self, input: &[Self::Input]) -> Self::Scalar {
-        // Hash the input to the base field.
-        let output = self.hash(input);
-
-        // Truncate the output to the size in data bits (1 bit less than the MODULUS) of the scalar.
-        // Slicing here is safe as the base field is larger than the scalar field.
-        Scalar::from_bits_le(&output.to_bits_le()[..E::ScalarField::size_in_data_bits()])
+        // Hash the input to the base field, and reduce the output into the scalar field.
+        self.hash(input).to_scalar_reduced()
     }
 }
 
@@ -14,16 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod absorb_bytes;
 mod hash;
 mod hash_many;
+mod hash_pair;
 mod hash_to_scalar;
 mod prf;
 
 #[cfg(all(test, console))]
 use snarkvm_circuit_types::environment::assert_scope;
 
-use crate::{Hash, HashMany, HashToScalar, PRF};
-use snarkvm_circuit_types::{environment::prelude::*, Field, Scalar};
+use crate::{Hash, HashMany, HashToScalar, ToScalarReduced, PRF};
+use snarkvm_circuit_types::{environment::prelude::*, Field, Scalar, U8};
 
 /// Poseidon2 is a cryptographic hash function of input rate 2.
 pub type Poseidon2<E> = Poseidon<E, 2>;
@@ -97,3 +99,129 @@ impl<E: Environment, const RATE: usize> Inject for Poseidon<E, RATE> {
         Self { domain, full_rounds, partial_rounds, alpha, ark, mds }
     }
 }
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Initializes a new instance of Poseidon from the given custom parameters.
+    /// This is intended for experimenting with custom round constants and MDS matrices.
+    pub fn from_parameters(
+        domain: Field<E>,
+        full_rounds: usize,
+        partial_rounds: usize,
+        alpha: Field<E>,
+        ark: Vec<Vec<Field<E>>>,
+        mds: Vec<Vec<Field<E>>>,
+    ) -> Self {
+        // Ensure the additive round keys are of size `(full_rounds + partial_rounds) x (RATE + CAPACITY)`.
+        let num_rounds = full_rounds + partial_rounds;
+        let width = RATE + CAPACITY;
+        if ark.len() != num_rounds {
+            E::halt(format!("Expected {num_rounds} rows of round keys, found {} rows", ark.len()))
+        }
+        for (i, row) in ark.iter().enumerate() {
+            if row.len() != width {
+                E::halt(format!("Expected {width} round keys in row {i}, found {}", row.len()))
+            }
+        }
+
+        // Ensure the MDS matrix is of size `(RATE + CAPACITY) x (RATE + CAPACITY)`.
+        if mds.len() != width {
+            E::halt(format!("Expected {width} rows in the MDS matrix, found {} rows", mds.len()))
+        }
+        for (i, row) in mds.iter().enumerate() {
+            if row.len() != width {
+                E::halt(format!("Expected {width} columns in row {i} of the MDS matrix, found {}", row.len()))
+            }
+        }
+
+        // Cache the bits for the field element.
+        alpha.to_bits_le();
+
+        Self { domain, full_rounds, partial_rounds, alpha, ark, mds }
+    }
+
+    /// Returns the input rate of the Poseidon sponge.
+    pub const fn rate(&self) -> usize {
+        RATE
+    }
+
+    /// Returns the capacity of the Poseidon sponge.
+    pub const fn capacity(&self) -> usize {
+        CAPACITY
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonCircuit0";
+
+    /// Returns the circuit parameters for the given native Poseidon instance.
+    #[allow(clippy::type_complexity)]
+    fn constant_parameters<const RATE: usize>(
+        native: &console::Poseidon<<Circuit as Environment>::BaseField, RATE>,
+    ) -> (usize, usize, Field<Circuit>, Vec<Vec<Field<Circuit>>>, Vec<Vec<Field<Circuit>>>) {
+        let parameters = native.parameters();
+        let to_constants = |matrix: &[Vec<_>]| {
+            matrix.iter().map(|row| row.iter().copied().map(Field::constant).collect()).collect()
+        };
+        (
+            parameters.full_rounds,
+            parameters.partial_rounds,
+            Field::constant(<Circuit as Environment>::BaseField::from(parameters.alpha as u128)),
+            to_constants(&parameters.ark[..parameters.full_rounds + parameters.partial_rounds]),
+            to_constants(&parameters.mds),
+        )
+    }
+
+    #[test]
+    fn test_from_parameters() -> Result<()> {
+        let native = console::Poseidon2::<<Circuit as Environment>::BaseField>::setup(DOMAIN)?;
+        let expected = Poseidon2::<Circuit>::constant(native.clone());
+
+        let (full_rounds, partial_rounds, alpha, ark, mds) = constant_parameters(&native);
+        let domain = Field::constant(native.domain());
+        let candidate = Poseidon2::<Circuit>::from_parameters(domain, full_rounds, partial_rounds, alpha, ark, mds);
+
+        // Ensure the custom instance matches the default instance.
+        let input = (0..5).map(|i| Field::new(Mode::Private, (i as u64).into())).collect::<Vec<_>>();
+        assert_eq!(expected.hash(&input).eject_value(), candidate.hash(&input).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 3 columns in row 1 of the MDS matrix, found 2")]
+    fn test_from_parameters_rejects_malformed_mds() {
+        let native = console::Poseidon2::<<Circuit as Environment>::BaseField>::setup(DOMAIN).unwrap();
+
+        let (full_rounds, partial_rounds, alpha, ark, mut mds) = constant_parameters(&native);
+        mds[1].pop();
+        let domain = Field::constant(native.domain());
+        Poseidon2::<Circuit>::from_parameters(domain, full_rounds, partial_rounds, alpha, ark, mds);
+    }
+
+    #[test]
+    #[should_panic(expected = "rows of round keys")]
+    fn test_from_parameters_rejects_malformed_ark() {
+        let native = console::Poseidon2::<<Circuit as Environment>::BaseField>::setup(DOMAIN).unwrap();
+
+        let (full_rounds, partial_rounds, alpha, mut ark, mds) = constant_parameters(&native);
+        ark.pop();
+        let domain = Field::constant(native.domain());
+        Poseidon2::<Circuit>::from_parameters(domain, full_rounds, partial_rounds, alpha, ark, mds);
+    }
+
+    #[test]
+    fn test_rate_and_capacity() -> Result<()> {
+        let native = console::Poseidon4::<<Circuit as Environment>::BaseField>::setup(DOMAIN)?;
+        let poseidon = Poseidon4::<Circuit>::constant(native);
+        assert_eq!(4, poseidon.rate());
+        assert_eq!(1, poseidon.capacity());
+        Ok(())
+    }
+}
@@ -14,7 +14,21 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_circuit_types::environment::{Eject, Inject, ScalarTrait, Ternary, ToBits};
+use snarkvm_circuit_types::{
+    environment::{
+        prelude::PrimeField,
+        BooleanTrait,
+        Eject,
+        Environment,
+        FromBits,
+        Inject,
+        ScalarTrait,
+        Ternary,
+        ToBits,
+    },
+    Field,
+    Scalar,
+};
 
 /// A trait for a commitment scheme.
 pub trait Commit {
@@ -26,6 +40,14 @@ pub trait Commit {
     fn commit(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Self::Output;
 }
 
+/// A trait for a commitment scheme that verifies openings, allowing generic code to accept any commitment backend.
+pub trait CommitmentScheme: Commit {
+    type Boolean: BooleanTrait;
+
+    /// Returns `true` if the given commitment opens to the given input and randomizer.
+    fn verify(&self, input: &[Self::Input], randomizer: &Self::Randomizer, commitment: &Self::Output) -> Self::Boolean;
+}
+
 /// A trait for a commitment scheme.
 pub trait CommitUncompressed {
     type Input;
@@ -81,3 +103,140 @@ pub trait PRF {
     /// Returns the output for the given seed and input.
     fn prf(&self, seed: &Self::Seed, input: &[Self::Input]) -> Self::Output;
 }
+
+/// A trait for reducing a base field element into the scalar field.
+pub trait ToScalarReduced {
+    type Scalar: ScalarTrait;
+
+    /// Returns the scalar field element from truncating `self` to the size in data bits of the scalar field.
+    fn to_scalar_reduced(&self) -> Self::Scalar;
+}
+
+impl<E: Environment> ToScalarReduced for Field<E> {
+    type Scalar = Scalar<E>;
+
+    /// Returns the scalar field element from truncating `self` to the size in data bits of the scalar field.
+    /// This is the canonical projection from the base field onto the scalar field, used by `hash_to_scalar`.
+    fn to_scalar_reduced(&self) -> Self::Scalar {
+        // Truncate `self` to the size in data bits (1 bit less than the MODULUS) of the scalar.
+        // Slicing here is safe as the base field is larger than the scalar field.
+        Scalar::from_bits_le(&self.to_bits_le()[..E::ScalarField::size_in_data_bits()])
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Poseidon;
+    use snarkvm_circuit_types::{
+        environment::{prelude::O, Circuit, Mode},
+        Boolean,
+    };
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use anyhow::Result;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_to_scalar_reduced() -> Result<()> {
+        use console::ToScalarReduced as T;
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                // Sample a random base field element.
+                let given = <Circuit as Environment>::BaseField::rand(&mut test_rng());
+                let expected: <Circuit as Environment>::ScalarField = given.to_scalar_reduced()?;
+
+                // Ensure the circuit reduction matches the native reduction.
+                Circuit::scope(format!("{mode} {i}"), || {
+                    let candidate = Field::<Circuit>::new(mode, given).to_scalar_reduced();
+                    assert_eq!(expected, candidate.eject_value());
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+                Circuit::reset();
+            }
+        }
+
+        // Ensure the largest base field element reduces successfully.
+        let given = -<Circuit as Environment>::BaseField::one();
+        let expected: <Circuit as Environment>::ScalarField = given.to_scalar_reduced()?;
+        assert_eq!(expected, Field::<Circuit>::new(Mode::Private, given).to_scalar_reduced().eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_scalar_reduced_matches_hash_to_scalar() -> Result<()> {
+        use console::{Hash as H, HashToScalar as HS, ToScalarReduced as T};
+
+        let native = console::Poseidon::<<Circuit as Environment>::BaseField, 4>::setup("PoseidonCircuit0")?;
+        let poseidon = Poseidon::<Circuit, 4>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input = (0..i % 8).map(|_| UniformRand::rand(&mut test_rng())).collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Field::<Circuit>::new(Mode::Private, *v)).collect::<Vec<_>>();
+
+            // Ensure the native reduction of the hash matches `hash_to_scalar`.
+            let expected: <Circuit as Environment>::ScalarField = native.hash_to_scalar(&native_input)?;
+            assert_eq!(expected, native.hash(&native_input)?.to_scalar_reduced()?);
+
+            // Ensure the circuit reduction of the hash matches `hash_to_scalar`.
+            Circuit::scope(format!("Poseidon {i}"), || {
+                assert_eq!(expected, poseidon.hash(&input).to_scalar_reduced().eject_value());
+                assert_eq!(expected, poseidon.hash_to_scalar(&input).eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    /// Checks that a commitment verifies with its opening, and fails to verify with a different input or randomizer.
+    fn check_commitment_scheme<C>(scheme: &C, num_bits: usize)
+    where
+        C: CommitmentScheme<Input = Boolean<Circuit>, Randomizer = Scalar<Circuit>, Boolean = Boolean<Circuit>>,
+    {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS / 10 {
+                // Sample a random input and randomizer.
+                let input = (0..num_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<_>>();
+                let randomizer = UniformRand::rand(&mut test_rng());
+
+                Circuit::scope(format!("{mode} {i}"), || {
+                    let input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+                    let randomizer = Scalar::new(mode, randomizer);
+
+                    // Ensure the commitment verifies with its opening.
+                    let commitment = scheme.commit(&input, &randomizer);
+                    assert!(scheme.verify(&input, &randomizer, &commitment).eject_value());
+
+                    // Ensure the commitment does not verify with a different input.
+                    let mut other_input = input.clone();
+                    other_input[0] = !&other_input[0];
+                    assert!(!scheme.verify(&other_input, &randomizer, &commitment).eject_value());
+
+                    // Ensure the commitment does not verify with a different randomizer.
+                    let other_randomizer = Scalar::new(mode, UniformRand::rand(&mut test_rng()));
+                    assert!(!scheme.verify(&input, &other_randomizer, &commitment).eject_value());
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_commitment_scheme() -> Result<()> {
+        type Affine = <Circuit as Environment>::Affine;
+
+        let pedersen = console::Pedersen64::<Affine>::setup("CommitmentCircuit0");
+        check_commitment_scheme(&crate::Pedersen64::<Circuit>::constant(pedersen), 64);
+
+        let bhp = console::BHP256::<Affine>::setup("CommitmentCircuit0")?;
+        check_commitment_scheme(&crate::BHP256::<Circuit>::constant(bhp), 256);
+        Ok(())
+    }
+}
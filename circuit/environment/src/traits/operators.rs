@@ -71,6 +71,16 @@ pub trait Ternary {
         Self: Sized;
 }
 
+/// Trait for multiplexer operations, selecting one of many options by index.
+pub trait Select<Index: ?Sized> {
+    type Output;
+
+    /// Returns the option at position `index`, enforcing that `index` is within bounds.
+    fn select(index: &Index, options: &[Self]) -> Self::Output
+    where
+        Self: Sized;
+}
+
 /// Binary operator for adding two values, enforcing an overflow never occurs.
 pub trait AddChecked<Rhs: ?Sized = Self> {
     type Output;
@@ -46,7 +46,7 @@ impl<A: Aleo> FromBits for Identifier<A> {
         let max_bytes = A::BaseField::size_in_data_bits() / 8; // Note: This intentionally rounds down.
         match num_bytes <= max_bytes {
             // Return the identifier.
-            true => Self(field, num_bytes as u8),
+            true => Self(field, num_bytes as u8, Default::default()),
             false => A::halt("Identifier exceeds the maximum capacity allowed"),
         }
     }
@@ -78,7 +78,7 @@ impl<A: Aleo> FromBits for Identifier<A> {
         let max_bytes = A::BaseField::size_in_data_bits() / 8; // Note: This intentionally rounds down.
         match num_bytes <= max_bytes {
             // Return the identifier.
-            true => Self(field, num_bytes as u8),
+            true => Self(field, num_bytes as u8, Default::default()),
             false => A::halt("Identifier exceeds the maximum capacity allowed"),
         }
     }
@@ -35,7 +35,7 @@ use nom::character::complete::{alpha1, alphanumeric1};
 /// The identifier must not consist solely of underscores.
 /// The identifier must fit within the data capacity of a base field element.
 #[derive(Clone)]
-pub struct Identifier<A: Aleo>(Field<A>, u8); // Number of bytes
+pub struct Identifier<A: Aleo>(Field<A>, u8, OnceCell<Vec<Boolean<A>>>); // Number of bytes, cached bits
 
 #[cfg(console)]
 impl<A: Aleo> Inject for Identifier<A> {
@@ -77,7 +77,7 @@ impl<A: Aleo> Inject for Identifier<A> {
         let field = Field::from_bits_le(&Vec::<Boolean<_>>::constant(identifier.as_bytes().to_bits_le()));
 
         // Return the identifier.
-        Self(field, identifier.as_bytes().len() as u8)
+        Self(field, identifier.as_bytes().len() as u8, Default::default())
     }
 }
 
@@ -21,13 +21,82 @@ impl<A: Aleo> ToBits for Identifier<A> {
 
     /// Returns the little-endian bits of the identifier.
     fn to_bits_le(&self) -> Vec<Self::Boolean> {
-        self.0.to_bits_le()[..8 * self.1 as usize].to_vec()
+        self.bits_le().to_vec()
     }
 
     /// Returns the big-endian bits of the identifier.
     fn to_bits_be(&self) -> Vec<Self::Boolean> {
-        let mut bits = self.to_bits_le();
-        bits.reverse();
-        bits
+        self.bits_le().iter().rev().cloned().collect()
+    }
+}
+
+impl<A: Aleo> Identifier<A> {
+    /// Returns the little-endian bits of the identifier, computing and caching them on the first call.
+    fn bits_le(&self) -> &[Boolean<A>] {
+        self.2.get_or_init(|| self.0.to_bits_le()[..8 * self.1 as usize].to_vec())
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_circuit_types::environment::assert_scope;
+
+    const ITERATIONS: usize = 1000;
+
+    #[test]
+    fn test_to_bits_matches_field_bits() {
+        for string in ["a", "foo_bar", "a_long_identifier_for_testing"] {
+            let identifier = Identifier::<Circuit>::from_str(string);
+
+            // Compute the expected bits directly from the field element.
+            let expected_le = identifier.0.to_bits_le()[..8 * string.len()].to_vec().eject_value();
+            let mut expected_be = expected_le.clone();
+            expected_be.reverse();
+
+            assert_eq!(expected_le, identifier.to_bits_le().eject_value());
+            assert_eq!(expected_be, identifier.to_bits_be().eject_value());
+        }
+    }
+
+    #[test]
+    fn test_to_bits_matches_console() {
+        const CHARACTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz_0123456789";
+
+        // Check every valid identifier length, up to the data capacity of the base field.
+        let max_bytes = <Circuit as Environment>::BaseField::size_in_data_bits() / 8;
+        for num_bytes in 1..=max_bytes {
+            let string: String = (0..num_bytes).map(|i| CHARACTERS[i % CHARACTERS.len()] as char).collect();
+
+            // Inject the console identifier.
+            let expected = console::Identifier::<<Circuit as Aleo>::Network>::try_from(string.as_str()).unwrap();
+            let candidate = Identifier::<Circuit>::new(Mode::Constant, expected.clone());
+
+            // Ensure the little-endian bits match the console bits.
+            let expected_le = expected.to_bits_le();
+            assert_eq!(8 * num_bytes, expected_le.len());
+            assert_eq!(expected_le, candidate.to_bits_le().eject_value());
+
+            // Ensure the big-endian bits are the reversal of the console little-endian bits.
+            let expected_be = expected_le.into_iter().rev().collect::<Vec<_>>();
+            assert_eq!(expected_be, candidate.to_bits_be().eject_value());
+        }
+    }
+
+    #[test]
+    fn test_to_bits_repeated_calls() {
+        let identifier = Identifier::<Circuit>::from_str("foo_bar");
+        let expected_le = identifier.to_bits_le().eject_value();
+        let expected_be = identifier.to_bits_be().eject_value();
+
+        Circuit::scope("Identifier::to_bits repeated", || {
+            for _ in 0..ITERATIONS {
+                assert_eq!(expected_le, identifier.to_bits_le().eject_value());
+                assert_eq!(expected_be, identifier.to_bits_be().eject_value());
+            }
+            // Ensure the repeated calls reuse the cached bits, and allocate nothing.
+            assert_scope!(0, 0, 0, 0);
+        });
     }
 }
@@ -26,5 +26,8 @@ pub use data::{Ciphertext, Data, Identifier, Literal, Plaintext, Visibility};
 mod record;
 pub use record::Record;
 
+mod sparse_merkle_tree;
+pub use sparse_merkle_tree::{PathHash, SparseMerklePath};
+
 mod state;
 pub use state::State;
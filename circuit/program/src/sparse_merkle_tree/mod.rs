@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod path_hash;
+pub use path_hash::*;
+
+mod verify_non_membership;
+
+use snarkvm_circuit_network::Aleo;
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field};
+
+/// The sibling hashes of a path (in order) from the leaf to the root of a sparse Merkle tree.
+/// The leaf position is given by the key, so it is not stored in the path.
+pub struct SparseMerklePath<A: Aleo, const DEPTH: u8> {
+    siblings: Vec<Field<A>>,
+}
+
+#[cfg(console)]
+impl<A: Aleo, const DEPTH: u8> Inject for SparseMerklePath<A, DEPTH> {
+    type Primitive = console::SparseMerklePath<A::Network, DEPTH>;
+
+    /// Initializes a new sparse Merkle path from the given mode and path.
+    fn new(mode: Mode, path: Self::Primitive) -> Self {
+        Self { siblings: path.siblings().iter().map(|sibling| Field::new(mode, *sibling)).collect() }
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo, const DEPTH: u8> Eject for SparseMerklePath<A, DEPTH> {
+    type Primitive = console::SparseMerklePath<A::Network, DEPTH>;
+
+    /// Ejects the mode of the sparse Merkle path.
+    fn eject_mode(&self) -> Mode {
+        self.siblings.eject_mode()
+    }
+
+    /// Ejects the sparse Merkle path.
+    fn eject_value(&self) -> Self::Primitive {
+        match Self::Primitive::try_from(self.siblings.eject_value()) {
+            Ok(path) => path,
+            Err(error) => A::halt(format!("Failed to eject the sparse Merkle path: {error}")),
+        }
+    }
+}
+
+impl<A: Aleo, const DEPTH: u8> SparseMerklePath<A, DEPTH> {
+    /// Returns the sibling hashes of the sparse Merkle path, from leaf to root.
+    pub fn siblings(&self) -> &[Field<A>] {
+        &self.siblings
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_circuit_algorithms::{Hash, Poseidon, BHP};
+
+/// A trait for a Merkle path hash function.
+pub trait PathHash<E: Environment> {
+    /// Returns the hash of the given path nodes.
+    fn hash(&self, left: &Field<E>, right: &Field<E>) -> Field<E>;
+
+    /// Returns the empty hash.
+    fn hash_empty(&self) -> Field<E> {
+        self.hash(&Field::zero(), &Field::zero())
+    }
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> PathHash<E> for BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the hash of the given path nodes.
+    fn hash(&self, left: &Field<E>, right: &Field<E>) -> Field<E> {
+        // Prepend the nodes with a `true` bit.
+        let mut input = vec![Boolean::constant(true)];
+        input.extend(left.to_bits_le());
+        input.extend(right.to_bits_le());
+        // Hash the input.
+        Hash::hash(self, &input)
+    }
+}
+
+impl<E: Environment, const RATE: usize> PathHash<E> for Poseidon<E, RATE> {
+    /// Returns the hash of the given path nodes.
+    fn hash(&self, left: &Field<E>, right: &Field<E>) -> Field<E> {
+        // Prepend the nodes with a `1field` element.
+        let input = [Field::one(), left.clone(), right.clone()];
+        // Hash the input.
+        Hash::hash(self, &input)
+    }
+}
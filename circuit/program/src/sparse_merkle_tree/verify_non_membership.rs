@@ -0,0 +1,175 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo, const DEPTH: u8> SparseMerklePath<A, DEPTH> {
+    /// Returns `true` if the path proves the given key is not in the tree with the given root,
+    /// by proving the leaf at the position of the key is empty.
+    pub fn verify_non_membership<PH: PathHash<A>>(
+        &self,
+        path_hasher: &PH,
+        root: &Field<A>,
+        key: &Field<A>,
+    ) -> Boolean<A> {
+        // Ensure the path length matches the expected depth.
+        if self.siblings.len() != DEPTH as usize {
+            A::halt(format!("Expected a sparse Merkle path of length {DEPTH}, found length {}", self.siblings.len()))
+        }
+
+        // Retrieve the bits of the key.
+        let key_bits = key.to_bits_le();
+        // Ensure the bits of the key are canonical (i.e. less than the modulus), so the leaf position is unique.
+        A::assert_eq(key, Field::from_bits_le(&key_bits));
+
+        // Start from the empty leaf hash.
+        let mut current_hash = path_hasher.hash_empty();
+
+        // Check levels between leaf level and root.
+        // Note: The leaf position is the `DEPTH` least significant bits of the key.
+        for (is_right, sibling_hash) in key_bits.iter().take(DEPTH as usize).zip_eq(&self.siblings) {
+            // Check if path node at this level is left or right.
+            let left = Field::ternary(is_right, sibling_hash, &current_hash);
+            let right = Field::ternary(is_right, &current_hash, sibling_hash);
+            // Update the current path node.
+            current_hash = path_hasher.hash(&left, &right);
+        }
+
+        // Check if final hash is root.
+        current_hash.is_equal(root)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_circuit_types::environment::assert_scope;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Aleo>::Network;
+    type NativeLH = snarkvm_console_algorithms::Poseidon<<Circuit as Environment>::BaseField, 4>;
+    type NativePH = snarkvm_console_algorithms::Poseidon<<Circuit as Environment>::BaseField, 2>;
+    type PH = snarkvm_circuit_algorithms::Poseidon<Circuit, 2>;
+
+    const DEPTH: u8 = 16;
+    const ITERATIONS: usize = 10;
+
+    /// Returns a new sparse Merkle tree with the given number of random keys.
+    fn sample_tree(
+        num_keys: usize,
+    ) -> Result<(NativePH, console::SparseMerkleTree<CurrentNetwork, NativeLH, NativePH, DEPTH>)> {
+        let leaf_hasher = NativeLH::setup("AleoSMTTest0")?;
+        let path_hasher = NativePH::setup("AleoSMTTest1")?;
+        let mut tree = console::SparseMerkleTree::new(&leaf_hasher, &path_hasher)?;
+        for _ in 0..num_keys {
+            tree.insert(UniformRand::rand(&mut test_rng()), vec![UniformRand::rand(&mut test_rng())])?;
+        }
+        Ok((path_hasher, tree))
+    }
+
+    fn check_verify_non_membership(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        let (native_path_hasher, tree) = sample_tree(ITERATIONS)?;
+        let path_hasher = PH::constant(native_path_hasher.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample a key that is not in the tree, and compute its non-membership proof.
+            let key = UniformRand::rand(&mut test_rng());
+            let path = tree.prove_non_membership(&key)?;
+            assert!(path.verify_non_membership(&native_path_hasher, tree.root(), &key));
+
+            // Initialize the circuit path, root, and key.
+            let circuit_path = SparseMerklePath::<Circuit, DEPTH>::new(mode, path.clone());
+            assert_eq!(path, circuit_path.eject_value());
+            let root = Field::<Circuit>::new(mode, *tree.root());
+            let circuit_key = Field::<Circuit>::new(mode, key);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = circuit_path.verify_non_membership(&path_hasher, &root, &circuit_key);
+                assert!(candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            assert!(Circuit::is_satisfied());
+
+            // Ensure the non-membership proof **fails** on an invalid root.
+            let invalid_root = Field::<Circuit>::new(mode, UniformRand::rand(&mut test_rng()));
+            let candidate = circuit_path.verify_non_membership(&path_hasher, &invalid_root, &circuit_key);
+            assert!(!candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    fn check_verify_non_membership_fails_when_present(mode: Mode) -> Result<()> {
+        let (native_path_hasher, tree) = sample_tree(ITERATIONS)?;
+        let path_hasher = PH::constant(native_path_hasher.clone());
+
+        for _ in 0..ITERATIONS {
+            // Compute a non-membership proof for a key, and then insert the key.
+            let key = UniformRand::rand(&mut test_rng());
+            let path = tree.prove_non_membership(&key)?;
+            let mut tree = tree.clone();
+            tree.insert(key, vec![UniformRand::rand(&mut test_rng())])?;
+
+            // Ensure the non-membership proof **fails** natively, as the key is present.
+            let expected = path.verify_non_membership(&native_path_hasher, tree.root(), &key);
+            assert!(!expected);
+
+            // Ensure the non-membership proof **fails** in the circuit.
+            let circuit_path = SparseMerklePath::<Circuit, DEPTH>::new(mode, path);
+            let root = Field::<Circuit>::new(mode, *tree.root());
+            let circuit_key = Field::<Circuit>::new(mode, key);
+            let candidate = circuit_path.verify_non_membership(&path_hasher, &root, &circuit_key);
+            assert_eq!(expected, candidate.eject_value());
+
+            // Ensure enforcing the non-membership proof is unsatisfiable.
+            Circuit::assert(candidate);
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_non_membership_constant() -> Result<()> {
+        check_verify_non_membership(Mode::Constant, 271, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_verify_non_membership_public() -> Result<()> {
+        check_verify_non_membership(Mode::Public, 17, 0, 9179, 9183)
+    }
+
+    #[test]
+    fn test_verify_non_membership_private() -> Result<()> {
+        check_verify_non_membership(Mode::Private, 17, 0, 9179, 9183)
+    }
+
+    #[test]
+    fn test_verify_non_membership_fails_when_present() -> Result<()> {
+        check_verify_non_membership_fails_when_present(Mode::Public)?;
+        check_verify_non_membership_fails_when_present(Mode::Private)
+    }
+}
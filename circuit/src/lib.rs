@@ -38,3 +38,125 @@ pub mod prelude {
     pub use super::*;
     pub use snarkvm_circuit_environment::*;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_scope, Circuit};
+
+    //
+    // Constraint-count regression harness.
+    //
+    // Each test synthesizes one core gadget per mode, and asserts the exact number of
+    // constants, public variables, private variables, and constraints it allocates.
+    // If a change to shared code alters any of these counts, the corresponding expected
+    // value below must be updated deliberately, alongside a justification in the change.
+    //
+
+    /// The expected (constants, public, private, constraints) for the constant, public, and private modes.
+    type ExpectedCounts = [(Mode, u64, u64, u64, u64); 3];
+
+    /// Returns a sample base field element.
+    fn sample_field(seed: u64) -> <Circuit as Environment>::BaseField {
+        <Circuit as Environment>::BaseField::from(seed)
+    }
+
+    #[test]
+    fn test_field_ternary_counts() {
+        const EXPECTED: ExpectedCounts =
+            [(Mode::Constant, 0, 0, 0, 0), (Mode::Public, 0, 0, 1, 1), (Mode::Private, 0, 0, 1, 1)];
+
+        for (mode, num_constants, num_public, num_private, num_constraints) in EXPECTED {
+            let condition = Boolean::<Circuit>::new(mode, true);
+            let first = Field::<Circuit>::new(mode, sample_field(1234567));
+            let second = Field::<Circuit>::new(mode, sample_field(7654321));
+
+            Circuit::scope(format!("Field::ternary {mode}"), || {
+                let candidate = Field::ternary(&condition, &first, &second);
+                assert_eq!(first.eject_value(), candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_integer_bitwise_counts() {
+        const EXPECTED_XOR: ExpectedCounts =
+            [(Mode::Constant, 0, 0, 0, 0), (Mode::Public, 0, 0, 64, 64), (Mode::Private, 0, 0, 64, 64)];
+        const EXPECTED_AND: ExpectedCounts =
+            [(Mode::Constant, 0, 0, 0, 0), (Mode::Public, 0, 0, 64, 64), (Mode::Private, 0, 0, 64, 64)];
+        const EXPECTED_OR: ExpectedCounts =
+            [(Mode::Constant, 0, 0, 0, 0), (Mode::Public, 0, 0, 64, 64), (Mode::Private, 0, 0, 64, 64)];
+
+        let (a, b) = (0x0123_4567_89ab_cdefu64, 0xfedc_ba98_7654_3210u64);
+
+        type Operation = fn(&U64<Circuit>, &U64<Circuit>) -> U64<Circuit>;
+
+        let operations: [(&str, ExpectedCounts, Operation, u64); 3] = [
+            ("xor", EXPECTED_XOR, |a, b| a ^ b, a ^ b),
+            ("and", EXPECTED_AND, |a, b| a & b, a & b),
+            ("or", EXPECTED_OR, |a, b| a | b, a | b),
+        ];
+
+        for (name, expected, operation, expected_value) in operations {
+            for (mode, num_constants, num_public, num_private, num_constraints) in expected {
+                let first = U64::<Circuit>::new(mode, a);
+                let second = U64::<Circuit>::new(mode, b);
+
+                Circuit::scope(format!("U64::{name} {mode}"), || {
+                    let candidate = operation(&first, &second);
+                    assert_eq!(expected_value, candidate.eject_value());
+                    assert_scope!(num_constants, num_public, num_private, num_constraints);
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_scalar_from_bits_le_counts() {
+        const EXPECTED: ExpectedCounts =
+            [(Mode::Constant, 252, 0, 0, 0), (Mode::Public, 251, 0, 250, 251), (Mode::Private, 251, 0, 250, 251)];
+
+        let expected = <Circuit as Environment>::ScalarField::from(1234567890u64);
+        let bits_le =
+            Scalar::<Circuit>::constant(expected).to_bits_le().iter().map(|bit| bit.eject_value()).collect::<Vec<_>>();
+        Circuit::reset();
+
+        for (mode, num_constants, num_public, num_private, num_constraints) in EXPECTED {
+            let candidate_bits = bits_le.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+            Circuit::scope(format!("Scalar::from_bits_le {mode}"), || {
+                let candidate = Scalar::<Circuit>::from_bits_le(&candidate_bits);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_bhp_commit_counts() {
+        const EXPECTED: ExpectedCounts =
+            [(Mode::Constant, 826, 0, 0, 0), (Mode::Public, 911, 0, 1949, 1949), (Mode::Private, 911, 0, 1949, 1949)];
+
+        // Initialize the BHP bases outside of the measured scope.
+        let _ = AleoV0::commit_bhp256(&[Boolean::constant(true)], &Scalar::constant(Default::default()));
+        Circuit::reset();
+
+        let input = (0..256u32).map(|i| i % 3 == 0).collect::<Vec<_>>();
+        let randomizer = <Circuit as Environment>::ScalarField::from(987654321u64);
+
+        for (mode, num_constants, num_public, num_private, num_constraints) in EXPECTED {
+            let circuit_input = input.iter().map(|bit| Boolean::<AleoV0>::new(mode, *bit)).collect::<Vec<_>>();
+            let circuit_randomizer = Scalar::<AleoV0>::new(mode, randomizer);
+
+            Circuit::scope(format!("BHP256::commit {mode}"), || {
+                let _candidate = AleoV0::commit_bhp256(&circuit_input, &circuit_randomizer);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+}
@@ -20,11 +20,17 @@ impl<E: Environment> FromBits for Address<E> {
     type Boolean = Boolean<E>;
 
     /// Initializes an address from a list of little-endian bits *without* trailing zeros.
+    ///
+    /// The bits are reconstructed into the **x-coordinate**, from which the y-coordinate is recovered,
+    /// and the point is enforced to satisfy the curve equation.
     fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
         Self(Group::from_bits_le(bits_le))
     }
 
     /// Initializes an address from a list of big-endian bits *without* leading zeros.
+    ///
+    /// The bits are reconstructed into the **x-coordinate**, from which the y-coordinate is recovered,
+    /// and the point is enforced to satisfy the curve equation.
     fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
         Self(Group::from_bits_be(bits_be))
     }
@@ -83,6 +89,44 @@ mod tests {
         check_from_bits_le(Mode::Private, 2, 0, 255, 256);
     }
 
+    #[test]
+    fn test_from_bits_le_invalid_is_unsatisfiable() {
+        for mode in [Mode::Public, Mode::Private] {
+            // Sample a random element.
+            let expected: <Circuit as Environment>::Affine = UniformRand::rand(&mut test_rng());
+
+            Circuit::scope(&format!("{} invalid", mode), || {
+                // Append a non-zero excess bit beyond the base field size.
+                let mut candidate = Group::<Circuit>::new(mode, expected).to_bits_le();
+                candidate.push(Boolean::new(mode, true));
+
+                let _candidate = Address::<Circuit>::from_bits_le(&candidate);
+                assert!(!Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_off_curve_is_unsatisfiable() {
+        for mode in [Mode::Public, Mode::Private] {
+            // Sample an x-coordinate that is not on the curve.
+            let x_coordinate = loop {
+                let x_coordinate: <Circuit as Environment>::BaseField = UniformRand::rand(&mut test_rng());
+                if <Circuit as Environment>::Affine::from_x_coordinate(x_coordinate, true).is_none() {
+                    break x_coordinate;
+                }
+            };
+
+            Circuit::scope(&format!("{} off-curve", mode), || {
+                let candidate = Field::<Circuit>::new(mode, x_coordinate).to_bits_le();
+                let _candidate = Address::<Circuit>::from_bits_le(&candidate);
+                assert!(!Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
     #[test]
     fn test_from_bits_be_constant() {
         check_from_bits_be(Mode::Constant, 3, 0, 0, 0);
@@ -465,4 +465,41 @@ mod tests {
         let b = Field::<Circuit>::new(Mode::Private, second);
         check_ternary("true ? Private : Private", expected, condition, a, b);
     }
+
+    #[test]
+    fn test_output_mode() {
+        let first: <Circuit as Environment>::BaseField = UniformRand::rand(&mut test_rng());
+        let second: <Circuit as Environment>::BaseField = UniformRand::rand(&mut test_rng());
+
+        // The expected output mode is:
+        //   - the mode of the selected input, if `condition` is a constant, and
+        //   - `Mode::Private`, otherwise (including when both inputs are constants).
+        let expected_mode = |condition: (Mode, bool), first: Mode, second: Mode| match condition {
+            (Mode::Constant, true) => first,
+            (Mode::Constant, false) => second,
+            _ => Mode::Private,
+        };
+
+        for condition_mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for first_mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                for second_mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                    for value in [true, false] {
+                        let name = format!("{value} ({condition_mode}) ? {first_mode} : {second_mode}");
+                        let condition = Boolean::<Circuit>::new(condition_mode, value);
+                        let a = Field::<Circuit>::new(first_mode, first);
+                        let b = Field::<Circuit>::new(second_mode, second);
+
+                        let expected = expected_mode((condition_mode, value), first_mode, second_mode);
+
+                        Circuit::scope(&name, || {
+                            let candidate = Field::ternary(&condition, &a, &b);
+                            assert_eq!(expected, candidate.eject_mode(), "{name}");
+                            assert_output_mode!(Ternary(Boolean, Field, Field) => Field, &(CircuitType::from(&condition), first_mode, second_mode), candidate);
+                        });
+                        Circuit::reset();
+                    }
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Group<E> {
+    /// Enforces that the group element is in the prime-order subgroup.
+    ///
+    /// This witnesses `q = self * cofactor^{-1}` as a point on the curve, and enforces `self == q * cofactor`.
+    /// As the cofactor multiple of any point on the curve is in the prime-order subgroup,
+    /// the circuit is unsatisfiable if `self` is not in the prime-order subgroup.
+    ///
+    /// Costs 14 private variables and 15 constraints, unless `self` is a constant.
+    pub fn enforce_in_subgroup(&self) {
+        // Witness the quotient of `self` by the cofactor.
+        let quotient: Group<E> = witness!(|self| self.mul_by_cofactor_inv());
+        // Ensure `self` is the product of the quotient and the cofactor.
+        let product = quotient.mul_by_cofactor();
+        E::assert_eq(&self.x, product.x);
+        E::assert_eq(&self.y, product.y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_enforce_in_subgroup(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let point = <Circuit as Environment>::Affine::rand(&mut test_rng());
+            let affine = Group::<Circuit>::new(mode, point);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                affine.enforce_in_subgroup();
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_enforce_in_subgroup_constant() {
+        check_enforce_in_subgroup(Mode::Constant, 10, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_enforce_in_subgroup_public() {
+        check_enforce_in_subgroup(Mode::Public, 4, 0, 14, 15);
+    }
+
+    #[test]
+    fn test_enforce_in_subgroup_private() {
+        check_enforce_in_subgroup(Mode::Private, 4, 0, 14, 15);
+    }
+
+    #[test]
+    fn test_enforce_in_subgroup_fails() {
+        // Construct the point of order 2, i.e. (0, -1).
+        let small_order = <Circuit as Environment>::Affine::from_coordinates((
+            <Circuit as Environment>::BaseField::zero(),
+            -<Circuit as Environment>::BaseField::one(),
+        ));
+        assert!(!small_order.is_in_correct_subgroup_assuming_on_curve());
+
+        for i in 0..ITERATIONS {
+            // Sample a random element, and offset it by the small-order point.
+            let point = <Circuit as Environment>::Affine::rand(&mut test_rng());
+            let candidate = match i {
+                0 => small_order,
+                _ => (point.to_projective() + small_order.to_projective()).into(),
+            };
+
+            for mode in [Mode::Public, Mode::Private] {
+                let affine = Group::<Circuit>::new(mode, candidate);
+                assert!(Circuit::is_satisfied());
+
+                // Ensure the circuit is unsatisfiable.
+                affine.enforce_in_subgroup();
+                assert!(!Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_enforce_in_subgroup_constant_fails() {
+        let small_order = <Circuit as Environment>::Affine::from_coordinates((
+            <Circuit as Environment>::BaseField::zero(),
+            -<Circuit as Environment>::BaseField::one(),
+        ));
+        Group::<Circuit>::constant(small_order).enforce_in_subgroup();
+    }
+}
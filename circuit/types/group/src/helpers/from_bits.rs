@@ -40,7 +40,7 @@ impl<E: Environment> FromBits for Group<E> {
 mod tests {
     use super::*;
     use snarkvm_circuit_environment::Circuit;
-    use snarkvm_utilities::{test_rng, UniformRand};
+    use snarkvm_utilities::{test_rng, ToBits as TBits, UniformRand};
 
     const ITERATIONS: u64 = 100;
 
@@ -74,6 +74,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bits_round_trip() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                // Sample a random element.
+                let expected: <Circuit as Environment>::Affine = UniformRand::rand(&mut test_rng());
+                let bits_le = Group::<Circuit>::new(mode, expected).to_bits_le();
+
+                // Ensure the bits match the console encoding of the x-coordinate.
+                let expected_bits_le = TBits::to_bits_le(&expected.to_x_coordinate());
+                assert_eq!(expected_bits_le, bits_le.eject_value());
+
+                // Ensure the group element is recovered from its bits.
+                let candidate = Group::<Circuit>::from_bits_le(&bits_le);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_off_curve_fails() {
+        let rng = &mut test_rng();
+
+        for mode in [Mode::Public, Mode::Private] {
+            // Sample an x-coordinate that does not lie on the curve.
+            let x = loop {
+                let x: <Circuit as Environment>::BaseField = UniformRand::rand(rng);
+                if <Circuit as Environment>::Affine::from_x_coordinate(x, true).is_none() {
+                    break x;
+                }
+            };
+            let bits_le = TBits::to_bits_le(&x).into_iter().map(|bit| Boolean::new(mode, bit)).collect::<Vec<_>>();
+
+            // Ensure the reconstruction is unsatisfiable.
+            let _candidate = Group::<Circuit>::from_bits_le(&bits_le);
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
     #[test]
     fn test_from_bits_le_constant() {
         check_from_bits_le(Mode::Constant, 3, 0, 0, 0);
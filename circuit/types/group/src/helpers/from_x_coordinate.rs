@@ -21,7 +21,11 @@ impl<E: Environment> Group<E> {
     /// For safety, the resulting point is always enforced to be on the curve with constraints.
     pub fn from_x_coordinate(x: Field<E>) -> Self {
         // Derive the y-coordinate.
-        let y = witness!(|x| E::affine_from_x_coordinate(x).to_y_coordinate());
+        let y = witness!(|x| match E::Affine::from_x_coordinate(x, true) {
+            Some(..) => E::affine_from_x_coordinate(x).to_y_coordinate(),
+            // If the x-coordinate is not on the curve, assign a y-coordinate that fails the curve equation.
+            None => E::BaseField::zero(),
+        });
 
         Self::from_xy_coordinates(x, y)
     }
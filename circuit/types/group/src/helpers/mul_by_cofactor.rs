@@ -18,6 +18,8 @@ use super::*;
 
 impl<E: Environment> Group<E> {
     /// Returns the product of the group element and the cofactor.
+    ///
+    /// Costs 10 private variables and 10 constraints, unless `self` is a constant.
     pub fn mul_by_cofactor(&self) -> Group<E> {
         // (For advanced users) The cofactor for this curve is `4`. Thus doubling is used to be performant.
         // See unit tests below, which sanity check that this condition holds.
@@ -69,6 +71,35 @@ mod tests {
         check_mul_by_cofactor(Mode::Private, 2, 0, 10, 10);
     }
 
+    #[test]
+    fn test_mul_by_cofactor_clears_cofactor() {
+        let mut num_outside_subgroup = 0;
+        for i in 0..ITERATIONS {
+            // Sample a random point on the curve, which may be outside of the prime-order subgroup.
+            let x = UniformRand::rand(&mut test_rng());
+            let point = match <Circuit as Environment>::Affine::from_x_coordinate(x, true) {
+                Some(point) => point,
+                None => continue,
+            };
+            if !point.is_in_correct_subgroup_assuming_on_curve() {
+                num_outside_subgroup += 1;
+            }
+
+            let affine = Group::<Circuit>::new(Mode::Private, point);
+
+            Circuit::scope(&format!("Private {}", i), || {
+                // Ensure the product matches the console, and lands in the prime-order subgroup.
+                let candidate = affine.mul_by_cofactor().eject_value();
+                assert_eq!(point.mul_by_cofactor(), candidate);
+                assert!(candidate.is_in_correct_subgroup_assuming_on_curve());
+                assert!(Circuit::is_satisfied());
+            });
+            Circuit::reset();
+        }
+        // Ensure the test covers points outside of the prime-order subgroup.
+        assert!(num_outside_subgroup > 0);
+    }
+
     /// This test shows that computing `mul_by_cofactor` using doubling is more cost-effective for our specific cofactor.
     #[test]
     fn test_mul_by_cofactor_matches() {
@@ -82,11 +82,7 @@ impl<E: Environment, I: IntegerType> DivChecked<Self> for Integer<E, I> {
             }
         } else if I::is_signed() {
             // Ensure that overflow cannot occur in this division.
-            // Signed integer division wraps when the dividend is I::MIN and the divisor is -1.
-            let min = Integer::constant(I::MIN);
-            let neg_one = Integer::constant(I::zero() - I::one());
-            let overflows = self.is_equal(&min) & other.is_equal(&neg_one);
-            E::assert_eq(overflows, E::zero());
+            self.enforce_div_does_not_overflow(other);
 
             // Divide the absolute value of `self` and `other` in the base field.
             // Note that it is safe to use `abs_wrapped`, since the case for I::MIN is handled above.
@@ -107,6 +103,58 @@ impl<E: Environment, I: IntegerType> DivChecked<Self> for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Enforces that the signed division of `self` by `other` does not overflow.
+    /// Signed integer division wraps when the dividend is I::MIN and the divisor is -1.
+    fn enforce_div_does_not_overflow(&self, other: &Self) {
+        let min = Integer::constant(I::MIN);
+        let neg_one = Integer::constant(I::zero() - I::one());
+        let overflows = self.is_equal(&min) & other.is_equal(&neg_one);
+        E::assert_eq(overflows, E::zero());
+    }
+
+    /// Returns the number of constants, public inputs, private inputs, and constraints
+    /// of `div_checked`, excluding its division-by-zero and overflow checks.
+    pub fn div_checked_division_count(case: &(Mode, Mode)) -> Count {
+        match I::is_signed() {
+            true => match (case.0, case.1) {
+                (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                (Mode::Constant, _) | (_, Mode::Constant) => {
+                    Count::less_than(4 * I::BITS, 0, (7 * I::BITS) + 8, (8 * I::BITS) + 13)
+                }
+                (_, _) => Count::is(3 * I::BITS, 0, (8 * I::BITS) + 5, (8 * I::BITS) + 9),
+            },
+            false => match (case.0, case.1) {
+                (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                (Mode::Constant, _) | (_, Mode::Constant) => {
+                    Count::less_than(0, 0, (2 * I::BITS) + 1, (2 * I::BITS) + 2)
+                }
+                (_, _) => Count::is(0, 0, (2 * I::BITS) + 1, (2 * I::BITS) + 2),
+            },
+        }
+    }
+
+    /// Returns the number of constants, public inputs, private inputs, and constraints
+    /// of the division-by-zero check in `div_checked`.
+    /// Division by zero halts during synthesis, and thus adds nothing to the circuit.
+    pub fn div_checked_zero_check_count(_case: &(Mode, Mode)) -> Count {
+        Count::zero()
+    }
+
+    /// Returns the number of constants, public inputs, private inputs, and constraints
+    /// of the overflow check (`I::MIN / -1`) in `div_checked`, which only applies to signed integers.
+    pub fn div_checked_overflow_count(case: &(Mode, Mode)) -> Count {
+        match I::is_signed() {
+            true => match (case.0, case.1) {
+                (Mode::Constant, Mode::Constant) => Count::is(0, 0, 0, 0),
+                (Mode::Constant, _) | (_, Mode::Constant) => Count::less_than(2 * I::BITS, 0, 2, 4),
+                (_, _) => Count::is(2 * I::BITS, 0, 5, 8),
+            },
+            false => Count::is(0, 0, 0, 0),
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> Metrics<dyn Div<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
     type Case = (Mode, Mode);
 
@@ -127,22 +175,9 @@ impl<E: Environment, I: IntegerType> Metrics<dyn DivChecked<Integer<E, I>, Outpu
     type Case = (Mode, Mode);
 
     fn count(case: &Self::Case) -> Count {
-        match I::is_signed() {
-            true => match (case.0, case.1) {
-                (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
-                (Mode::Constant, _) | (_, Mode::Constant) => {
-                    Count::less_than(6 * I::BITS, 0, (7 * I::BITS) + 10, (8 * I::BITS) + 17)
-                }
-                (_, _) => Count::is(5 * I::BITS, 0, (8 * I::BITS) + 10, (8 * I::BITS) + 17),
-            },
-            false => match (case.0, case.1) {
-                (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
-                (Mode::Constant, _) | (_, Mode::Constant) => {
-                    Count::less_than(0, 0, (2 * I::BITS) + 1, (2 * I::BITS) + 2)
-                }
-                (_, _) => Count::is(0, 0, (2 * I::BITS) + 1, (2 * I::BITS) + 2),
-            },
-        }
+        Self::div_checked_division_count(case)
+            + Self::div_checked_zero_check_count(case)
+            + Self::div_checked_overflow_count(case)
     }
 }
 
@@ -177,12 +212,16 @@ mod tests {
             check_operation_halts(&a, &b, Integer::div_checked);
         } else {
             match first.checked_div(&second) {
-                Some(expected) => Circuit::scope(name, || {
-                    let candidate = a.div_checked(&b);
-                    assert_eq!(expected, candidate.eject_value());
-                    assert_count!(DivChecked(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
-                    assert_output_mode!(DivChecked(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
-                }),
+                Some(expected) => {
+                    Circuit::scope(name, || {
+                        let candidate = a.div_checked(&b);
+                        assert_eq!(expected, candidate.eject_value());
+                        assert_count!(DivChecked(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                        let case = (mode_a, mode_b);
+                        assert_output_mode!(DivChecked(Integer<I>, Integer<I>) => Integer<I>, &case, candidate);
+                    });
+                    check_overflow_count(&a, &b, mode_a, mode_b);
+                }
                 None => match (mode_a, mode_b) {
                     (Mode::Constant, Mode::Constant) => check_operation_halts(&a, &b, Integer::div_checked),
                     _ => Circuit::scope(name, || {
@@ -195,6 +234,68 @@ mod tests {
         Circuit::reset();
     }
 
+    fn check_overflow_count<I: IntegerType>(
+        a: &Integer<Circuit, I>,
+        b: &Integer<Circuit, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        Circuit::scope("Overflow check", || {
+            // The overflow check is only synthesized for signed integers that are not both constant.
+            if I::is_signed() && !(mode_a.is_constant() && mode_b.is_constant()) {
+                a.enforce_div_does_not_overflow(b);
+            }
+            let count = Integer::<Circuit, I>::div_checked_overflow_count(&(mode_a, mode_b));
+            assert!(count.matches(
+                Circuit::num_constants_in_scope(),
+                Circuit::num_public_in_scope(),
+                Circuit::num_private_in_scope(),
+                Circuit::num_constraints_in_scope()
+            ));
+        });
+    }
+
+    #[test]
+    fn test_count_breakdown() {
+        fn check<I: IntegerType>() {
+            for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+                for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                    let case = (mode_a, mode_b);
+                    let total = <Integer<Circuit, I> as Metrics<
+                        dyn DivChecked<Integer<Circuit, I>, Output = Integer<Circuit, I>>,
+                    >>::count(&case);
+                    let division = Integer::<Circuit, I>::div_checked_division_count(&case);
+                    let zero_check = Integer::<Circuit, I>::div_checked_zero_check_count(&case);
+                    let overflow = Integer::<Circuit, I>::div_checked_overflow_count(&case);
+
+                    // Ensure the components sum to the total.
+                    let sum = division + zero_check + overflow;
+                    assert_eq!((total.0, total.1, total.2, total.3), (sum.0, sum.1, sum.2, sum.3));
+
+                    // Ensure division by zero adds nothing to the circuit.
+                    let zero = Count::zero();
+                    assert_eq!(
+                        (zero.0, zero.1, zero.2, zero.3),
+                        (zero_check.0, zero_check.1, zero_check.2, zero_check.3)
+                    );
+                    // Ensure the overflow check only applies to signed integers that are not both constant.
+                    let has_overflow_check = I::is_signed() && !(mode_a.is_constant() && mode_b.is_constant());
+                    assert_eq!(has_overflow_check, overflow.3 != zero.3);
+                }
+            }
+        }
+        check::<u8>();
+        check::<i8>();
+        check::<u16>();
+        check::<i16>();
+        check::<u32>();
+        check::<i32>();
+        check::<u64>();
+        check::<i64>();
+        check::<u128>();
+        check::<i128>();
+    }
+
     fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
         for _ in 0..ITERATIONS {
             let first: I = UniformRand::rand(&mut test_rng());
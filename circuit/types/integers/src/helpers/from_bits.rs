@@ -20,6 +20,7 @@ impl<E: Environment, I: IntegerType> FromBits for Integer<E, I> {
     type Boolean = Boolean<E>;
 
     /// Initializes a new integer from a list of little-endian bits *with* trailing zeros.
+    /// If more than `I::BITS` bits are given, the excess bits are enforced to be zero.
     fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
         // Ensure the list of booleans is within the allowed size in bits.
         let num_bits = bits_le.len() as u64;
@@ -49,6 +50,18 @@ impl<E: Environment, I: IntegerType> FromBits for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Initializes a new integer from the lowest `I::BITS` bits of the given little-endian bits,
+    /// *ignoring* any excess bits. To enforce that the excess bits are zero, use `from_bits_le`.
+    pub fn from_bits_le_lossy(bits_le: &[Boolean<E>]) -> Self {
+        // Construct the truncated list of bits, resizing up if necessary.
+        let mut bits_le = bits_le.iter().take(I::BITS as usize).cloned().collect::<Vec<_>>();
+        bits_le.resize(I::BITS as usize, Boolean::constant(false));
+
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,6 +148,68 @@ mod tests {
         }
     }
 
+    fn check_from_bits_le_excess<I: IntegerType>(mode: Mode) {
+        for i in 0..ITERATIONS {
+            // Sample a random integer.
+            let expected: I = UniformRand::rand(&mut test_rng());
+            let num_excess_bits = 1 + i as usize;
+
+            // Exact length.
+            Circuit::scope(&format!("Exact {} {}", mode, i), || {
+                let given_bits = Integer::<Circuit, I>::new(mode, expected).to_bits_le();
+                assert_eq!(expected, Integer::<Circuit, I>::from_bits_le(&given_bits).eject_value());
+                assert_eq!(expected, Integer::<Circuit, I>::from_bits_le_lossy(&given_bits).eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+
+            // Excess zero bits.
+            Circuit::scope(&format!("Zeros {} {}", mode, i), || {
+                let given_bits = Integer::<Circuit, I>::new(mode, expected).to_bits_le();
+                let zeros = [given_bits, vec![Boolean::new(mode, false); num_excess_bits]].concat();
+                assert_eq!(expected, Integer::<Circuit, I>::from_bits_le(&zeros).eject_value());
+                assert_eq!(expected, Integer::<Circuit, I>::from_bits_le_lossy(&zeros).eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+
+            // Excess one bits.
+            Circuit::scope(&format!("Ones {} {}", mode, i), || {
+                let given_bits = Integer::<Circuit, I>::new(mode, expected).to_bits_le();
+                let ones = [given_bits, vec![Boolean::new(mode, true); num_excess_bits]].concat();
+                // Ensure the lossy version ignores the excess bits.
+                assert_eq!(expected, Integer::<Circuit, I>::from_bits_le_lossy(&ones).eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+                // Ensure the checked version is unsatisfiable.
+                assert_eq!(expected, Integer::<Circuit, I>::from_bits_le(&ones).eject_value());
+                assert!(!Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_excess() {
+        check_from_bits_le_excess::<u8>(Mode::Public);
+        check_from_bits_le_excess::<u8>(Mode::Private);
+        check_from_bits_le_excess::<i16>(Mode::Private);
+        check_from_bits_le_excess::<u64>(Mode::Private);
+        check_from_bits_le_excess::<i128>(Mode::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_bits_le_excess_constant_ones_halts() {
+        let bits = [Integer::<Circuit, u8>::constant(1).to_bits_le(), vec![Boolean::constant(true)]].concat();
+        let _ = Integer::<Circuit, u8>::from_bits_le(&bits);
+    }
+
+    #[test]
+    fn test_from_bits_le_lossy_constant_ones() {
+        let bits = [Integer::<Circuit, u8>::constant(1).to_bits_le(), vec![Boolean::constant(true)]].concat();
+        assert_eq!(1, Integer::<Circuit, u8>::from_bits_le_lossy(&bits).eject_value());
+    }
+
     // Tests for u8.
 
     #[test]
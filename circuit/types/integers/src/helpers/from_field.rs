@@ -34,6 +34,21 @@ impl<E: Environment, I: IntegerType> FromField for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Casts an integer from a base field, reducing the field element modulo `2^I::BITS`.
+    /// Unlike `from_field`, this method does **not** enforce that the upper bits are zero.
+    pub fn from_field_lossy(field: &Field<E>) -> Self {
+        // Note: We are reconstituting the integer from the lower bits of the base field.
+        debug_assert!(I::BITS < E::BaseField::size_in_bits() as u64);
+
+        // Extract the integer bits from the field element, discarding the remaining upper bits.
+        let bits_le = field.to_bits_le().into_iter().take(I::BITS as usize).collect();
+
+        // Return the integer.
+        Integer { bits_le, phantom: Default::default() }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,6 +73,76 @@ mod tests {
         }
     }
 
+    fn check_from_field_lossy<I: IntegerType>(mode: Mode) {
+        // Compute `2^I::BITS` as a base field element.
+        let two_to_bits = <Circuit as Environment>::BaseField::from(2u64).pow(&[I::BITS]);
+
+        for i in 0..ITERATIONS {
+            // Sample a random integer.
+            let expected: I = UniformRand::rand(&mut test_rng());
+            let field = Integer::<Circuit, I>::new(mode, expected).to_field().eject_value();
+
+            // Offset the field element by `2^I::BITS`, so that it exceeds the range of the integer.
+            let candidate = Field::<Circuit>::new(mode, field + two_to_bits);
+
+            Circuit::scope(format!("{mode} {expected} {i}"), || {
+                // Perform the operation.
+                let candidate = Integer::<Circuit, I>::from_field_lossy(&candidate);
+                assert_eq!(expected, candidate.eject_value());
+                match mode {
+                    Mode::Constant => assert_scope!(253, 0, 0, 0),
+                    _ => assert_scope!(0, 0, 253, 254),
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_from_field_out_of_range<I: IntegerType>(mode: Mode) {
+        // Compute `2^I::BITS` as a base field element.
+        let two_to_bits = <Circuit as Environment>::BaseField::from(2u64).pow(&[I::BITS]);
+
+        // Ensure `2^I::BITS - 1` is the largest field element that is in range.
+        let candidate = Field::<Circuit>::new(mode, two_to_bits - <Circuit as Environment>::BaseField::one());
+        let candidate = Integer::<Circuit, I>::from_field(candidate);
+        assert_eq!(I::zero().wrapping_sub(&I::one()), candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure `2^I::BITS` is out of range for the checked cast.
+        let candidate = Field::<Circuit>::new(mode, two_to_bits);
+        let _candidate = Integer::<Circuit, I>::from_field(candidate);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure `2^I::BITS` wraps around to zero for the lossy cast.
+        let candidate = Field::<Circuit>::new(mode, two_to_bits);
+        let candidate = Integer::<Circuit, I>::from_field_lossy(&candidate);
+        assert_eq!(I::zero(), candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_field_lossy() {
+        check_from_field_lossy::<u8>(Mode::Constant);
+        check_from_field_lossy::<u8>(Mode::Public);
+        check_from_field_lossy::<i16>(Mode::Private);
+        check_from_field_lossy::<u32>(Mode::Private);
+        check_from_field_lossy::<i64>(Mode::Public);
+        check_from_field_lossy::<u128>(Mode::Private);
+        check_from_field_lossy::<i128>(Mode::Constant);
+    }
+
+    #[test]
+    fn test_from_field_out_of_range() {
+        check_from_field_out_of_range::<u8>(Mode::Public);
+        check_from_field_out_of_range::<i8>(Mode::Private);
+        check_from_field_out_of_range::<u64>(Mode::Private);
+        check_from_field_out_of_range::<i128>(Mode::Public);
+        check_from_field_out_of_range::<u128>(Mode::Private);
+    }
+
     #[test]
     fn test_u8_from_field() {
         type I = u8;
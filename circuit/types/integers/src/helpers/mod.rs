@@ -19,6 +19,7 @@ use super::*;
 pub mod from_bits;
 pub mod from_field;
 pub mod msb;
+pub mod normalize_shift_amount;
 pub mod one;
 pub mod to_bits;
 pub mod to_field;
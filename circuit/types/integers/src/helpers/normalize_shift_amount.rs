@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the shift amount `rhs mod I::BITS`, as a zero-extended `U8`.
+    ///
+    /// As `I::BITS` is a power of two, this masks all but the lower `log2(I::BITS)` bits of `rhs`,
+    /// which ensures that `2^{rhs} < I::MAX` in the shift operations. This costs no constraints.
+    pub fn normalize_shift_amount<M: Magnitude>(rhs: &Integer<E, M>) -> U8<E> {
+        // Index of the first upper bit of rhs that we mask.
+        let first_upper_bit_index = I::BITS.trailing_zeros() as usize;
+
+        // Zero-extend the lower bits of `rhs` to `8` bits.
+        let mut bits_le = Vec::with_capacity(8);
+        bits_le.extend_from_slice(&rhs.bits_le[..first_upper_bit_index]);
+        bits_le.resize(8, Boolean::constant(false));
+
+        U8 { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_normalize_shift_amount<I: IntegerType, M: Magnitude>(mode: Mode, from_u32: fn(u32) -> Option<M>) {
+        let bits = I::BITS as u32;
+        for _ in 0..ITERATIONS {
+            let shift: M = UniformRand::rand(&mut test_rng());
+            let shift = shift.to_u32().unwrap();
+            let candidate = Integer::<Circuit, M>::new(mode, from_u32(shift).unwrap());
+
+            Circuit::scope("NormalizeShiftAmount", || {
+                let normalized = Integer::<Circuit, I>::normalize_shift_amount(&candidate);
+                assert_eq!(shift % bits, normalized.eject_value() as u32);
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+
+        // Check that shifting by `BITS + k` normalizes identically to shifting by `k`.
+        for k in 0..bits {
+            if let (Some(wrapped), Some(plain)) = (from_u32(bits + k), from_u32(k)) {
+                let wrapped = Integer::<Circuit, M>::new(mode, wrapped);
+                let plain = Integer::<Circuit, M>::new(mode, plain);
+
+                let expected = Integer::<Circuit, I>::normalize_shift_amount(&plain);
+                let candidate = Integer::<Circuit, I>::normalize_shift_amount(&wrapped);
+                assert_eq!(expected.eject_value(), candidate.eject_value());
+                assert_eq!(k, candidate.eject_value() as u32);
+            }
+        }
+
+        // Check that shifting by `BITS` and `BITS + k` behaves identically to shifting by `0` and `k`.
+        let first: I = UniformRand::rand(&mut test_rng());
+        let a = Integer::<Circuit, I>::new(mode, first);
+        for k in 0..bits {
+            if let (Some(wrapped), Some(plain)) = (from_u32(bits + k), from_u32(k)) {
+                let wrapped = Integer::<Circuit, M>::new(mode, wrapped);
+                let plain = Integer::<Circuit, M>::new(mode, plain);
+
+                assert_eq!(a.shl_wrapped(&plain).eject_value(), a.shl_wrapped(&wrapped).eject_value());
+                assert_eq!(a.shr_wrapped(&plain).eject_value(), a.shr_wrapped(&wrapped).eject_value());
+                assert_eq!(first.wrapping_shl(k), a.shl_wrapped(&wrapped).eject_value());
+                assert_eq!(first.wrapping_shr(k), a.shr_wrapped(&wrapped).eject_value());
+            }
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_normalize_shift_amount::<I, u8>(mode, |value| u8::try_from(value).ok());
+            check_normalize_shift_amount::<I, u16>(mode, |value| u16::try_from(value).ok());
+            check_normalize_shift_amount::<I, u32>(mode, Some);
+        }
+    }
+
+    #[test]
+    fn test_u8_normalize_shift_amount() {
+        run_test::<u8>();
+    }
+
+    #[test]
+    fn test_i8_normalize_shift_amount() {
+        run_test::<i8>();
+    }
+
+    #[test]
+    fn test_u16_normalize_shift_amount() {
+        run_test::<u16>();
+    }
+
+    #[test]
+    fn test_i16_normalize_shift_amount() {
+        run_test::<i16>();
+    }
+
+    #[test]
+    fn test_u32_normalize_shift_amount() {
+        run_test::<u32>();
+    }
+
+    #[test]
+    fn test_i32_normalize_shift_amount() {
+        run_test::<i32>();
+    }
+
+    #[test]
+    fn test_u64_normalize_shift_amount() {
+        run_test::<u64>();
+    }
+
+    #[test]
+    fn test_i64_normalize_shift_amount() {
+        run_test::<i64>();
+    }
+
+    #[test]
+    fn test_u128_normalize_shift_amount() {
+        run_test::<u128>();
+    }
+
+    #[test]
+    fn test_i128_normalize_shift_amount() {
+        run_test::<i128>();
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Select<U8<E>> for Field<E> {
+    type Output = Self;
+
+    /// Returns the option at position `index`, enforcing that `index` is less than the number of options.
+    ///
+    /// The option is selected by a binary tree of ternaries over the bits of `index`,
+    /// which has a depth of `ceil(log2(options.len()))`.
+    fn select(index: &U8<E>, options: &[Self]) -> Self::Output {
+        // Ensure there is at least one option, and at most one option per index.
+        match options.len() {
+            0 => E::halt("Select requires at least one option"),
+            num_options if num_options > 256 => E::halt("Select supports at most 256 options"),
+            _ => (),
+        }
+
+        // Enforce that the index is within bounds.
+        // If the number of options is a power of two, it suffices to check the upper bits of the index are zero.
+        match options.len().is_power_of_two() {
+            true => {
+                let depth = options.len().trailing_zeros() as usize;
+                index.bits_le.iter().skip(depth).for_each(|bit| E::assert(!bit));
+            }
+            false => E::assert(index.is_less_than(&U8::constant(options.len() as u8))),
+        }
+
+        // Halve the options at each level, using the corresponding bit of the index as the condition.
+        // An unpaired last option is carried up unchanged, as the bounds check ensures it is selected
+        // only when the index bit is `false`.
+        let mut options = options.to_vec();
+        for bit in index.bits_le.iter() {
+            if options.len() == 1 {
+                break;
+            }
+            options = options
+                .chunks(2)
+                .map(|pair| match pair {
+                    [even, odd] => Field::ternary(bit, odd, even),
+                    [last] => last.clone(),
+                    _ => E::halt("Malformed chunk detected during select"),
+                })
+                .collect();
+        }
+
+        match options.pop() {
+            Some(selected) => selected,
+            None => E::halt("Malformed expression detected during select"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    fn check_select(
+        mode_index: Mode,
+        mode_options: Mode,
+        num_options: usize,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let values = (0..num_options).map(|_| UniformRand::rand(&mut test_rng())).collect::<Vec<_>>();
+        let options = values.iter().map(|value| Field::<Circuit>::new(mode_options, *value)).collect::<Vec<_>>();
+
+        for (i, expected) in values.iter().enumerate() {
+            let index = U8::<Circuit>::new(mode_index, i as u8);
+
+            Circuit::scope(format!("Select {i} of {num_options} ({mode_index}, {mode_options})"), || {
+                let candidate = Field::select(&index, &options);
+                assert_eq!(*expected, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_select_out_of_bounds(num_options: usize) {
+        let options = (0..num_options)
+            .map(|_| Field::<Circuit>::new(Mode::Private, UniformRand::rand(&mut test_rng())))
+            .collect::<Vec<_>>();
+
+        for i in [num_options, num_options + 1, 255] {
+            let index = U8::<Circuit>::new(Mode::Private, i as u8);
+
+            Circuit::scope(format!("Select {i} of {num_options}"), || {
+                let _candidate = Field::select(&index, &options);
+                assert!(!Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_select_4() {
+        // The bounds check costs 6 constraints, and the tree costs 3 ternaries.
+        check_select(Mode::Constant, Mode::Private, 4, 0, 0, 0, 0);
+        check_select(Mode::Public, Mode::Private, 4, 0, 0, 3, 9);
+        check_select(Mode::Private, Mode::Private, 4, 0, 0, 3, 9);
+    }
+
+    #[test]
+    fn test_select_8() {
+        // The bounds check costs 5 constraints, and the tree costs 7 ternaries.
+        check_select(Mode::Constant, Mode::Private, 8, 0, 0, 0, 0);
+        check_select(Mode::Public, Mode::Private, 8, 0, 0, 7, 12);
+        check_select(Mode::Private, Mode::Private, 8, 0, 0, 7, 12);
+    }
+
+    #[test]
+    fn test_select_uneven() {
+        check_select(Mode::Private, Mode::Private, 1, 0, 0, 0, 8);
+        // The bounds check requires a comparison, as the number of options is not a power of two.
+        check_select(Mode::Private, Mode::Private, 5, 16, 0, 13, 15);
+    }
+
+    #[test]
+    fn test_select_out_of_bounds() {
+        check_select_out_of_bounds(1);
+        check_select_out_of_bounds(4);
+        check_select_out_of_bounds(5);
+        check_select_out_of_bounds(8);
+    }
+}
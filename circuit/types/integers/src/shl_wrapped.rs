@@ -33,12 +33,8 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShlWrapped<Integer<E, M>> for
             // By masking the upper bits, we have that rhs < I::BITS.
             // Therefore, 2^{rhs} < I::MAX.
 
-            // Zero-extend `rhs` by `8`.
-            let mut bits_le = rhs.bits_le[..first_upper_bit_index].to_vec();
-            bits_le.extend(core::iter::repeat(Boolean::constant(false)).take(8));
-
             // Use U8 for the exponent as it costs fewer constraints.
-            let rhs_as_u8 = U8 { bits_le, phantom: Default::default() };
+            let rhs_as_u8 = Self::normalize_shift_amount(rhs);
 
             if rhs_as_u8.is_constant() {
                 // If the shift amount is a constant, then we can manually shift in bits and truncate the result.
@@ -54,7 +50,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShlWrapped<Integer<E, M>> for
                 // Since 2^{rhs} < I::MAX, we know that the operation will not overflow I::MAX or the field modulus.
                 let two = Field::one() + Field::one();
                 let mut shift_in_field = Field::one();
-                for bit in rhs.bits_le[..first_upper_bit_index].iter().rev() {
+                for bit in rhs_as_u8.bits_le[..first_upper_bit_index].iter().rev() {
                     shift_in_field = shift_in_field.square();
                     shift_in_field = Field::ternary(bit, &(&shift_in_field * &two), &shift_in_field);
                 }
@@ -32,12 +32,8 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShrWrapped<Integer<E, M>> for
             // Perform the right shift operation by exponentiation and multiplication.
             // By masking the upper bits, we have that rhs < I::BITS.
             // Therefore, 2^{rhs} < I::MAX.
-            let mut lower_rhs_bits = Vec::with_capacity(8);
-            lower_rhs_bits.extend_from_slice(&rhs.bits_le[..first_upper_bit_index]);
-            lower_rhs_bits.resize(8, Boolean::constant(false));
-
             // Use U8 for the exponent as it costs fewer constraints.
-            let rhs_as_u8 = U8 { bits_le: lower_rhs_bits, phantom: Default::default() };
+            let rhs_as_u8 = Self::normalize_shift_amount(rhs);
 
             if rhs_as_u8.is_constant() {
                 // If the shift amount is a constant, then we can manually shift in bits and truncate the result.
@@ -63,7 +59,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShrWrapped<Integer<E, M>> for
                 // Since 2^{rhs} < I::MAX, we know that the operation will not overflow I::MAX or the field modulus.
                 let two = Field::one() + Field::one();
                 let mut shift_in_field = Field::one();
-                for bit in rhs.bits_le[..first_upper_bit_index].iter().rev() {
+                for bit in rhs_as_u8.bits_le[..first_upper_bit_index].iter().rev() {
                     shift_in_field = shift_in_field.square();
                     shift_in_field = Field::ternary(bit, &(&shift_in_field * &two), &shift_in_field);
                 }
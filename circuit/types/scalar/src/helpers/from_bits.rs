@@ -76,7 +76,7 @@ impl<E: Environment> FromBits for Scalar<E> {
 mod tests {
     use super::*;
     use snarkvm_circuit_environment::Circuit;
-    use snarkvm_utilities::{test_rng, UniformRand};
+    use snarkvm_utilities::{test_rng, BigInteger, UniformRand};
 
     const ITERATIONS: u64 = 100;
 
@@ -146,6 +146,64 @@ mod tests {
         }
     }
 
+    /// Returns the little-endian bits of the given big integer, truncated to the scalar field size.
+    fn scalar_bits_le(
+        mode: Mode,
+        value: <<Circuit as Environment>::ScalarField as PrimeField>::BigInteger,
+    ) -> Vec<Boolean<Circuit>> {
+        (0..<Circuit as Environment>::ScalarField::size_in_bits())
+            .map(|i| Boolean::new(mode, value.get_bit(i)))
+            .collect()
+    }
+
+    fn check_from_bits_le_modulus_bound(mode: Mode) {
+        let modulus = <Circuit as Environment>::ScalarField::modulus();
+
+        // Ensure `MODULUS - 1` is accepted.
+        let mut modulus_minus_one = modulus;
+        modulus_minus_one.sub_noborrow(&1u64.into());
+        Circuit::scope(&format!("{} MODULUS - 1", mode), || {
+            let candidate = Scalar::<Circuit>::from_bits_le(&scalar_bits_le(mode, modulus_minus_one));
+            assert_eq!(-<Circuit as Environment>::ScalarField::one(), candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+
+        // Ensure `MODULUS` is rejected.
+        Circuit::scope(&format!("{} MODULUS", mode), || {
+            let _candidate = Scalar::<Circuit>::from_bits_le(&scalar_bits_le(mode, modulus));
+            assert!(!Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+
+        // Ensure `MODULUS + 1` is rejected.
+        let mut modulus_plus_one = modulus;
+        modulus_plus_one.add_nocarry(&1u64.into());
+        Circuit::scope(&format!("{} MODULUS + 1", mode), || {
+            let _candidate = Scalar::<Circuit>::from_bits_le(&scalar_bits_le(mode, modulus_plus_one));
+            assert!(!Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_bits_le_modulus_bound_public() {
+        check_from_bits_le_modulus_bound(Mode::Public);
+    }
+
+    #[test]
+    fn test_from_bits_le_modulus_bound_private() {
+        check_from_bits_le_modulus_bound(Mode::Private);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to eject scalar field value")]
+    fn test_from_bits_le_modulus_bound_constant() {
+        // A constant `MODULUS` is rejected while evaluating the comparison.
+        let modulus = <Circuit as Environment>::ScalarField::modulus();
+        let _candidate = Scalar::<Circuit>::from_bits_le(&scalar_bits_le(Mode::Constant, modulus));
+    }
+
     #[test]
     fn test_from_bits_le_constant() {
         check_from_bits_le(Mode::Constant, 252, 0, 0, 0);
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> FromField for Scalar<E> {
+    type Field = Field<E>;
+
+    /// Casts a scalar from a base field element.
+    /// This method enforces that the base field element is less than the scalar field modulus.
+    fn from_field(field: Self::Field) -> Self {
+        // Note: `Scalar::from_bits_le` enforces that the excess bits are zero,
+        // and that the remaining bits are less than the scalar field modulus.
+        Self::from_bits_le(&field.to_bits_le())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_field(mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        for i in 0..ITERATIONS {
+            // Sample a random scalar.
+            let expected: <Circuit as Environment>::ScalarField = UniformRand::rand(&mut test_rng());
+            let candidate =
+                Field::<Circuit>::new(mode, Scalar::<Circuit>::new(mode, expected).to_field().eject_value());
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                // Perform the operation.
+                let candidate = Scalar::from_field(candidate);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_from_field_out_of_range(mode: Mode) {
+        // Compute `ScalarField::MODULUS - 1` as a base field element.
+        let modulus_minus_one = Scalar::<Circuit>::constant(-<Circuit as Environment>::ScalarField::one());
+        let modulus_minus_one = modulus_minus_one.to_field().eject_value();
+
+        // Ensure `ScalarField::MODULUS - 1` is the largest field element that is in range.
+        let candidate = Scalar::<Circuit>::from_field(Field::new(mode, modulus_minus_one));
+        assert_eq!(-<Circuit as Environment>::ScalarField::one(), candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure `ScalarField::MODULUS` is out of range.
+        let modulus = modulus_minus_one + <Circuit as Environment>::BaseField::one();
+        let _candidate = Scalar::<Circuit>::from_field(Field::new(mode, modulus));
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure the largest base field element is out of range.
+        let _candidate = Scalar::<Circuit>::from_field(Field::new(mode, -<Circuit as Environment>::BaseField::one()));
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_field_constant() {
+        check_from_field(Mode::Constant, 505, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_from_field_public() {
+        check_from_field(Mode::Public, 251, 0, 504, 507);
+    }
+
+    #[test]
+    fn test_from_field_private() {
+        check_from_field(Mode::Private, 251, 0, 504, 507);
+    }
+
+    #[test]
+    fn test_from_field_out_of_range() {
+        check_from_field_out_of_range(Mode::Public);
+        check_from_field_out_of_range(Mode::Private);
+    }
+}
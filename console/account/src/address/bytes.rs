@@ -21,7 +21,8 @@ impl<N: Network> FromBytes for Address<N> {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let x_coordinate = N::Field::read_le(&mut reader)?;
-        Ok(Self(N::affine_from_x_coordinate(x_coordinate).map_err(|e| error(format!("{e}")))?))
+        // Wrap the recovery error, so callers can distinguish it from a read failure.
+        Self::from_x_coordinate(x_coordinate).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
     }
 }
 
@@ -36,7 +37,7 @@ impl<N: Network> ToBytes for Address<N> {
 mod tests {
     use super::*;
     use snarkvm_console_network::Testnet3;
-    use snarkvm_utilities::test_crypto_rng;
+    use snarkvm_utilities::{test_crypto_rng, UniformRand};
 
     use anyhow::Result;
 
@@ -58,4 +59,46 @@ mod tests {
         }
         Ok(())
     }
+
+    /// Returns the account error wrapped in the given I/O error, if one exists.
+    fn account_error(error: &io::Error) -> Option<&AccountError> {
+        error.get_ref().and_then(|error| error.downcast_ref::<AccountError>())
+    }
+
+    #[test]
+    fn test_read_le_errors() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Ensure a truncated buffer fails to read, without an account error.
+        let error = Address::<CurrentNetwork>::read_le(&[0u8; 8][..]).unwrap_err();
+        assert_eq!(io::ErrorKind::UnexpectedEof, error.kind());
+        assert!(account_error(&error).is_none());
+
+        // Sample x-coordinates that are off the curve, and on the curve but outside of the prime-order subgroup.
+        let mut not_on_curve = None;
+        let mut not_in_subgroup = None;
+        while not_on_curve.is_none() || not_in_subgroup.is_none() {
+            let x_coordinate = <CurrentNetwork as Network>::Field::rand(rng);
+            let candidates = [true, false]
+                .into_iter()
+                .filter_map(|greatest| <CurrentNetwork as Network>::Affine::from_x_coordinate(x_coordinate, greatest))
+                .collect::<Vec<_>>();
+            if candidates.is_empty() {
+                not_on_curve = Some(x_coordinate);
+            } else if candidates.iter().all(|candidate| !candidate.is_in_correct_subgroup_assuming_on_curve()) {
+                not_in_subgroup = Some(x_coordinate);
+            }
+        }
+
+        // Ensure an x-coordinate off the curve fails with the corresponding error.
+        let error = Address::<CurrentNetwork>::read_le(&not_on_curve.unwrap().to_bytes_le()?[..]).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, error.kind());
+        assert!(matches!(account_error(&error), Some(AccountError::AddressNotOnCurve)));
+
+        // Ensure an x-coordinate outside of the prime-order subgroup fails with the corresponding error.
+        let error = Address::<CurrentNetwork>::read_le(&not_in_subgroup.unwrap().to_bytes_le()?[..]).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, error.kind());
+        assert!(matches!(account_error(&error), Some(AccountError::AddressNotInSubgroup)));
+        Ok(())
+    }
 }
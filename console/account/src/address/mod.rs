@@ -18,21 +18,22 @@ mod bytes;
 mod serialize;
 mod string;
 mod try_from;
+mod verify;
 
-use crate::{ComputeKey, PrivateKey, ViewKey};
+use crate::{AccountError, ComputeKey, PrivateKey, Signature, ViewKey};
 use snarkvm_console_network::Network;
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{
-    error,
-    io::{Read, Result as IoResult, Write},
+    io::{self, Read, Result as IoResult, Write},
     FromBytes,
     FromBytesDeserializer,
+    ToBits,
     ToBytes,
     ToBytesSerializer,
 };
 
-use anyhow::{bail, Error};
+use anyhow::{anyhow, bail, Error, Result};
 use bech32::{self, FromBase32, ToBase32};
 use core::{fmt, ops::Deref, str::FromStr};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
@@ -45,6 +46,62 @@ impl<N: Network> Address<N> {
     pub fn from_group(group: N::Affine) -> Self {
         Self(group)
     }
+
+    /// Returns a new address from an x-coordinate, recovering the affine group element.
+    /// This method checks that the recovered group element is in the prime-order subgroup.
+    ///
+    /// # Errors
+    /// Returns `AccountError::AddressNotOnCurve` if no point on the curve has the given x-coordinate.
+    /// Returns `AccountError::AddressNotInSubgroup` if no such point is in the prime-order subgroup.
+    pub fn from_x_coordinate(x_coordinate: N::Field) -> Result<Self, AccountError> {
+        // Recover the candidate point with the largest y-coordinate.
+        Self::from_greatest_candidate(N::Affine::from_x_coordinate(x_coordinate, true))
+    }
+
+    /// Returns a list of addresses from a list of x-coordinates, recovering the affine group elements.
+    /// This method batches the inversions across the recoveries, and otherwise matches the output
+    /// of `Address::from_x_coordinate` on each x-coordinate.
+    ///
+    /// # Errors
+    /// Returns an error with the index of the first x-coordinate that does not correspond to an address.
+    pub fn try_from_x_coordinates(x_coordinates: &[N::Field]) -> Result<Vec<Self>> {
+        // Recover the candidate points with the largest y-coordinates.
+        N::Affine::batch_from_x_coordinates(x_coordinates, true)
+            .into_iter()
+            .enumerate()
+            .map(|(index, candidate)| {
+                Self::from_greatest_candidate(candidate)
+                    .map_err(|error| anyhow!("Invalid address x-coordinate at index {index}: {error}"))
+            })
+            .collect()
+    }
+
+    /// Returns the address in the prime-order subgroup, given the recovered point with the largest y-coordinate.
+    fn from_greatest_candidate(greatest: Option<N::Affine>) -> Result<Self, AccountError> {
+        // Ensure a point on the curve was recovered.
+        let greatest = greatest.ok_or(AccountError::AddressNotOnCurve)?;
+        // Derive the candidate point with the smallest y-coordinate, i.e. `(x, -y)`.
+        let smallest = N::Affine::from_coordinates((greatest.to_x_coordinate(), -greatest.to_y_coordinate()));
+        // Return the candidate in the prime-order subgroup.
+        match [greatest, smallest].into_iter().find(|candidate| candidate.is_in_correct_subgroup_assuming_on_curve()) {
+            Some(group) => Ok(Self(group)),
+            None => Err(AccountError::AddressNotInSubgroup),
+        }
+    }
+
+    /// Returns a new address from the `(x, y)` coordinates of an affine group element, **without**
+    /// recovering or checking the group element.
+    ///
+    /// This skips the square root and subgroup check performed by `Address::from_x_coordinate`,
+    /// and is intended for trusted inputs, such as the coordinates of a previously-validated address.
+    ///
+    /// # Note
+    /// The caller must ensure `(x, y)` is a point on the curve in the prime-order subgroup.
+    /// Violating this contract is memory-safe, however the resulting address will not correspond
+    /// to any account, and any proof or signature involving it is undefined.
+    pub fn from_coordinates_unchecked(x_coordinate: N::Field, y_coordinate: N::Field) -> Self {
+        Self(N::Affine::from_coordinates((x_coordinate, y_coordinate)))
+    }
 }
 
 impl<N: Network> Deref for Address<N> {
@@ -60,6 +117,7 @@ impl<N: Network> Deref for Address<N> {
 mod tests {
     use super::*;
     use snarkvm_console_network::Testnet3;
+    use snarkvm_fields::One;
     use snarkvm_utilities::test_crypto_rng;
 
     use anyhow::Result;
@@ -81,4 +139,57 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_from_coordinates_unchecked() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new address.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+            let expected = Address::try_from(private_key)?;
+
+            // Recover the address from its x-coordinate, using the checked path.
+            let candidate = Address::<CurrentNetwork>::from_x_coordinate(expected.to_x_coordinate())?;
+            assert_eq!(expected, candidate);
+
+            // Recover the address from its coordinates, using the unchecked path.
+            let candidate = Address::<CurrentNetwork>::from_coordinates_unchecked(
+                expected.to_x_coordinate(),
+                expected.to_y_coordinate(),
+            );
+            assert_eq!(expected, candidate);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_x_coordinates() -> Result<()> {
+        // Sample a list of addresses.
+        let expected = (0..ITERATIONS)
+            .map(|_| Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?))
+            .collect::<Result<Vec<_>>>()?;
+        let x_coordinates = expected.iter().map(|address| address.to_x_coordinate()).collect::<Vec<_>>();
+
+        // Ensure the batch recovery matches the per-element recovery.
+        let candidates = Address::<CurrentNetwork>::try_from_x_coordinates(&x_coordinates)?;
+        assert_eq!(expected, candidates);
+        for (x_coordinate, candidate) in x_coordinates.iter().zip(&candidates) {
+            assert_eq!(Address::from_x_coordinate(*x_coordinate)?, *candidate);
+        }
+
+        // Ensure an empty list is supported.
+        assert!(Address::<CurrentNetwork>::try_from_x_coordinates(&[])?.is_empty());
+
+        // Find an x-coordinate that does not correspond to an address.
+        let mut invalid = <CurrentNetwork as Network>::Field::one();
+        while Address::<CurrentNetwork>::from_x_coordinate(invalid).is_ok() {
+            invalid += <CurrentNetwork as Network>::Field::one();
+        }
+
+        // Ensure an invalid x-coordinate errors with its index.
+        let mut x_coordinates = x_coordinates[..10].to_vec();
+        x_coordinates.insert(7, invalid);
+        let error = Address::<CurrentNetwork>::try_from_x_coordinates(&x_coordinates).unwrap_err();
+        assert!(error.to_string().contains("at index 7"), "{error}");
+        Ok(())
+    }
 }
@@ -74,6 +74,44 @@ impl<N: Network> TryFrom<&ViewKey<N>> for Address<N> {
     }
 }
 
+impl<N: Network> Address<N> {
+    /// Derives the account addresses for the given account private keys.
+    ///
+    /// This method batches the conversions into affine coordinates across all private keys,
+    /// which amortizes the field inversions, and is intended for deriving many accounts at once.
+    pub fn try_from_many(private_keys: &[PrivateKey<N>]) -> Result<Vec<Self>> {
+        // Compute (pk_sig, pr_sig, pk_vrf) := (G^sk_sig, G^r_sig, G^sk_vrf) for each private key.
+        let mut compute_key_points = private_keys
+            .iter()
+            .flat_map(|private_key| {
+                [private_key.sk_sig(), private_key.r_sig(), private_key.sk_vrf()].map(|s| N::g_scalar_multiply(&s))
+            })
+            .collect::<Vec<_>>();
+        // Convert (pk_sig, pr_sig, pk_vrf) into affine coordinates.
+        <N::Affine as AffineCurve>::Projective::batch_normalization(&mut compute_key_points);
+
+        // Compute view_key := sk_sig + r_sig + sk_prf for each private key.
+        let view_keys = private_keys
+            .iter()
+            .zip(compute_key_points.chunks(3))
+            .enumerate()
+            .map(|(index, (private_key, points))| {
+                // Derive the compute key.
+                let (pk_sig, pr_sig, pk_vrf) = (points[0].to_affine(), points[1].to_affine(), points[2].to_affine());
+                let compute_key = ComputeKey::<N>::try_from((pk_sig, pr_sig, pk_vrf))
+                    .map_err(|error| anyhow!("Failed to derive the address at index {index}: {error}"))?;
+                Ok(private_key.sk_sig() + private_key.r_sig() + compute_key.sk_prf())
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute G^view_key for each view key.
+        let mut addresses = view_keys.iter().map(N::g_scalar_multiply).collect::<Vec<_>>();
+        // Convert the addresses into affine coordinates.
+        <N::Affine as AffineCurve>::Projective::batch_normalization(&mut addresses);
+        Ok(addresses.into_iter().map(|address| Self(address.to_affine())).collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,4 +141,21 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_try_from_many() -> Result<()> {
+        for i in 0..(ITERATIONS / 100) {
+            // Sample new private keys.
+            let private_keys = (0..i)
+                .map(|_| PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng()))
+                .collect::<Result<Vec<_>>>()?;
+
+            // Derive the addresses individually.
+            let expected = private_keys.iter().map(Address::try_from).collect::<Result<Vec<_>>>()?;
+
+            // Check the addresses derived in a batch.
+            assert_eq!(expected, Address::try_from_many(&private_keys)?);
+        }
+        Ok(())
+    }
 }
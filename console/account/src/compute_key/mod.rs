@@ -16,9 +16,10 @@
 
 mod bytes;
 mod serialize;
+mod to_address;
 mod try_from;
 
-use crate::PrivateKey;
+use crate::{Address, PrivateKey};
 use snarkvm_console_network::Network;
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::PrimeField;
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::FromBytes;
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AccountError {
+    #[error("Failed to decode base58: {}", _0)]
+    Base58Decode(String),
+
+    #[error("Invalid encoding length: {}", _0)]
+    InvalidLength(String),
+
+    #[error("Invalid encoding prefix: {}", _0)]
+    InvalidPrefix(String),
+
+    #[error("Invalid account private key: {}", _0)]
+    InvalidPrivateKey(String),
+
+    #[error("Invalid account view key: {}", _0)]
+    InvalidViewKey(String),
+
+    #[error("Invalid account mnemonic: {}", _0)]
+    InvalidMnemonic(String),
+
+    #[error("The scalar is not in range of the scalar field")]
+    OutOfRangeScalar,
+
+    #[error("The address x-coordinate does not correspond to a point on the curve")]
+    AddressNotOnCurve,
+
+    #[error("The address x-coordinate corresponds to a point outside of the prime-order subgroup")]
+    AddressNotInSubgroup,
+}
+
+impl From<base58::FromBase58Error> for AccountError {
+    fn from(error: base58::FromBase58Error) -> Self {
+        AccountError::Base58Decode(format!("{:?}", error))
+    }
+}
+
+/// Reads a scalar from the given little-endian bytes.
+/// Returns `AccountError::OutOfRangeScalar` only if the bytes encode an integer that is not less than the modulus.
+pub(crate) fn scalar_from_bytes_le<F: PrimeField>(bytes: &[u8]) -> Result<F, AccountError> {
+    let repr = F::BigInteger::read_le(bytes).map_err(|error| AccountError::InvalidLength(format!("{error}")))?;
+    F::from_repr(repr).ok_or(AccountError::OutOfRangeScalar)
+}
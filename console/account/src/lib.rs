@@ -23,6 +23,9 @@ pub use address::*;
 pub mod compute_key;
 pub use compute_key::*;
 
+pub mod error;
+pub use error::*;
+
 pub mod private_key;
 pub use private_key::*;
 
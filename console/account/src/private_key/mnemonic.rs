@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use sha2::{Digest, Sha256};
+
+/// The BIP39 English word list.
+static MNEMONIC_WORDS: &str = include_str!("resources/english.txt");
+
+/// The number of bytes of entropy in a mnemonic, which is the size of an account seed.
+const MNEMONIC_ENTROPY_SIZE_IN_BYTES: usize = 32;
+/// The number of bits encoded by each word in a mnemonic.
+const MNEMONIC_WORD_SIZE_IN_BITS: usize = 11;
+/// The number of words in a mnemonic, encoding the entropy and an 8-bit checksum.
+const MNEMONIC_NUM_WORDS: usize = (MNEMONIC_ENTROPY_SIZE_IN_BYTES * 8 + 8) / MNEMONIC_WORD_SIZE_IN_BITS;
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the account private key as a BIP39 mnemonic, encoding the account seed as 24 words.
+    pub fn to_mnemonic(&self) -> String {
+        // Encode the account seed as little-endian bytes.
+        let mut entropy = bytes_from_bits_le(&self.seed.to_bits_le());
+        entropy.resize(MNEMONIC_ENTROPY_SIZE_IN_BYTES, 0u8);
+
+        // Append the checksum, which is the first byte of the SHA-256 hash of the entropy.
+        let checksum = Sha256::digest(&entropy)[0];
+        entropy.push(checksum);
+
+        // Encode each 11-bit chunk (in big-endian order) as a word.
+        let words = MNEMONIC_WORDS.lines().collect::<Vec<_>>();
+        let bits = entropy.iter().flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1)).collect::<Vec<_>>();
+        bits.chunks(MNEMONIC_WORD_SIZE_IN_BITS)
+            .map(|chunk| words[chunk.iter().fold(0usize, |index, bit| (index << 1) | *bit as usize)])
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Returns the account private key from a BIP39 mnemonic, checking the word count and checksum.
+    pub fn from_mnemonic(phrase: &str) -> Result<Self, AccountError> {
+        // Ensure the mnemonic has the expected number of words.
+        let phrase = phrase.split_whitespace().collect::<Vec<_>>();
+        if phrase.len() != MNEMONIC_NUM_WORDS {
+            return Err(AccountError::InvalidMnemonic(format!(
+                "found {} words, expected {MNEMONIC_NUM_WORDS}",
+                phrase.len()
+            )));
+        }
+
+        // Decode each word into its 11-bit chunk (in big-endian order).
+        let words = MNEMONIC_WORDS.lines().collect::<Vec<_>>();
+        let mut bits = Vec::with_capacity(MNEMONIC_NUM_WORDS * MNEMONIC_WORD_SIZE_IN_BITS);
+        for word in phrase {
+            match words.binary_search(&word) {
+                Ok(index) => bits.extend((0..MNEMONIC_WORD_SIZE_IN_BITS).rev().map(|i| (index >> i) & 1 == 1)),
+                Err(_) => return Err(AccountError::InvalidMnemonic(format!("'{word}' is not in the word list"))),
+            }
+        }
+        let mut entropy = bits
+            .chunks(8)
+            .map(|chunk| chunk.iter().fold(0u8, |byte, bit| (byte << 1) | *bit as u8))
+            .collect::<Vec<_>>();
+
+        // Ensure the checksum is the first byte of the SHA-256 hash of the entropy.
+        let checksum = entropy.pop();
+        if checksum != Some(Sha256::digest(&entropy)[0]) {
+            return Err(AccountError::InvalidMnemonic("the checksum does not match".to_string()));
+        }
+
+        // Recover the account seed.
+        let seed = scalar_from_bytes_le(&entropy)?;
+        // Output the private key.
+        Self::try_from(seed).map_err(|error| AccountError::InvalidPrivateKey(format!("{error}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_fields::Zero;
+    use snarkvm_utilities::test_crypto_rng;
+
+    use anyhow::Result;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_mnemonic() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let expected = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+
+            // Check the mnemonic representation.
+            let candidate = expected.to_mnemonic();
+            assert_eq!(MNEMONIC_NUM_WORDS, candidate.split(' ').count());
+            assert_eq!(expected, PrivateKey::from_mnemonic(&candidate)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_mnemonic_word_list() {
+        let words = MNEMONIC_WORDS.lines().collect::<Vec<_>>();
+        assert_eq!(1 << MNEMONIC_WORD_SIZE_IN_BITS, words.len());
+        // Ensure the word list is sorted, as decoding relies on a binary search.
+        assert!(words.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_mnemonic_test_vectors() -> Result<()> {
+        // Check the BIP39 test vector for an all-zero entropy.
+        let zero = PrivateKey::<CurrentNetwork>::try_from(<CurrentNetwork as Network>::Scalar::zero())?;
+        let expected = format!("{}art", "abandon ".repeat(23));
+        assert_eq!(expected, zero.to_mnemonic());
+        assert_eq!(zero, PrivateKey::from_mnemonic(&expected)?);
+
+        // Check the BIP39 test vector for an entropy of `0x7f` bytes, which is not a valid account seed.
+        let phrase = "legal winner thank year wave sausage worth useful legal winner thank year \
+                      wave sausage worth useful legal winner thank year wave sausage worth title";
+        assert!(matches!(PrivateKey::<CurrentNetwork>::from_mnemonic(phrase), Err(AccountError::OutOfRangeScalar)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_mnemonic_swapped_word_fails() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+            let mut phrase = private_key.to_mnemonic().split(' ').map(str::to_string).collect::<Vec<_>>();
+
+            // Swap the last word for its neighbor in the word list, which only changes the checksum bits.
+            let words = MNEMONIC_WORDS.lines().collect::<Vec<_>>();
+            let index = words.binary_search(&phrase[MNEMONIC_NUM_WORDS - 1].as_str()).unwrap();
+            phrase[MNEMONIC_NUM_WORDS - 1] = words[index ^ 1].to_string();
+
+            let candidate = PrivateKey::<CurrentNetwork>::from_mnemonic(&phrase.join(" "));
+            assert!(matches!(candidate, Err(AccountError::InvalidMnemonic(message)) if message.contains("checksum")));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_mnemonic_invalid_words_fail() -> Result<()> {
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+        let phrase = private_key.to_mnemonic();
+        let words = phrase.split(' ').collect::<Vec<_>>();
+
+        // Ensure a mnemonic with too few or too many words fails.
+        let too_few = words[..MNEMONIC_NUM_WORDS - 1].join(" ");
+        assert!(matches!(PrivateKey::<CurrentNetwork>::from_mnemonic(&too_few), Err(AccountError::InvalidMnemonic(_))));
+        let too_many = format!("{phrase} {}", words[0]);
+        assert!(matches!(
+            PrivateKey::<CurrentNetwork>::from_mnemonic(&too_many),
+            Err(AccountError::InvalidMnemonic(_))
+        ));
+        assert!(matches!(PrivateKey::<CurrentNetwork>::from_mnemonic(""), Err(AccountError::InvalidMnemonic(_))));
+
+        // Ensure a mnemonic with a word outside of the word list fails.
+        let unknown = format!("aleo {}", words[1..].join(" "));
+        assert!(matches!(PrivateKey::<CurrentNetwork>::from_mnemonic(&unknown), Err(AccountError::InvalidMnemonic(_))));
+        Ok(())
+    }
+}
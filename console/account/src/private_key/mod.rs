@@ -15,26 +15,32 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod mnemonic;
 mod serialize;
+mod sign;
 mod string;
+mod to_address;
 mod try_from;
 
+use crate::{scalar_from_bytes_le, AccountError, Address, Signature};
 use snarkvm_console_algorithms::{Poseidon2, PRF};
 use snarkvm_console_network::Network;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{
+    bytes_from_bits_le,
     error,
     io::{Read, Result as IoResult, Write},
     CryptoRng,
     FromBytes,
     FromBytesDeserializer,
     Rng,
+    ToBits,
     ToBytes,
     ToBytesSerializer,
     UniformRand,
 };
 
-use anyhow::{anyhow, bail, Error, Result};
+use anyhow::Result;
 use base58::{FromBase58, ToBase58};
 use core::{fmt, str::FromStr};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+static ACCOUNT_SIGNATURE_NONCE_DOMAIN: &str = "AleoAccountSignatureNonce0";
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns a signature for the given message (as field elements) using the private key.
+    ///
+    /// The signature nonce is derived as `HashToScalar(nonce)`, for a randomly-sampled `nonce`.
+    pub fn sign<R: Rng + CryptoRng>(&self, message: &[N::Field], rng: &mut R) -> Result<Signature<N>> {
+        // Sample a random nonce.
+        let nonce: N::Field = UniformRand::rand(rng);
+        // Compute the randomizer := HashToScalar(nonce).
+        let randomizer = N::hash_to_scalar_psd2(&[nonce])?;
+        // Sign the message, encoded as little-endian bits.
+        Signature::sign(self, &message.to_bits_le(), randomizer)
+    }
+
+    /// Returns a deterministic signature for the given message (as field elements) using the private key.
+    ///
+    /// The signature nonce is derived as `HashToScalar(DOMAIN || sk_sig || LENGTH(message) || message)`,
+    /// so the same private key and message always produce the same signature, independent of an RNG.
+    pub fn sign_deterministic(&self, message: &[N::Field]) -> Result<Signature<N>> {
+        // Construct the nonce domain separator.
+        let domain = N::Field::from_bytes_le_mod_order(ACCOUNT_SIGNATURE_NONCE_DOMAIN.as_bytes());
+        // Convert the signature secret key into a base field element.
+        // Note: This is injective, as the scalar field is smaller than the base field.
+        let sk_sig = N::field_from_bits_le(&self.sk_sig.to_bits_le())?;
+
+        // Construct the hash input (domain, sk_sig, message).
+        let mut preimage = Vec::with_capacity(3 + message.len());
+        preimage.push(domain);
+        preimage.push(sk_sig);
+        preimage.push(N::Field::from(message.len() as u128));
+        preimage.extend_from_slice(message);
+
+        // Compute the randomizer := HashToScalar(domain, sk_sig, message).
+        let randomizer = N::hash_to_scalar_psd8(&preimage)?;
+        // Sign the message, encoded as little-endian bits.
+        Signature::sign(self, &message.to_bits_le(), randomizer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Address;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_fields::One;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_sign_and_verify() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Sample a message.
+            let message: Vec<_> = (0..i).map(|_| UniformRand::rand(rng)).collect();
+
+            // Sign the message, and check that the signature is valid.
+            let signature = private_key.sign(&message, rng)?;
+            assert!(address.verify(&message, &signature));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_and_verify_fails() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for i in 1..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Sample a message, and sign it.
+            let message: Vec<<CurrentNetwork as Network>::Field> = (0..i).map(|_| UniformRand::rand(rng)).collect();
+            let signature = private_key.sign(&message, rng)?;
+
+            // Check that the signature is invalid for a tampered message.
+            let mut tampered_message = message.clone();
+            tampered_message[(i / 2) as usize] += <CurrentNetwork as Network>::Field::one();
+            assert!(!address.verify(&tampered_message, &signature));
+
+            // Check that the signature is invalid for a truncated message.
+            assert!(!address.verify(&message[..message.len() - 1], &signature));
+
+            // Check that a tampered signature is invalid for the message.
+            let tampered_response = signature.response() + <CurrentNetwork as Network>::Scalar::one();
+            let tampered_bytes = ((signature.challenge(), tampered_response), signature.compute_key()).to_bytes_le()?;
+            let tampered_signature = Signature::read_le(&tampered_bytes[..])?;
+            assert!(!address.verify(&message, &tampered_signature));
+
+            // Check that the deterministic signature is invalid for a tampered message.
+            let signature = private_key.sign_deterministic(&message)?;
+            assert!(!address.verify(&tampered_message, &signature));
+
+            // Check that the signature is invalid for a different address.
+            let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(!other_address.verify(&message, &signature));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_deterministic() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Sample a message.
+            let message: Vec<_> = (0..i).map(|_| UniformRand::rand(rng)).collect();
+
+            // Sign the message, and check that the signature is valid.
+            let signature = private_key.sign_deterministic(&message)?;
+            assert!(address.verify(&message, &signature));
+
+            // Check that signing again produces the same signature.
+            assert_eq!(signature, private_key.sign_deterministic(&message)?);
+
+            // Check that a different private key produces a different signature.
+            let other_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            assert_ne!(signature, other_private_key.sign_deterministic(&message)?);
+        }
+        Ok(())
+    }
+}
@@ -19,19 +19,25 @@ use super::*;
 static PRIVATE_KEY_PREFIX: [u8; 11] = [127, 134, 189, 116, 210, 221, 210, 137, 145, 18, 253]; // APrivateKey1
 
 impl<N: Network> FromStr for PrivateKey<N> {
-    type Err = Error;
+    type Err = AccountError;
 
     /// Reads in an account private key from a base58 string.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         // Encode the string into base58.
-        let data = s.from_base58().map_err(|err| anyhow!("{:?}", err))?;
+        let data = s.from_base58()?;
         if data.len() != 43 {
-            bail!("Invalid account private key length: found {}, expected 43", data.len())
+            return Err(AccountError::InvalidLength(format!("found length {}, expected 43", data.len())));
         } else if data[0..11] != PRIVATE_KEY_PREFIX {
-            bail!("Invalid account private key prefix: found {:?}, expected {:?}", &data[0..11], PRIVATE_KEY_PREFIX)
+            return Err(AccountError::InvalidPrefix(format!(
+                "found prefix {:?}, expected {:?}",
+                &data[0..11],
+                PRIVATE_KEY_PREFIX
+            )));
         }
+        // Recover the account seed.
+        let seed = scalar_from_bytes_le(&data[11..43])?;
         // Output the private key.
-        Ok(Self::try_from(FromBytes::read_le(&data[11..43])?).map_err(|e| error(format!("{e}")))?)
+        Self::try_from(seed).map_err(|error| AccountError::InvalidPrivateKey(format!("{error}")))
     }
 }
 
@@ -72,4 +78,27 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_string_errors() {
+        // Ensure a malformed base58 string is rejected with a base58 decoding error.
+        assert!(matches!(
+            PrivateKey::<CurrentNetwork>::from_str("APrivateKey10OIl"),
+            Err(AccountError::Base58Decode(_))
+        ));
+        // Ensure a well-formed base58 string of the wrong length is rejected.
+        assert!(matches!(PrivateKey::<CurrentNetwork>::from_str("APrivateKey1"), Err(AccountError::InvalidLength(_))));
+        // Ensure a string of the right length with the wrong prefix is rejected.
+        assert!(matches!(
+            PrivateKey::<CurrentNetwork>::from_str(&[1u8; 43].to_base58()),
+            Err(AccountError::InvalidPrefix(_))
+        ));
+        // Ensure a seed that is not less than the scalar field modulus is rejected.
+        let mut data = [255u8; 43];
+        data[0..11].copy_from_slice(&PRIVATE_KEY_PREFIX);
+        assert!(matches!(
+            PrivateKey::<CurrentNetwork>::from_str(&data.to_base58()),
+            Err(AccountError::OutOfRangeScalar)
+        ));
+    }
 }
@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the account address corresponding to the account private key.
+    pub fn to_address(&self) -> Result<Address<N>> {
+        Address::try_from(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ComputeKey;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_to_address() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+            let compute_key = ComputeKey::try_from(&private_key)?;
+            let expected = Address::try_from(&private_key)?;
+
+            // Ensure the private key, compute key, and explicit derivations all agree.
+            assert_eq!(expected, private_key.to_address()?);
+            assert_eq!(expected, compute_key.to_address()?);
+            assert_eq!(expected, Address::try_from(&compute_key)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A record ciphertext, whose ownership can be checked with a view key before it is decrypted.
+pub trait RecordCiphertext<N: Network> {
+    /// Returns the nonce of the record (i.e. `G^r`).
+    fn nonce(&self) -> &N::Affine;
+
+    /// Returns the owner tag of the record (i.e. the record MAC `Hash(G^r^view_key)`).
+    fn owner_tag(&self) -> &N::Field;
+}
+
+impl<N: Network> ViewKey<N> {
+    /// Returns `true` if the given record ciphertext belongs to this view key.
+    ///
+    /// This check only requires one scalar multiplication and one hash, and is intended
+    /// to filter records before attempting a full decryption.
+    pub fn is_owner<R: RecordCiphertext<N>>(&self, record_ciphertext: &R) -> bool {
+        // Compute the record view key := G^r^view_key.
+        let record_view_key = self.mul_point(*record_ciphertext.nonce()).to_affine().to_x_coordinate();
+        // Compute the candidate owner tag := Hash(G^r^view_key).
+        match N::hash_psd2(&[N::mac_domain(), record_view_key]) {
+            // Check if the owner tags match.
+            Ok(candidate_owner_tag) => *record_ciphertext.owner_tag() == candidate_owner_tag,
+            // If the computation fails, return false.
+            Err(error) => {
+                eprintln!("Failed to compute the candidate owner tag: {error}");
+                false
+            }
+        }
+    }
+}
@@ -15,25 +15,34 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod is_owner;
+mod secret_json;
 mod serialize;
 mod string;
 mod try_from;
+mod zeroizing;
 
-use crate::{ComputeKey, PrivateKey};
+pub use is_owner::RecordCiphertext;
+pub use secret_json::SecretJson;
+pub use zeroizing::ZeroizingBits;
+
+use crate::{scalar_from_bytes_le, AccountError, ComputeKey, PrivateKey};
 use snarkvm_console_network::Network;
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{
     io::{Read, Result as IoResult, Write},
+    BigInteger,
     FromBytes,
     FromBytesDeserializer,
     ToBytes,
     ToBytesSerializer,
 };
 
-use anyhow::{anyhow, bail, Error};
-use base58::{FromBase58, ToBase58};
+use base58::ToBase58;
 use core::{fmt, ops::Deref, str::FromStr};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::Zeroize;
 
 /// The account view key used to decrypt records and ciphertext.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A wrapper for a secret (such as a `ViewKey`) that is read from a config file.
+///
+/// The `Debug` and `Display` implementations redact the secret, so it is not leaked into logs.
+/// The secret may only be accessed with `expose_secret`, and `SecretJson` does not implement
+/// `Serialize`, so writing the secret out requires opting in with `expose_secret` or
+/// `#[serde(serialize_with = "SecretJson::serialize_exposed")]`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretJson<T>(T);
+
+impl<T> SecretJson<T> {
+    /// Initializes a new wrapper for the given secret.
+    pub fn new(secret: T) -> Self {
+        Self(secret)
+    }
+
+    /// Returns the secret.
+    pub fn expose_secret(&self) -> &T {
+        &self.0
+    }
+
+    /// Serializes the exposed secret, for use with `#[serde(serialize_with = "...")]`.
+    pub fn serialize_exposed<S: Serializer>(secret: &Self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+    {
+        secret.expose_secret().serialize(serializer)
+    }
+}
+
+impl<T> fmt::Debug for SecretJson<T> {
+    /// Writes a redacted placeholder, instead of the secret.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SecretJson(<redacted>)")
+    }
+}
+
+impl<T> fmt::Display for SecretJson<T> {
+    /// Writes a redacted placeholder, instead of the secret.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<redacted>")
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for SecretJson<T> {
+    /// Deserializes the secret.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::test_crypto_rng;
+
+    use anyhow::Result;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Config {
+        #[serde(serialize_with = "SecretJson::serialize_exposed")]
+        view_key: SecretJson<ViewKey<CurrentNetwork>>,
+    }
+
+    #[test]
+    fn test_redacted() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new view key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+            let view_key = ViewKey::try_from(private_key)?;
+            let secret = SecretJson::new(view_key);
+
+            // Ensure the view key is redacted.
+            assert_eq!("SecretJson(<redacted>)", format!("{secret:?}"));
+            assert_eq!("<redacted>", format!("{secret}"));
+            let config = format!("{:?}", Config { view_key: secret });
+            assert!(!config.contains(&view_key.to_string()));
+            assert!(!config.contains(&format!("{view_key:?}")));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new view key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+            let expected = ViewKey::try_from(private_key)?;
+            let secret = SecretJson::new(expected);
+
+            // Serialize the exposed secret.
+            let candidate_string = serde_json::to_string(secret.expose_secret())?;
+            assert_eq!(serde_json::to_string(&expected)?, candidate_string);
+
+            // Deserialize
+            let candidate: SecretJson<ViewKey<CurrentNetwork>> = serde_json::from_str(&candidate_string)?;
+            assert_eq!(&expected, candidate.expose_secret());
+
+            // Ensure the secret round-trips as a field of a config.
+            let config_string = serde_json::to_string(&Config { view_key: secret })?;
+            assert_eq!(format!("{{\"view_key\":{candidate_string}}}"), config_string);
+            let config: Config = serde_json::from_str(&config_string)?;
+            assert_eq!(&expected, config.view_key.expose_secret());
+        }
+        Ok(())
+    }
+}
@@ -19,19 +19,66 @@ use super::*;
 static VIEW_KEY_PREFIX: [u8; 7] = [14, 138, 223, 204, 247, 224, 122]; // AViewKey1
 
 impl<N: Network> FromStr for ViewKey<N> {
-    type Err = Error;
+    type Err = AccountError;
 
     /// Reads in an account view key from a base58 string.
+    ///
+    /// The string is decoded in constant time with respect to its characters,
+    /// so that importing a view key does not leak its structure through timing.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // Encode the string into base58.
-        let data = s.from_base58().map_err(|err| anyhow!("{:?}", err))?;
-        if data.len() != 39 {
-            bail!("Invalid account view key length: found {}, expected 39", data.len())
-        } else if data[0..7] != VIEW_KEY_PREFIX {
-            bail!("Invalid account view key prefix: found {:?}, expected {:?}", &data[0..7], VIEW_KEY_PREFIX)
+        // Decode the string from base58.
+        let data = from_base58_constant_time::<39>(s)?;
+        if data[0..7] != VIEW_KEY_PREFIX {
+            return Err(AccountError::InvalidPrefix(format!(
+                "found prefix {:?}, expected {:?}",
+                &data[0..7],
+                VIEW_KEY_PREFIX
+            )));
         }
         // Output the view key.
-        Ok(Self(FromBytes::read_le(&data[7..39])?))
+        Ok(Self(scalar_from_bytes_le(&data[7..39])?))
+    }
+}
+
+/// Decodes the given base58 string into exactly `N` bytes, in constant time with respect to its characters.
+/// For strings that decode to `N` bytes without a leading zero byte, the output matches `FromBase58`.
+fn from_base58_constant_time<const N: usize>(s: &str) -> Result<[u8; N], AccountError> {
+    const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    let mut bytes = [0u8; N];
+    let mut is_invalid = 0u32;
+    let mut overflow = 0u32;
+
+    for (index, character) in s.bytes().enumerate() {
+        // Look up the digit by scanning the entire alphabet, without branching on the character.
+        let mut digit = 0u32;
+        let mut is_found = 0u32;
+        for (candidate, symbol) in ALPHABET.iter().enumerate() {
+            // Set `is_equal` to 1 if the character matches the symbol, and 0 otherwise.
+            let is_equal = ((character ^ symbol) as u32).wrapping_sub(1) >> 31;
+            digit |= is_equal.wrapping_neg() & candidate as u32;
+            is_found |= is_equal;
+        }
+        is_invalid |= is_found ^ 1;
+        // A leading '1' encodes a leading zero byte, which does not fit in `N` bytes.
+        if index == 0 {
+            is_invalid |= (digit.wrapping_sub(1) >> 31) & 1;
+        }
+
+        // Multiply the big-endian bytes by 58, and add the digit, over every byte.
+        let mut carry = digit;
+        for byte in bytes.iter_mut().rev() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        overflow |= carry;
+    }
+
+    match (is_invalid, overflow) {
+        (0, 0) => Ok(bytes),
+        (0, _) => Err(AccountError::InvalidLength(format!("found length above {N}, expected {N}"))),
+        _ => Err(AccountError::Base58Decode("invalid base58 string".to_string())),
     }
 }
 
@@ -55,6 +102,7 @@ mod tests {
     use snarkvm_utilities::test_crypto_rng;
 
     use anyhow::Result;
+    use base58::FromBase58;
 
     type CurrentNetwork = Testnet3;
 
@@ -74,4 +122,44 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_from_base58_constant_time() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new view key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+            let view_key = ViewKey::try_from(private_key)?;
+
+            // Ensure the constant-time decoder matches the base58 decoder.
+            let string = view_key.to_string();
+            assert_eq!(string.from_base58().unwrap(), from_base58_constant_time::<39>(&string)?);
+        }
+
+        // Ensure a leading '1', which encodes a leading zero byte, is rejected.
+        let mut bytes = [7u8; 39];
+        bytes[0] = 0;
+        assert!(from_base58_constant_time::<39>(&bytes.to_base58()).is_err());
+        // Ensure a string that exceeds the number of bytes is rejected.
+        assert!(from_base58_constant_time::<4>(&[1u8; 5].to_base58()).is_err());
+        // Ensure a string that fits within the number of bytes is zero-padded.
+        assert_eq!([0, 1, 2], from_base58_constant_time::<3>(&[1u8, 2].to_base58())?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_errors() {
+        // Ensure a malformed base58 string is rejected with a base58 decoding error.
+        assert!(matches!(ViewKey::<CurrentNetwork>::from_str("AViewKey10OIl"), Err(AccountError::Base58Decode(_))));
+        // Ensure a well-formed base58 string that exceeds the length is rejected.
+        assert!(matches!(
+            ViewKey::<CurrentNetwork>::from_str(&[1u8; 40].to_base58()),
+            Err(AccountError::InvalidLength(_))
+        ));
+        // Ensure a well-formed base58 string with the wrong prefix is rejected.
+        assert!(matches!(ViewKey::<CurrentNetwork>::from_str("AViewKey1"), Err(AccountError::InvalidPrefix(_))));
+        // Ensure a view key that is not less than the scalar field modulus is rejected.
+        let mut data = [255u8; 39];
+        data[0..7].copy_from_slice(&VIEW_KEY_PREFIX);
+        assert!(matches!(ViewKey::<CurrentNetwork>::from_str(&data.to_base58()), Err(AccountError::OutOfRangeScalar)));
+    }
 }
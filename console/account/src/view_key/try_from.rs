@@ -17,7 +17,7 @@
 use super::*;
 
 impl<N: Network> TryFrom<PrivateKey<N>> for ViewKey<N> {
-    type Error = Error;
+    type Error = AccountError;
 
     /// Initializes a new account view key from an account private key.
     fn try_from(private_key: PrivateKey<N>) -> Result<Self, Self::Error> {
@@ -26,12 +26,13 @@ impl<N: Network> TryFrom<PrivateKey<N>> for ViewKey<N> {
 }
 
 impl<N: Network> TryFrom<&PrivateKey<N>> for ViewKey<N> {
-    type Error = Error;
+    type Error = AccountError;
 
     /// Initializes a new account view key from an account private key.
     fn try_from(private_key: &PrivateKey<N>) -> Result<Self, Self::Error> {
         // Derive the compute key.
-        let compute_key = ComputeKey::try_from(private_key)?;
+        let compute_key =
+            ComputeKey::try_from(private_key).map_err(|error| AccountError::InvalidViewKey(format!("{error}")))?;
         // Compute view_key := sk_sig + r_sig + sk_prf.
         Ok(Self(private_key.sk_sig() + private_key.r_sig() + compute_key.sk_prf()))
     }
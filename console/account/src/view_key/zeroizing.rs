@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A buffer of secret bits that is zeroized when dropped.
+///
+/// This is a best-effort measure, as copies made by the caller are not cleared.
+pub struct ZeroizingBits(Vec<bool>);
+
+impl ZeroizingBits {
+    /// Initializes a new zeroizing buffer that takes ownership of the given bits.
+    pub fn new(bits: Vec<bool>) -> Self {
+        Self(bits)
+    }
+}
+
+impl Zeroize for ZeroizingBits {
+    /// Zeroizes the entire allocation of the buffer, and clears it.
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Deref for ZeroizingBits {
+    type Target = [bool];
+
+    /// Returns the bits in the buffer.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Drop for ZeroizingBits {
+    /// Zeroizes the buffer before it is freed.
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<N: Network> ViewKey<N> {
+    /// Returns `point * view_key`, decomposing the view key into a zeroizing buffer of bits.
+    ///
+    /// `Record::decrypt` and `Data::decrypt` use this to derive their view keys,
+    /// so neither the view key representation nor its bits linger in memory after decryption.
+    pub fn mul_point(&self, point: N::Affine) -> N::Projective {
+        let num_bits = N::Scalar::size_in_bits();
+        let mut repr = self.0.to_repr();
+        // Allocate the buffer up front, so extending it never leaves a reallocated copy behind.
+        let mut bits_be = ZeroizingBits::new(Vec::with_capacity(num_bits));
+        bits_be.0.extend((0..num_bits).rev().map(|i| repr.get_bit(i)));
+        repr.as_mut().zeroize();
+        point.mul_bits(bits_be.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::{test_crypto_rng, UniformRand};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_zeroize() {
+        let mut bits = ZeroizingBits::new(vec![true; 256]);
+        assert!(bits.iter().all(|bit| *bit));
+
+        bits.zeroize();
+        assert!(bits.is_empty());
+    }
+
+    #[test]
+    fn test_mul_point() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let view_key = ViewKey::try_from(private_key)?;
+            let point = <CurrentNetwork as Network>::Affine::rand(rng);
+
+            assert_eq!(point * *view_key, view_key.mul_point(point));
+        }
+        Ok(())
+    }
+}
@@ -16,7 +16,8 @@
 
 use super::*;
 
-impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Commit for BHP<G, NUM_WINDOWS, WINDOW_SIZE>
+impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8> Commit
+    for BHP<G, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
 where
     <G as AffineCurve>::BaseField: PrimeField,
 {
@@ -29,3 +30,14 @@ where
         Ok(self.commit_uncompressed(input, randomizer)?.to_x_coordinate())
     }
 }
+
+impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8> CommitmentScheme
+    for BHP<G, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
+where
+    <G as AffineCurve>::BaseField: PrimeField,
+{
+    /// Returns `true` if the given BHP commitment opens to the given input and randomizer.
+    fn verify(&self, input: &[Self::Input], randomizer: &Self::Randomizer, commitment: &Self::Output) -> Result<bool> {
+        Ok(self.commit(input, randomizer)? == *commitment)
+    }
+}
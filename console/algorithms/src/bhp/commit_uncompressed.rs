@@ -16,8 +16,8 @@
 
 use super::*;
 
-impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> CommitUncompressed
-    for BHP<G, NUM_WINDOWS, WINDOW_SIZE>
+impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8> CommitUncompressed
+    for BHP<G, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
 where
     <G as AffineCurve>::BaseField: PrimeField,
 {
@@ -16,7 +16,8 @@
 
 use super::*;
 
-impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompressed for BHP<G, NUM_WINDOWS, WINDOW_SIZE>
+impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8> HashUncompressed
+    for BHP<G, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
 where
     <G as AffineCurve>::BaseField: PrimeField,
 {
@@ -29,7 +30,7 @@ where
     /// the BHP commitment scheme, as it is typically not used by applications.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Result<Self::Output> {
         // The number of hasher bits to fit.
-        let num_hasher_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+        let num_hasher_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * CHUNK_SIZE as usize;
         // The number of data bits in the output.
         let num_data_bits = G::BaseField::size_in_data_bits();
         // The maximum number of input bits per iteration.
@@ -111,4 +112,57 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_bhp_chunk_sizes() -> Result<()> {
+        fn check_chunk_size<const CHUNK_SIZE: u8>() -> Result<()> {
+            let bhp = BHP::<EdwardsAffine, 8, 32, CHUNK_SIZE>::setup("BHPTest")?;
+            assert_eq!(CHUNK_SIZE, bhp.chunk_size());
+
+            let max_bits = bhp.num_windows() as usize * bhp.window_size() as usize * CHUNK_SIZE as usize;
+            for i in 0..(ITERATIONS / 10) {
+                let input = (0..bhp.window_size() as u64 + i).map(|_| bool::rand(&mut test_rng())).collect::<Vec<_>>();
+                bhp.hash_uncompressed(&input)?;
+            }
+            // Ensure the hasher accepts exactly the parameter size, and rejects anything larger.
+            let input = (0..max_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<_>>();
+            assert!(bhp.hasher.hash_uncompressed(&input).is_ok());
+            let input = (0..max_bits + 1).map(|_| bool::rand(&mut test_rng())).collect::<Vec<_>>();
+            assert!(bhp.hasher.hash_uncompressed(&input).is_err());
+            Ok(())
+        }
+
+        check_chunk_size::<2>()?;
+        check_chunk_size::<3>()?;
+        check_chunk_size::<4>()
+    }
+
+    #[test]
+    fn test_bhp_unsupported_chunk_size() {
+        assert!(BHP::<EdwardsAffine, 8, 32, 0>::setup("BHPTest").is_err());
+        assert!(BHP::<EdwardsAffine, 8, 32, 1>::setup("BHPTest").is_err());
+        assert!(BHP::<EdwardsAffine, 8, 32, 9>::setup("BHPTest").is_err());
+        // Ensure the parameter size must exceed the size of the digest.
+        assert!(BHP::<EdwardsAffine, 1, 32, 2>::setup("BHPTest").is_err());
+    }
+
+    #[test]
+    fn test_bhp_default_chunk_size_is_unchanged() -> Result<()> {
+        let input = (0..300).map(|i| i % 3 == 0 || i % 7 == 1).collect::<Vec<_>>();
+
+        let bhp = BHP256::<EdwardsAffine>::setup("BHPTest")?;
+        assert_eq!(3, bhp.chunk_size());
+        assert_eq!(
+            "71636374186261132158975987351387860794388150130716164342755954552756907659",
+            bhp.hash(&input[..200])?.to_string()
+        );
+
+        let bhp = BHP512::<EdwardsAffine>::setup("BHPTest")?;
+        assert_eq!(3, bhp.chunk_size());
+        assert_eq!(
+            "16716992331562005232791113813761740995959131372646894738871488158787196367",
+            bhp.hash(&input)?.to_string()
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8>
+    BHPHasher<G, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
+{
+    /// Returns the BHP commitment of the given input and randomizer as a field element,
+    /// where the input is prefixed with its length as a 64-bit little-endian integer.
+    ///
+    /// The hasher pads its input with zeros up to a multiple of `CHUNK_SIZE`, which means
+    /// that `input` and `input || 0^k` may hash to the same digest. Prefer this method when
+    /// committing to variable-length inputs with the hasher directly, as the length prefix
+    /// removes this ambiguity. (Note: `BHP` already encodes the input length in its first
+    /// iteration, and does not require this method.)
+    pub fn commit_with_length_prefix(&self, input: &[bool], randomizer: &G::ScalarField) -> Result<G::BaseField> {
+        // Construct the preimage as: [ LENGTH(INPUT) || INPUT ].
+        let mut preimage = Vec::with_capacity(64 + input.len());
+        preimage.extend((input.len() as u64).to_bits_le());
+        preimage.extend(input);
+        // Compute the commitment on the preimage.
+        Ok(self.randomize(self.hash_uncompressed(&preimage)?, randomizer).to_x_coordinate())
+    }
+
+    /// Returns the given digest, blinded with `h^r` for the given randomizer `r`.
+    fn randomize(&self, digest: G, randomizer: &G::ScalarField) -> G {
+        let mut output = digest.to_projective();
+        // Compute h^r.
+        randomizer.to_bits_le().iter().zip_eq(&*self.random_base).filter(|(bit, _)| **bit).for_each(|(_, base)| {
+            output += base;
+        });
+        output.to_affine()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::EdwardsAffine;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 100;
+
+    type Hasher = BHPHasher<EdwardsAffine, 8, 54>;
+
+    #[test]
+    fn test_commit_with_length_prefix() -> Result<()> {
+        let hasher = Hasher::setup("BHPTest")?;
+
+        for i in 0..ITERATIONS {
+            // Sample an input whose length is not a multiple of the chunk size.
+            let num_bits = Hasher::MIN_BITS + 3 * i as usize + 1;
+            let input = (0..num_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<_>>();
+            let randomizer = UniformRand::rand(&mut test_rng());
+
+            // Compute the unprefixed and prefixed commitments on the input.
+            let unprefixed = hasher.randomize(hasher.hash_uncompressed(&input)?, &randomizer).to_x_coordinate();
+            let prefixed = hasher.commit_with_length_prefix(&input, &randomizer)?;
+            assert_ne!(unprefixed, prefixed);
+
+            for k in 1..=2 {
+                // Extend the input with `k` zeros, up to the chunk boundary.
+                let mut extended = input.clone();
+                extended.resize(num_bits + k, false);
+
+                // Ensure the unprefixed commitments collide.
+                let candidate = hasher.randomize(hasher.hash_uncompressed(&extended)?, &randomizer).to_x_coordinate();
+                assert_eq!(unprefixed, candidate);
+
+                // Ensure the prefixed commitments are distinct.
+                let candidate = hasher.commit_with_length_prefix(&extended, &randomizer)?;
+                assert_ne!(prefixed, candidate);
+            }
+        }
+        Ok(())
+    }
+}
@@ -16,8 +16,8 @@
 
 use super::*;
 
-impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompressed
-    for BHPHasher<G, NUM_WINDOWS, WINDOW_SIZE>
+impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8> HashUncompressed
+    for BHPHasher<G, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
 {
     type Input = bool;
     type Output = G;
@@ -37,28 +37,99 @@ impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
             input.len()
         );
 
-        // Pad the input to a multiple of `BHP_CHUNK_SIZE` for hashing.
+        // Pad the input to a multiple of `CHUNK_SIZE` for hashing.
+        let chunk_size = CHUNK_SIZE as usize;
         let mut input = input.to_vec();
-        if input.len() % BHP_CHUNK_SIZE != 0 {
-            let padding = BHP_CHUNK_SIZE - (input.len() % BHP_CHUNK_SIZE);
+        if input.len() % chunk_size != 0 {
+            let padding = chunk_size - (input.len() % chunk_size);
             input.resize(input.len() + padding, false);
-            ensure!((input.len() % BHP_CHUNK_SIZE) == 0, "Input must be a multiple of {BHP_CHUNK_SIZE}");
+            ensure!((input.len() % chunk_size) == 0, "Input must be a multiple of {CHUNK_SIZE}");
         }
 
-        // Compute sum of h_i^{sum of (1-2*c_{i,j,2})*(1+c_{i,j,0}+2*c_{i,j,1})*2^{4*(j-1)} for all j in segment}
-        // for all i. Described in section 5.4.1.7 in the Zcash protocol specification.
+        // Compute sum of h_i^{sum of (1-2*c_{i,j,k-1})*(1+c_{i,j,0}+...+2^{k-2}*c_{i,j,k-2})*2^{(k+1)*(j-1)}
+        // for all j in segment} for all i, where `k = CHUNK_SIZE`. Described in section 5.4.1.7 in the
+        // Zcash protocol specification (for `k = 3`).
         //
         // Note: `.zip()` is used here (as opposed to `.zip_eq()`) as the input can be less than
-        // `NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE` in length, which is the parameter size here.
-        Ok(input
-            .chunks(WINDOW_SIZE as usize * BHP_CHUNK_SIZE)
+        // `NUM_WINDOWS * WINDOW_SIZE * CHUNK_SIZE` in length, which is the parameter size here.
+        let window_bits = WINDOW_SIZE as usize * chunk_size;
+
+        // Note: The windows are independent, so their partial sums are computed in parallel, and then combined.
+        #[cfg(feature = "parallel")]
+        let sum = input
+            .par_chunks(window_bits)
+            .zip(self.bases_lookup.par_iter())
+            .map(|(bits, bases)| Self::window_sum(bits, bases))
+            .sum::<G::Projective>();
+
+        #[cfg(not(feature = "parallel"))]
+        let sum = input
+            .chunks(window_bits)
             .zip(&*self.bases_lookup)
-            .flat_map(|(bits, bases)| {
-                bits.chunks(BHP_CHUNK_SIZE).zip(bases).map(|(chunk_bits, base)| {
-                    base[(chunk_bits[0] as usize) | (chunk_bits[1] as usize) << 1 | (chunk_bits[2] as usize) << 2]
-                })
+            .map(|(bits, bases)| Self::window_sum(bits, bases))
+            .sum::<G::Projective>();
+
+        Ok(sum.to_affine())
+    }
+}
+
+impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8>
+    BHPHasher<G, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
+{
+    /// Returns the partial sum of one window, given the window bits and the bases lookup for the window.
+    fn window_sum(bits: &[bool], bases: &[Vec<G::Projective>]) -> G::Projective {
+        bits.chunks(CHUNK_SIZE as usize)
+            .zip(bases)
+            .map(|(chunk_bits, base)| {
+                base[chunk_bits.iter().enumerate().fold(0, |index, (i, bit)| index | (*bit as usize) << i)]
             })
-            .sum::<G::Projective>()
-            .to_affine())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::EdwardsAffine;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_hash_uncompressed_matches_sequential() -> Result<()> {
+        let hasher = BHPHasher::<EdwardsAffine, 8, 54>::setup("BHPTest")?;
+        let max_bits = BHPHasher::<EdwardsAffine, 8, 54>::MAX_BITS;
+
+        for i in 0..ITERATIONS {
+            // Sample a multi-window input.
+            let num_bits = max_bits - (i as usize * 37);
+            let input = (0..num_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<_>>();
+
+            // Compute the expected hash, by folding over the bases sequentially, without the lookup table.
+            let mut padded_input = input.clone();
+            padded_input.resize(num_bits + (3 - num_bits % 3) % 3, false);
+            let expected = padded_input
+                .chunks(3)
+                .zip(hasher.bases.iter().flatten())
+                .fold(<EdwardsAffine as AffineCurve>::Projective::zero(), |sum, (chunk_bits, base)| {
+                    // Compute the signed digit `(1 + c_0 + 2 * c_1) * (1 - 2 * c_2)` times the base.
+                    let mut term = *base;
+                    if chunk_bits[0] {
+                        term += base;
+                    }
+                    if chunk_bits[1] {
+                        term += base.double();
+                    }
+                    match chunk_bits[2] {
+                        true => sum - term,
+                        false => sum + term,
+                    }
+                })
+                .to_affine();
+
+            // Ensure the hash matches the sequential computation.
+            assert_eq!(expected, hasher.hash_uncompressed(&input)?);
+        }
+        Ok(())
     }
 }
@@ -14,47 +14,69 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod commit_with_length_prefix;
 mod hash_uncompressed;
 
 use crate::{Blake2Xs, HashUncompressed};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::{PrimeField, Zero};
-use snarkvm_utilities::{cfg_iter, BigInteger};
+use snarkvm_utilities::{cfg_iter, BigInteger, ToBits};
 
 use anyhow::{ensure, Result};
 use core::ops::Neg;
+use itertools::Itertools;
 use std::sync::Arc;
 
-/// The BHP chunk size (this implementation is for a 3-bit BHP).
-pub(super) const BHP_CHUNK_SIZE: usize = 3;
-pub(super) const BHP_LOOKUP_SIZE: usize = 2usize.pow(BHP_CHUNK_SIZE as u32);
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The default BHP chunk size (i.e. a 3-bit BHP).
+pub(super) const BHP_CHUNK_SIZE: u8 = 3;
+/// The minimum BHP chunk size (one magnitude bit and one sign bit).
+pub(super) const BHP_MIN_CHUNK_SIZE: u8 = 2;
+/// The maximum BHP chunk size.
+pub(super) const BHP_MAX_CHUNK_SIZE: u8 = 8;
 
 /// BHP is a collision-resistant hash function that takes a variable-length input.
 /// The BHP hasher is used to process one internal iteration of the BHP hash function.
+///
+/// Each chunk of `CHUNK_SIZE` bits is interpreted as a signed digit, where the lower
+/// `CHUNK_SIZE - 1` bits encode the magnitude, and the most significant bit encodes the sign.
+/// By default, this is a 3-bit BHP.
 #[derive(Clone)]
-pub struct BHPHasher<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> {
+pub struct BHPHasher<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8 = BHP_CHUNK_SIZE> {
     /// The bases for the BHP hash.
     bases: Arc<Vec<Vec<G::Projective>>>,
     /// The bases lookup table for the BHP hash.
-    bases_lookup: Arc<Vec<Vec<[G::Projective; BHP_LOOKUP_SIZE]>>>,
+    bases_lookup: Arc<Vec<Vec<Vec<G::Projective>>>>,
     /// The random base for the BHP commitment.
     random_base: Arc<Vec<G::Projective>>,
 }
 
-impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<G, NUM_WINDOWS, WINDOW_SIZE> {
+impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8>
+    BHPHasher<G, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
+{
     /// The maximum number of input bits.
-    const MAX_BITS: usize = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+    const MAX_BITS: usize = NUM_WINDOWS as usize * WINDOW_SIZE as usize * CHUNK_SIZE as usize;
     /// The minimum number of input bits (at least one window).
-    const MIN_BITS: usize = WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+    const MIN_BITS: usize = WINDOW_SIZE as usize * CHUNK_SIZE as usize;
+    /// The number of entries in the lookup table for each base.
+    const LOOKUP_SIZE: usize = 1 << CHUNK_SIZE;
 
     /// Initializes a new instance of BHP with the given domain.
     pub fn setup(domain: &str) -> Result<Self> {
+        // Ensure the chunk size is supported.
+        ensure!(
+            (BHP_MIN_CHUNK_SIZE..=BHP_MAX_CHUNK_SIZE).contains(&CHUNK_SIZE),
+            "The BHP chunk size must be between {BHP_MIN_CHUNK_SIZE} and {BHP_MAX_CHUNK_SIZE} bits, found {CHUNK_SIZE}"
+        );
+
         // Calculate the maximum window size.
         let mut maximum_window_size = 0;
         let mut range = <G::ScalarField as PrimeField>::BigInteger::from(2_u64);
         while range < G::ScalarField::modulus_minus_one_div_two() {
             // range < (p-1)/2
-            range.muln(4); // range * 2^4
+            range.muln(CHUNK_SIZE as u32 + 1); // range * 2^(CHUNK_SIZE + 1)
             maximum_window_size += 1;
         }
         ensure!(WINDOW_SIZE <= maximum_window_size, "The maximum BHP window size is {maximum_window_size}");
@@ -70,7 +92,7 @@ impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<G,
                 let mut powers = Vec::with_capacity(WINDOW_SIZE as usize);
                 for _ in 0..WINDOW_SIZE {
                     powers.push(base);
-                    for _ in 0..4 {
+                    for _ in 0..(CHUNK_SIZE + 1) {
                         base.double_in_place();
                     }
                 }
@@ -87,16 +109,19 @@ impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<G,
             .map(|x| {
                 x.iter()
                     .map(|g| {
-                        let mut lookup = [G::Projective::zero(); BHP_LOOKUP_SIZE];
-                        for (i, element) in lookup.iter_mut().enumerate().take(BHP_LOOKUP_SIZE) {
+                        let mut lookup = vec![G::Projective::zero(); Self::LOOKUP_SIZE];
+                        for (i, element) in lookup.iter_mut().enumerate() {
+                            // Compute the magnitude `(1 + i_0 + 2 * i_1 + ...)`, using the lower `CHUNK_SIZE - 1` bits.
                             *element = *g;
-                            if (i & 0x01) != 0 {
-                                *element += g;
-                            }
-                            if (i & 0x02) != 0 {
-                                *element += g.double();
+                            let mut power = *g;
+                            for j in 0..(CHUNK_SIZE - 1) {
+                                if (i >> j) & 0x01 != 0 {
+                                    *element += power;
+                                }
+                                power.double_in_place();
                             }
-                            if (i & 0x04) != 0 {
+                            // Negate the element, if the most significant bit is set.
+                            if (i >> (CHUNK_SIZE - 1)) & 0x01 != 0 {
                                 *element = element.neg();
                             }
                         }
@@ -104,7 +129,7 @@ impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<G,
                     })
                     .collect()
             })
-            .collect::<Vec<Vec<[G::Projective; BHP_LOOKUP_SIZE]>>>();
+            .collect::<Vec<Vec<Vec<G::Projective>>>>();
         ensure!(bases_lookup.len() == NUM_WINDOWS as usize, "Incorrect number of BHP lookups ({})", bases_lookup.len());
         for window in &bases_lookup {
             ensure!(window.len() == WINDOW_SIZE as usize, "Incorrect BHP lookup window size ({})", window.len());
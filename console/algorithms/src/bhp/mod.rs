@@ -15,14 +15,14 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod hasher;
-use hasher::BHPHasher;
+use hasher::{BHPHasher, BHP_CHUNK_SIZE};
 
 mod commit;
 mod commit_uncompressed;
 mod hash;
 mod hash_uncompressed;
 
-use crate::{Commit, CommitUncompressed, Hash, HashUncompressed};
+use crate::{Commit, CommitUncompressed, CommitmentScheme, Hash, HashUncompressed};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::ToBits;
@@ -31,8 +31,6 @@ use anyhow::{ensure, Result};
 use itertools::Itertools;
 use std::sync::Arc;
 
-const BHP_CHUNK_SIZE: usize = 3;
-
 /// BHP256 is a collision-resistant hash function that takes a 256-bit input.
 pub type BHP256<G> = BHP<G, 3, 57>; // Supports inputs up to 261 bits (1 u8 + 1 Fq).
 /// BHP512 is a collision-resistant hash function that takes a 512-bit input.
@@ -56,15 +54,18 @@ pub type BHP1024<G> = BHP<G, 8, 54>; // Supports inputs up to 1044 bits (4 u8 +
 /// ```text
 /// DIGEST_N+1 = BHP([ DIGEST_N[0..DATA_BITS] || INPUT[(N+1)*BLOCK_SIZE..(N+2)*BLOCK_SIZE] ]);
 /// ```
+///
+/// The `CHUNK_SIZE` is the number of input bits processed per base (by default, this is a 3-bit BHP).
 #[derive(Clone)]
-pub struct BHP<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> {
+pub struct BHP<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8 = BHP_CHUNK_SIZE> {
     /// The domain separator for the BHP hash function.
     domain: Vec<bool>,
     /// The internal BHP hasher used to process one iteration.
-    hasher: BHPHasher<G, NUM_WINDOWS, WINDOW_SIZE>,
+    hasher: BHPHasher<G, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>,
 }
 
-impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<G, NUM_WINDOWS, WINDOW_SIZE>
+impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8, const CHUNK_SIZE: u8>
+    BHP<G, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>
 where
     <G as AffineCurve>::BaseField: PrimeField,
 {
@@ -75,8 +76,16 @@ where
         let max_bits = G::BaseField::size_in_data_bits() - 64; // 64 bits encode the length.
         ensure!(num_bits <= max_bits, "Domain cannot exceed {max_bits} bits, found {num_bits} bits");
 
+        // Ensure each iteration of the hasher is able to absorb the previous digest and at least one input bit.
+        let num_hasher_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * CHUNK_SIZE as usize;
+        let num_data_bits = G::BaseField::size_in_data_bits();
+        ensure!(
+            num_hasher_bits > num_data_bits,
+            "The BHP parameter size must exceed {num_data_bits} bits, found {num_hasher_bits} bits"
+        );
+
         // Initialize the BHP hasher.
-        let hasher = BHPHasher::<G, NUM_WINDOWS, WINDOW_SIZE>::setup(domain)?;
+        let hasher = BHPHasher::<G, NUM_WINDOWS, WINDOW_SIZE, CHUNK_SIZE>::setup(domain)?;
 
         // Convert the domain into a boolean vector.
         let mut domain = domain.as_bytes().to_bits_le();
@@ -113,4 +122,9 @@ where
     pub fn window_size(&self) -> u8 {
         WINDOW_SIZE
     }
+
+    /// Returns the chunk size.
+    pub fn chunk_size(&self) -> u8 {
+        CHUNK_SIZE
+    }
 }
@@ -97,6 +97,42 @@ impl<
             false => Ok(cmp::min(element, -element)),
         }
     }
+
+    /// Returns a decoded field element, given the affine group element and sign from `encode`.
+    ///
+    /// As `encode` clears the cofactor, the encoding is only recovered up to a small-order point.
+    /// Each candidate encoding is decoded, and the first that re-encodes to the given group element is returned.
+    /// Note that the returned field element may differ from the original input, if several inputs share an encoding.
+    pub fn decode_cofactor_cleared(group: &G, sign_high: bool) -> Result<BaseField<G>> {
+        ensure!(!group.is_zero(), "Inputs to Elligator2 must be nonzero (inverses will fail)");
+        ensure!(group.is_on_curve(), "Inputs to Elligator2 must be on the twisted Edwards curve");
+        ensure!(group.is_in_correct_subgroup_assuming_on_curve(), "Inputs to Elligator2 must be in the prime subgroup");
+
+        // Compute the small-order points, which are (0, 1), (0, -1), and (x, 0) for a * x^2 == 1.
+        let (zero, one) = (BaseField::<G>::zero(), BaseField::<G>::one());
+        let small_order_points = [G::from_coordinates((zero, one)), G::from_coordinates((zero, -one))]
+            .into_iter()
+            .chain([true, false].into_iter().filter_map(|greatest| G::from_y_coordinate(zero, greatest)));
+
+        // Recover the prime-order component of the encoding before the cofactor was cleared.
+        let base = group.mul_by_cofactor_inv();
+
+        for small_order_point in small_order_points {
+            let mut candidate = base.to_projective();
+            candidate.add_assign_mixed(&small_order_point);
+            let candidate = G::from(candidate);
+
+            // Ensure the candidate decodes to a field element that encodes to the given group element and sign.
+            if let Ok(element) = Self::decode(&candidate, sign_high) {
+                if let Ok((encoding, encoding_sign_high)) = Self::encode(&element) {
+                    if encoding == *group && encoding_sign_high == sign_high {
+                        return Ok(element);
+                    }
+                }
+            }
+        }
+        bail!("Elligator2 failed: no preimage exists for the given group element and sign")
+    }
 }
 
 #[cfg(test)]
@@ -132,6 +168,55 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_decode_sign_selection() -> Result<()> {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS / 10 {
+            let expected: <EdwardsAffine as AffineCurve>::BaseField = UniformRand::rand(rng);
+
+            let (encoded, sign_high) =
+                Elligator2::<EdwardsAffine, EdwardsParameters>::encode_without_cofactor_clear(&expected)?;
+
+            // Ensure the low sign selects the minimum, and the high sign selects the maximum.
+            let low = Elligator2::<EdwardsAffine, EdwardsParameters>::decode(&encoded, false)?;
+            let high = Elligator2::<EdwardsAffine, EdwardsParameters>::decode(&encoded, true)?;
+            assert_eq!(cmp::min(expected, -expected), low);
+            assert_eq!(cmp::max(expected, -expected), high);
+
+            // Ensure the wrong sign yields a different field element, namely the negation of the input.
+            let wrong = Elligator2::<EdwardsAffine, EdwardsParameters>::decode(&encoded, !sign_high)?;
+            assert_ne!(expected, wrong);
+            assert_eq!(-expected, wrong);
+
+            // Ensure the wrong field element is still valid, as it encodes to the same group element.
+            let (candidate, candidate_sign_high) =
+                Elligator2::<EdwardsAffine, EdwardsParameters>::encode_without_cofactor_clear(&wrong)?;
+            assert_eq!(encoded, candidate);
+            assert_eq!(!sign_high, candidate_sign_high);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_and_decode_cofactor_cleared() -> Result<()> {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS / 10 {
+            let input = UniformRand::rand(rng);
+
+            // Encode the input, and decode the cofactor-cleared group element with the returned sign.
+            let (encoded, sign_high) = Elligator2::<EdwardsAffine, EdwardsParameters>::encode(&input)?;
+            let decoded = Elligator2::<EdwardsAffine, EdwardsParameters>::decode_cofactor_cleared(&encoded, sign_high)?;
+
+            // Ensure the decoded element encodes to the same group element and sign.
+            let (candidate, candidate_sign_high) = Elligator2::<EdwardsAffine, EdwardsParameters>::encode(&decoded)?;
+            assert_eq!(encoded, candidate);
+            assert_eq!(sign_high, candidate_sign_high);
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_zero_fails() {
         let encode = Elligator2::<EdwardsAffine, EdwardsParameters>::encode(&Zero::zero());
@@ -26,6 +26,7 @@ impl<
     pub(super) const MONTGOMERY_B: BaseField<G> = <P as MontgomeryParameters>::COEFF_B;
 
     /// Returns the encoded affine group element and sign, given a field element.
+    /// The sign is required to decode the group element with `decode_cofactor_cleared`.
     pub fn encode(input: &BaseField<G>) -> Result<(G, bool)> {
         // Compute the encoding of the input field element.
         let (encoding, sign_high) = Self::encode_without_cofactor_clear(input)?;
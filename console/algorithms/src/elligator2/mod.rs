@@ -17,7 +17,7 @@
 mod decode;
 mod encode;
 
-use snarkvm_curves::{AffineCurve, MontgomeryParameters, TwistedEdwardsParameters};
+use snarkvm_curves::{AffineCurve, MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters};
 use snarkvm_fields::{Field, LegendreSymbol, One, SquareRootField, Zero};
 
 use anyhow::{anyhow, bail, ensure, Result};
@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::AffineCurve;
+use snarkvm_fields::One;
+
+use anyhow::{bail, Result};
+
+/// The maximum number of increments to attempt, before aborting.
+const MAX_ATTEMPTS: usize = 256;
+
+/// Returns an affine group element in the prime-order subgroup, given a base field element,
+/// using the try-and-increment method.
+///
+/// The input is expected to be the output of a hash to the base field (e.g. Poseidon).
+/// Starting from the input, each candidate is tested as an x-coordinate on the curve, and
+/// incremented by one until a point is found. The point is then cofactor cleared.
+///
+/// This method is not constant-time, and serves as a reference implementation to cross-check
+/// `Elligator2`, and as a fallback for curves where `Elligator2` does not apply.
+pub fn hash_to_group_try_increment<G: AffineCurve>(input: &G::BaseField) -> Result<G> {
+    let mut x = *input;
+    for _ in 0..MAX_ATTEMPTS {
+        // Attempt to recover a point with the candidate x-coordinate.
+        if let Some(point) = G::from_x_coordinate(x, true) {
+            // Cofactor clear the point.
+            let group = point.mul_by_cofactor();
+            // Output the group element, if it is not the identity (i.e. `point` was not of small order).
+            if !group.is_zero() {
+                debug_assert!(group.is_on_curve());
+                debug_assert!(group.is_in_correct_subgroup_assuming_on_curve());
+                return Ok(group);
+            }
+        }
+        // Increment the candidate x-coordinate.
+        x += G::BaseField::one();
+    }
+    // Abort with probability roughly 2^-256.
+    bail!("Try-and-increment failed to find a group element after {MAX_ATTEMPTS} attempts")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Elligator2;
+    use snarkvm_curves::edwards_bls12::{EdwardsAffine, EdwardsParameters};
+    use snarkvm_fields::Zero;
+    use snarkvm_utilities::{test_rng, ToBits, UniformRand};
+
+    const ITERATIONS: usize = 1000;
+
+    type BaseField = <EdwardsAffine as AffineCurve>::BaseField;
+
+    #[test]
+    fn test_hash_to_group_try_increment() -> Result<()> {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            let input: BaseField = UniformRand::rand(rng);
+
+            // Ensure the output is a nonzero element in the prime-order subgroup.
+            let group = hash_to_group_try_increment::<EdwardsAffine>(&input)?;
+            assert!(!group.is_zero());
+            assert!(group.is_on_curve());
+            assert!(group.is_in_correct_subgroup_assuming_on_curve());
+
+            // Ensure the output is deterministic.
+            assert_eq!(group, hash_to_group_try_increment::<EdwardsAffine>(&input)?);
+
+            // Ensure the output is the cofactor-cleared point, when the input is already a valid x-coordinate.
+            if let Some(point) = EdwardsAffine::from_x_coordinate(input, true) {
+                if !point.mul_by_cofactor().is_zero() {
+                    assert_eq!(point.mul_by_cofactor(), group);
+                }
+            }
+
+            // Cross-check against Elligator2, which must map the input into the same prime-order subgroup,
+            // and whose output must decode to a field element that re-encodes to the same group element.
+            if !input.is_zero() {
+                let (expected, sign_high) = Elligator2::<EdwardsAffine, EdwardsParameters>::encode(&input)?;
+                assert!(expected.is_on_curve());
+                assert!(expected.is_in_correct_subgroup_assuming_on_curve());
+
+                let decoded =
+                    Elligator2::<EdwardsAffine, EdwardsParameters>::decode_cofactor_cleared(&expected, sign_high)?;
+                assert_eq!(expected, Elligator2::<EdwardsAffine, EdwardsParameters>::encode(&decoded)?.0);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_to_group_try_increment_is_well_distributed() -> Result<()> {
+        const NUM_BUCKETS: usize = 16;
+
+        let rng = &mut test_rng();
+
+        // Bucket the outputs by the lowest 4 bits of their x-coordinate.
+        let mut buckets = [0usize; NUM_BUCKETS];
+        let mut outputs = Vec::with_capacity(ITERATIONS * 2);
+        for _ in 0..ITERATIONS * 2 {
+            let group = hash_to_group_try_increment::<EdwardsAffine>(&UniformRand::rand(rng))?;
+            let bits = group.to_x_coordinate().to_bits_le();
+            let bucket = bits[..4].iter().rev().fold(0, |acc, bit| (acc << 1) | *bit as usize);
+            buckets[bucket] += 1;
+            outputs.push(group);
+        }
+
+        // Ensure there are no collisions among the outputs.
+        let num_outputs = outputs.len();
+        outputs.sort_by_key(|group| group.to_x_coordinate());
+        outputs.dedup();
+        assert_eq!(num_outputs, outputs.len());
+
+        // Compute the chi-squared statistic over the buckets.
+        let expected = (ITERATIONS * 2) as f64 / NUM_BUCKETS as f64;
+        let chi_squared = buckets.iter().map(|count| (*count as f64 - expected).powi(2) / expected).sum::<f64>();
+        // Ensure the statistic is below the critical value for 15 degrees of freedom at p = 0.001.
+        assert!(chi_squared < 37.697, "Outputs are not well-distributed (chi^2 = {chi_squared}): {buckets:?}");
+        Ok(())
+    }
+}
@@ -26,14 +26,17 @@ pub use blake2xs::Blake2Xs;
 mod elligator2;
 pub use elligator2::Elligator2;
 
+mod hash_to_group;
+pub use hash_to_group::hash_to_group_try_increment;
+
 mod nsec5;
 pub use nsec5::NSEC5;
 
 mod pedersen;
-pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
+pub use pedersen::{Pedersen, Pedersen128, Pedersen64, PedersenOpening, RandomizerReuseDetector};
 
 mod poseidon;
-pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8};
+pub use poseidon::{AlgebraicSponge, DuplexSpongeMode, Poseidon, Poseidon2, Poseidon4, Poseidon8, PoseidonSponge};
 
 pub mod traits;
 pub use traits::*;
@@ -26,3 +26,10 @@ impl<G: AffineCurve, const NUM_BITS: u8> Commit for Pedersen<G, NUM_BITS> {
         Ok(self.commit_uncompressed(input, randomizer)?.to_x_coordinate())
     }
 }
+
+impl<G: AffineCurve, const NUM_BITS: u8> CommitmentScheme for Pedersen<G, NUM_BITS> {
+    /// Returns `true` if the given Pedersen commitment opens to the given input and randomizer.
+    fn verify(&self, input: &[Self::Input], randomizer: &Self::Randomizer, commitment: &Self::Output) -> Result<bool> {
+        Ok(self.commit(input, randomizer)? == *commitment)
+    }
+}
@@ -26,3 +26,81 @@ impl<G: AffineCurve, const NUM_BITS: u8> Hash for Pedersen<G, NUM_BITS> {
         Ok(self.hash_uncompressed(input)?.to_x_coordinate())
     }
 }
+
+impl<G: AffineCurve, const NUM_BITS: u8> Pedersen<G, NUM_BITS> {
+    /// Returns the Pedersen hash of the given input as field elements that depend on both
+    /// the x-coordinate and the sign of the y-coordinate.
+    ///
+    /// The output is `[x, sign(y)]`, where `sign(y)` is `1` if `y` is lexicographically larger
+    /// than `-y`, and `0` otherwise. The sign is kept as a separate element, as folding it into
+    /// the x-coordinate (e.g. as `2 * x + sign(y)`) wraps around the modulus and collides.
+    /// As such, two points that differ only in the sign of their y-coordinate map to distinct
+    /// outputs, whereas `hash` maps both to the same x-coordinate.
+    pub fn hash_to_fields_with_sign(&self, input: &[bool]) -> Result<[G::BaseField; 2]> {
+        let point = self.hash_uncompressed(input)?;
+        let (x, y) = (point.to_x_coordinate(), point.to_y_coordinate());
+        Ok(Self::pack_with_sign(x, y > -y))
+    }
+
+    /// Returns `[x, sign]` for the given x-coordinate and sign.
+    fn pack_with_sign(x: G::BaseField, sign: bool) -> [G::BaseField; 2] {
+        match sign {
+            true => [x, G::BaseField::one()],
+            false => [x, G::BaseField::zero()],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::EdwardsAffine;
+    use snarkvm_fields::Field;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_hash_to_fields_with_sign() -> Result<()> {
+        let pedersen = Pedersen64::<EdwardsAffine>::setup("PedersenHashToFieldWithSignTest");
+
+        for _ in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..64).map(|_| bool::rand(&mut test_rng())).collect::<Vec<_>>();
+
+            // Compute the point, and the point with the opposite sign of the y-coordinate.
+            let point = pedersen.hash_uncompressed(&input)?;
+            let (x, y) = (point.to_x_coordinate(), point.to_y_coordinate());
+            let flipped = EdwardsAffine::from_x_coordinate(x, y < -y).unwrap();
+            assert_eq!(-y, flipped.to_y_coordinate());
+
+            // Ensure the plain hash maps both points to the same x-coordinate.
+            assert_eq!(pedersen.hash(&input)?, flipped.to_x_coordinate());
+
+            // Ensure the point is recovered from the packed x-coordinate and sign, and not the flipped point.
+            let [packed_x, packed_sign] = pedersen.hash_to_fields_with_sign(&input)?;
+            assert!(packed_sign.is_zero() || packed_sign.is_one());
+            let recovered = EdwardsAffine::from_x_coordinate(packed_x, packed_sign.is_one()).unwrap();
+            assert_eq!(point, recovered);
+            assert_ne!(flipped, recovered);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_pack_with_sign_near_half_modulus() {
+        type F = <EdwardsAffine as AffineCurve>::BaseField;
+
+        // Sample `(p + 1) / 2`, which satisfies `2 * (p + 1) / 2 = 1 (mod p)`.
+        let half = F::half();
+        // Ensure folding the sign into the x-coordinate, as `2 * x + sign`, would collide.
+        assert_eq!(F::zero().double() + F::one(), half.double());
+
+        // Ensure `(0, 1)` and `((p + 1) / 2, 0)` are packed into distinct outputs.
+        let packed = Pedersen64::<EdwardsAffine>::pack_with_sign(F::zero(), true);
+        let candidate = Pedersen64::<EdwardsAffine>::pack_with_sign(half, false);
+        assert_ne!(packed, candidate);
+        assert_eq!([F::zero(), F::one()], packed);
+        assert_eq!([half, F::zero()], candidate);
+    }
+}
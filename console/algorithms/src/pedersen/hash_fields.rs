@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<G: AffineCurve, const NUM_BITS: u8> Pedersen<G, NUM_BITS> {
+    /// Returns the Pedersen hash of the given field elements, where each field element
+    /// is decomposed into its canonical **little-endian** bits, and the bits are concatenated.
+    ///
+    /// The output is equal to calling `hash` on the decomposed bits.
+    ///
+    /// # Errors
+    /// Returns an error if the total number of bits exceeds `NUM_BITS`.
+    pub fn hash_fields(&self, fields: &[G::BaseField]) -> Result<G::BaseField> {
+        // Decompose the field elements into little-endian bits.
+        let input = fields.iter().flat_map(|field| field.to_bits_le()).collect::<Vec<_>>();
+        // Ensure the input size is within the parameter size.
+        if input.len() > NUM_BITS as usize {
+            bail!(
+                "Invalid input size for Pedersen: expected <= {NUM_BITS} bits, found {} bits from {} field elements",
+                input.len(),
+                fields.len()
+            )
+        }
+        // Compute the Pedersen hash.
+        self.hash(&input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::{EdwardsAffine, Fq};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_hash_fields() -> Result<()> {
+        // Note: A single base field element of Edwards BLS12 has 253 bits.
+        let pedersen = Pedersen::<EdwardsAffine, 253>::setup("PedersenHashFieldsTest");
+
+        for _ in 0..ITERATIONS {
+            // Sample a random field element.
+            let field = Fq::rand(&mut test_rng());
+
+            // Ensure the hash matches decomposing the field element manually.
+            let expected = pedersen.hash(&field.to_bits_le())?;
+            assert_eq!(expected, pedersen.hash_fields(&[field])?);
+        }
+
+        // Ensure an empty input matches hashing no bits.
+        assert_eq!(pedersen.hash(&[])?, pedersen.hash_fields(&[])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_fields_exceeds_window() {
+        // Ensure two field elements exceed the window of a single field element.
+        let pedersen = Pedersen::<EdwardsAffine, 253>::setup("PedersenHashFieldsTest");
+        let fields = [Fq::rand(&mut test_rng()), Fq::rand(&mut test_rng())];
+        let error = pedersen.hash_fields(&fields).unwrap_err();
+        assert!(error.to_string().contains("found 506 bits from 2 field elements"), "{error}");
+
+        // Ensure a single field element exceeds the window of a 64-bit input.
+        let pedersen = Pedersen64::<EdwardsAffine>::setup("PedersenHashFieldsTest");
+        assert!(pedersen.hash_fields(&[Fq::rand(&mut test_rng())]).is_err());
+    }
+}
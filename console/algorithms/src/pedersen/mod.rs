@@ -17,15 +17,24 @@
 mod commit;
 mod commit_uncompressed;
 mod hash;
+mod hash_fields;
 mod hash_uncompressed;
+mod serialize;
 
-use crate::{Blake2Xs, Commit, CommitUncompressed, Hash, HashUncompressed};
-use snarkvm_curves::{AffineCurve, ProjectiveCurve};
-use snarkvm_fields::{PrimeField, Zero};
+mod opening;
+pub use opening::PedersenOpening;
+
+mod randomizer_reuse;
+pub use randomizer_reuse::RandomizerReuseDetector;
+
+use crate::{Blake2Xs, Commit, CommitUncompressed, CommitmentScheme, Hash, HashUncompressed};
+use snarkvm_curves::{batch_into_affine, AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{One, PrimeField, Zero};
 use snarkvm_utilities::ToBits;
 
 use anyhow::{bail, Result};
 use itertools::Itertools;
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 use std::{borrow::Cow, sync::Arc};
 
 /// Pedersen64 is an *additively-homomorphic* collision-resistant hash function that takes a 64-bit input.
@@ -37,6 +46,8 @@ pub type Pedersen128<G> = Pedersen<G, 128>;
 /// The Pedersen hash function does *not* behave like a random oracle, see Poseidon for one.
 #[derive(Clone)]
 pub struct Pedersen<G: AffineCurve, const NUM_BITS: u8> {
+    /// The setup message for the Pedersen hash.
+    message: String,
     /// The base window for the Pedersen hash.
     base_window: Arc<Vec<G::Projective>>,
     /// The random base window for the Pedersen commitment.
@@ -69,7 +80,16 @@ impl<G: AffineCurve, const NUM_BITS: u8> Pedersen<G, NUM_BITS> {
         }
         assert_eq!(random_base.len(), num_scalar_bits);
 
-        Self { base_window: Arc::new(base_window.to_vec()), random_base_window: Arc::new(random_base) }
+        Self {
+            message: message.to_string(),
+            base_window: Arc::new(base_window.to_vec()),
+            random_base_window: Arc::new(random_base),
+        }
+    }
+
+    /// Returns the setup message.
+    pub fn message(&self) -> &str {
+        &self.message
     }
 
     /// Returns the base window.
@@ -81,4 +101,34 @@ impl<G: AffineCurve, const NUM_BITS: u8> Pedersen<G, NUM_BITS> {
     pub fn random_base_window(&self) -> &Arc<Vec<G::Projective>> {
         &self.random_base_window
     }
+
+    /// Returns the base window in affine form, using a single batch inversion.
+    pub fn base_window_affine(&self) -> Vec<G> {
+        batch_into_affine(&self.base_window)
+    }
+
+    /// Returns the random base window in affine form, using a single batch inversion.
+    pub fn random_base_window_affine(&self) -> Vec<G> {
+        batch_into_affine(&self.random_base_window)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::EdwardsAffine;
+
+    #[test]
+    fn test_window_affine() {
+        let pedersen = Pedersen64::<EdwardsAffine>::setup("PedersenWindowAffineTest");
+
+        // Ensure the batch normalization matches the per-element conversion.
+        let expected = pedersen.base_window().iter().map(|base| base.to_affine()).collect::<Vec<_>>();
+        assert_eq!(expected, pedersen.base_window_affine());
+        assert_eq!(64, expected.len());
+
+        let expected = pedersen.random_base_window().iter().map(|base| base.to_affine()).collect::<Vec<_>>();
+        assert_eq!(expected, pedersen.random_base_window_affine());
+        assert_eq!(<EdwardsAffine as AffineCurve>::ScalarField::size_in_bits(), expected.len());
+    }
 }
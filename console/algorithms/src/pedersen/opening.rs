@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_utilities::{
+    bits_from_bytes_le,
+    bytes_from_bits_le,
+    error,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
+
+/// The opening of a Pedersen commitment, consisting of the committed message bits and the randomizer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "G: AffineCurve")]
+pub struct PedersenOpening<G: AffineCurve> {
+    /// The committed message, as a list of little-endian bits.
+    message: Vec<bool>,
+    /// The randomizer of the commitment.
+    randomizer: G::ScalarField,
+}
+
+impl<G: AffineCurve> PedersenOpening<G> {
+    /// Initializes a new opening from the given message bits and randomizer.
+    pub fn new(message: Vec<bool>, randomizer: G::ScalarField) -> Self {
+        Self { message, randomizer }
+    }
+
+    /// Returns the committed message bits.
+    pub fn message(&self) -> &[bool] {
+        &self.message
+    }
+
+    /// Returns the randomizer.
+    pub fn randomizer(&self) -> &G::ScalarField {
+        &self.randomizer
+    }
+
+    /// Returns the Pedersen commitment for this opening.
+    pub fn commit<const NUM_BITS: u8>(&self, pedersen: &Pedersen<G, NUM_BITS>) -> Result<G::BaseField> {
+        pedersen.commit(&self.message, &self.randomizer)
+    }
+
+    /// Returns `true` if this opening recomputes the given Pedersen commitment.
+    pub fn verify<const NUM_BITS: u8>(&self, pedersen: &Pedersen<G, NUM_BITS>, commitment: &G::BaseField) -> bool {
+        match self.commit(pedersen) {
+            Ok(candidate) => candidate == *commitment,
+            Err(_) => false,
+        }
+    }
+}
+
+impl<G: AffineCurve> FromBytes for PedersenOpening<G> {
+    /// Reads the opening from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of message bits.
+        let num_bits = u16::read_le(&mut reader)? as usize;
+        // Read the message bytes.
+        let mut bytes = vec![0u8; (num_bits + 7) / 8];
+        reader.read_exact(&mut bytes)?;
+        // Ensure the padding bits are zero.
+        if bits_from_bytes_le(&bytes).skip(num_bits).any(|bit| bit) {
+            return Err(error("Invalid Pedersen opening: the message padding bits must be zero"));
+        }
+        let message = bits_from_bytes_le(&bytes).take(num_bits).collect();
+        // Read the randomizer.
+        let randomizer = G::ScalarField::read_le(&mut reader)?;
+        Ok(Self { message, randomizer })
+    }
+}
+
+impl<G: AffineCurve> ToBytes for PedersenOpening<G> {
+    /// Writes the opening to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of message bits fits in a `u16`.
+        let num_bits = u16::try_from(self.message.len())
+            .map_err(|_| error("Invalid Pedersen opening: the message exceeds the maximum number of bits"))?;
+        num_bits.write_le(&mut writer)?;
+        bytes_from_bits_le(&self.message).write_le(&mut writer)?;
+        self.randomizer.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::{EdwardsAffine, Fr};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+    const MESSAGE: &str = "PedersenOpeningTest";
+
+    type Pedersen64 = super::Pedersen64<EdwardsAffine>;
+
+    #[test]
+    fn test_opening() -> Result<()> {
+        let pedersen = Pedersen64::setup(MESSAGE);
+
+        for i in 0..ITERATIONS {
+            // Generate a commitment and its opening.
+            let message = (0..(i % 65)).map(|_| bool::rand(&mut test_rng())).collect::<Vec<_>>();
+            let opening = PedersenOpening::<EdwardsAffine>::new(message.clone(), Fr::rand(&mut test_rng()));
+            let commitment = opening.commit(&pedersen)?;
+            assert_eq!(pedersen.commit(&message, opening.randomizer())?, commitment);
+
+            // Serialize the opening, and deserialize it on the verifier side.
+            let bytes = opening.to_bytes_le()?;
+            let candidate = PedersenOpening::<EdwardsAffine>::read_le(&bytes[..])?;
+            assert_eq!(opening, candidate);
+            assert!(candidate.verify(&pedersen, &commitment));
+
+            let string = serde_json::to_string(&opening)?;
+            let candidate: PedersenOpening<EdwardsAffine> = serde_json::from_str(&string)?;
+            assert_eq!(opening, candidate);
+            assert!(candidate.verify(&pedersen, &commitment));
+
+            // Ensure verification fails on a tampered randomizer.
+            let tampered = PedersenOpening::new(candidate.message().to_vec(), *candidate.randomizer() + Fr::one());
+            assert!(!tampered.verify(&pedersen, &commitment));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_opening_invalid_bytes() -> Result<()> {
+        let opening = PedersenOpening::<EdwardsAffine>::new(vec![true, false, true], Fr::rand(&mut test_rng()));
+        let mut bytes = opening.to_bytes_le()?;
+
+        // Ensure non-zero padding bits are rejected.
+        bytes[2] |= 0b1000_0000;
+        assert!(PedersenOpening::<EdwardsAffine>::read_le(&bytes[..]).is_err());
+
+        // Ensure messages that exceed the maximum number of bits are rejected.
+        let opening =
+            PedersenOpening::<EdwardsAffine>::new(vec![false; u16::MAX as usize + 1], Fr::rand(&mut test_rng()));
+        assert!(opening.to_bytes_le().is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::{collections::HashMap, sync::Mutex};
+
+/// A wrapper around a Pedersen commitment scheme, which records the randomizer of each commitment,
+/// and returns an error if a randomizer is reused across commitments to different inputs.
+/// Reusing a randomizer breaks the hiding property of the commitments, so this is a developer-safety check.
+pub struct RandomizerReuseDetector<G: AffineCurve, const NUM_BITS: u8> {
+    /// The Pedersen commitment scheme.
+    pedersen: Pedersen<G, NUM_BITS>,
+    /// The input committed to with each randomizer.
+    randomizers: Mutex<HashMap<G::ScalarField, Vec<bool>>>,
+}
+
+impl<G: AffineCurve, const NUM_BITS: u8> RandomizerReuseDetector<G, NUM_BITS> {
+    /// Initializes a new reuse detector for the given Pedersen commitment scheme, with no recorded randomizers.
+    pub fn new(pedersen: Pedersen<G, NUM_BITS>) -> Self {
+        Self { pedersen, randomizers: Default::default() }
+    }
+
+    /// Returns the Pedersen commitment scheme.
+    pub fn pedersen(&self) -> &Pedersen<G, NUM_BITS> {
+        &self.pedersen
+    }
+
+    /// Records the randomizer of a commitment to the given input, and checks that the randomizer
+    /// was not previously used for a different input. Recomputing a commitment is not a reuse.
+    fn check_randomizer(&self, input: &[bool], randomizer: &G::ScalarField) -> Result<()> {
+        let mut randomizers = match self.randomizers.lock() {
+            Ok(randomizers) => randomizers,
+            Err(error) => error.into_inner(),
+        };
+        match randomizers.get(randomizer) {
+            Some(previous) if previous.as_slice() != input => {
+                bail!("Pedersen randomizer was reused across commitments to different inputs")
+            }
+            Some(_) => Ok(()),
+            None => {
+                randomizers.insert(*randomizer, input.to_vec());
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<G: AffineCurve, const NUM_BITS: u8> Commit for RandomizerReuseDetector<G, NUM_BITS> {
+    type Input = bool;
+    type Output = G::BaseField;
+    type Randomizer = G::ScalarField;
+
+    /// Returns the Pedersen commitment of the given input and randomizer as a field element,
+    /// or an error if the randomizer was previously used for a different input.
+    fn commit(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Result<Self::Output> {
+        self.check_randomizer(input, randomizer)?;
+        self.pedersen.commit(input, randomizer)
+    }
+}
+
+impl<G: AffineCurve, const NUM_BITS: u8> CommitUncompressed for RandomizerReuseDetector<G, NUM_BITS> {
+    type Input = bool;
+    type Output = G;
+    type Randomizer = G::ScalarField;
+
+    /// Returns the Pedersen commitment of the given input and randomizer as an affine group element,
+    /// or an error if the randomizer was previously used for a different input.
+    fn commit_uncompressed(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Result<Self::Output> {
+        self.check_randomizer(input, randomizer)?;
+        self.pedersen.commit_uncompressed(input, randomizer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::{EdwardsAffine, Fr};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const MESSAGE: &str = "PedersenRandomizerReuseTest";
+
+    type Pedersen64 = super::Pedersen64<EdwardsAffine>;
+
+    #[test]
+    fn test_randomizer_reuse() -> Result<()> {
+        let rng = &mut test_rng();
+
+        let first = (0..64).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+        let second = first.iter().map(|bit| !bit).collect::<Vec<_>>();
+        let randomizer = Fr::rand(rng);
+
+        // Ensure reuse is allowed by the Pedersen commitment itself.
+        let pedersen = Pedersen64::setup(MESSAGE);
+        let expected = pedersen.commit(&first, &randomizer)?;
+        pedersen.commit(&second, &randomizer)?;
+
+        // Ensure the detector rejects a reused randomizer.
+        let detector = RandomizerReuseDetector::new(pedersen);
+        assert_eq!(expected, detector.commit(&first, &randomizer)?);
+        assert!(detector.commit(&second, &randomizer).is_err());
+        assert!(detector.commit_uncompressed(&second, &randomizer).is_err());
+
+        // Ensure recomputing the commitment, i.e. to verify an opening, is not a reuse.
+        assert_eq!(expected, detector.commit(&first, &randomizer)?);
+        assert!(PedersenOpening::<EdwardsAffine>::new(first, randomizer).verify(detector.pedersen(), &expected));
+
+        // Ensure distinct randomizers succeed.
+        detector.commit(&second, &Fr::rand(rng))?;
+        detector.commit_uncompressed(&second, &Fr::rand(rng))?;
+        Ok(())
+    }
+}
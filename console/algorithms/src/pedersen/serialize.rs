@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<G: AffineCurve, const NUM_BITS: u8> Serialize for Pedersen<G, NUM_BITS> {
+    /// Serializes Pedersen into its setup message and precomputed windows.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut pedersen = serializer.serialize_struct("Pedersen", 3)?;
+        pedersen.serialize_field("message", &self.message)?;
+        pedersen.serialize_field("base_window", &self.base_window_affine())?;
+        pedersen.serialize_field("random_base_window", &self.random_base_window_affine())?;
+        pedersen.end()
+    }
+}
+
+impl<'de, G: AffineCurve, const NUM_BITS: u8> Deserialize<'de> for Pedersen<G, NUM_BITS> {
+    /// Deserializes Pedersen from its setup message, and optionally, its precomputed windows.
+    /// The windows are always rebuilt from the setup message, and any given windows must match them.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(bound = "G: AffineCurve")]
+        struct Parameters<G> {
+            message: String,
+            #[serde(default)]
+            base_window: Option<Vec<G>>,
+            #[serde(default)]
+            random_base_window: Option<Vec<G>>,
+        }
+
+        let parameters = Parameters::<G>::deserialize(deserializer)?;
+
+        // Rebuild the windows from the setup message.
+        let pedersen = Self::setup(&parameters.message);
+
+        // Ensure the given windows, if any, match the rebuilt windows.
+        if let Some(base_window) = parameters.base_window {
+            if base_window != pedersen.base_window_affine() {
+                return Err(de::Error::custom("Pedersen base window does not match the setup message"));
+            }
+        }
+        if let Some(random_base_window) = parameters.random_base_window {
+            if random_base_window != pedersen.random_base_window_affine() {
+                return Err(de::Error::custom("Pedersen random base window does not match the setup message"));
+            }
+        }
+        Ok(pedersen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::{EdwardsAffine, Fr};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 10;
+    const MESSAGE: &str = "PedersenSerdeTest";
+
+    type Pedersen64 = super::Pedersen64<EdwardsAffine>;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let expected = Pedersen64::setup(MESSAGE);
+
+        // Serialize
+        let candidate_string = serde_json::to_string(&expected)?;
+        let candidate_value = serde_json::from_str::<serde_json::Value>(&candidate_string)?;
+        assert_eq!(MESSAGE, candidate_value["message"].as_str().unwrap());
+        assert_eq!(64, candidate_value["base_window"].as_array().unwrap().len());
+
+        // Deserialize
+        let candidate: Pedersen64 = serde_json::from_str(&candidate_string)?;
+        assert_eq!(expected.message(), candidate.message());
+        assert_eq!(expected.base_window(), candidate.base_window());
+        assert_eq!(expected.random_base_window(), candidate.random_base_window());
+
+        // Ensure the reconstructed instance commits identically.
+        for _ in 0..ITERATIONS {
+            let input = (0..64).map(|_| bool::rand(&mut test_rng())).collect::<Vec<_>>();
+            let randomizer = Fr::rand(&mut test_rng());
+            assert_eq!(expected.commit(&input, &randomizer)?, candidate.commit(&input, &randomizer)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_json_from_message() -> Result<()> {
+        let expected = Pedersen64::setup(MESSAGE);
+
+        // Deserialize from only the setup message, which rebuilds the windows.
+        let candidate: Pedersen64 = serde_json::from_str(&format!("{{\"message\":\"{MESSAGE}\"}}"))?;
+        assert_eq!(expected.base_window(), candidate.base_window());
+        assert_eq!(expected.random_base_window(), candidate.random_base_window());
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_json_mismatched_windows() -> Result<()> {
+        // Serialize the windows of one instance, under the setup message of another.
+        let mut candidate_value = serde_json::to_value(Pedersen64::setup(MESSAGE))?;
+        candidate_value["message"] = serde_json::Value::from("PedersenSerdeTestOther");
+
+        // Ensure deserialization fails.
+        match serde_json::from_value::<Pedersen64>(candidate_value) {
+            Ok(_) => panic!("Deserialized Pedersen with mismatched windows"),
+            Err(error) => assert!(error.to_string().contains("does not match the setup message")),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<F: PrimeField, const RATE: usize> Poseidon<F, RATE> {
+    /// Returns the authenticated encryption of the given plaintext, under the given key and nonce.
+    /// The output is the ciphertext, followed by a single field element for the authentication tag.
+    ///
+    /// Note: The nonce must not be reused for the same key.
+    pub fn encrypt(&self, key: F, nonce: F, plaintext: &[F]) -> Result<Vec<F>> {
+        // Initialize the sponge, and squeeze the keystream.
+        let (mut sponge, keystream) = self.prepare_keystream(key, nonce, plaintext.len())?;
+
+        // Compute the ciphertext, by adding the keystream to the plaintext.
+        let mut ciphertext = plaintext
+            .iter()
+            .zip_eq(keystream.iter())
+            .map(|(plaintext, keystream)| *plaintext + keystream)
+            .collect::<Vec<_>>();
+
+        // Absorb the ciphertext, and squeeze the authentication tag.
+        sponge.try_absorb(&ciphertext)?;
+        ciphertext.push(sponge.squeeze(1)[0]);
+        Ok(ciphertext)
+    }
+
+    /// Returns the plaintext of the given authenticated ciphertext, under the given key and nonce.
+    /// This method returns an error if the authentication tag does not match the ciphertext.
+    pub fn decrypt(&self, key: F, nonce: F, ciphertext: &[F]) -> Result<Vec<F>> {
+        // Split the ciphertext and the authentication tag.
+        let (tag, ciphertext) = match ciphertext.split_last() {
+            Some((tag, ciphertext)) => (tag, ciphertext),
+            None => bail!("Failed to decrypt: the ciphertext is missing an authentication tag"),
+        };
+
+        // Initialize the sponge, and squeeze the keystream.
+        let (mut sponge, keystream) = self.prepare_keystream(key, nonce, ciphertext.len())?;
+
+        // Absorb the ciphertext, and ensure the authentication tag matches, in constant time.
+        sponge.try_absorb(ciphertext)?;
+        if !Self::is_equal_constant_time(&sponge.squeeze(1)[0], tag)? {
+            bail!("Failed to decrypt: the authentication tag is invalid")
+        }
+
+        // Compute the plaintext, by subtracting the keystream from the ciphertext.
+        Ok(ciphertext.iter().zip_eq(keystream.iter()).map(|(ciphertext, keystream)| *ciphertext - keystream).collect())
+    }
+
+    /// Initializes a sponge with the given key and nonce, and returns the sponge with the squeezed keystream.
+    fn prepare_keystream(
+        &self,
+        key: F,
+        nonce: F,
+        num_fields: usize,
+    ) -> Result<(PoseidonSponge<F, RATE, CAPACITY>, Vec<F>)> {
+        // Ensure the number of field elements is within the keystream size.
+        let num_fields = match u16::try_from(num_fields) {
+            Ok(num_fields) => num_fields,
+            Err(_) => bail!("Poseidon encryption supports at most {} field elements, found {num_fields}", u16::MAX),
+        };
+
+        // Ensure the rate is large enough to hold the domain and the length.
+        let num_padding = match RATE.checked_sub(2) {
+            Some(num_padding) => num_padding,
+            None => bail!("Poseidon encryption requires a rate of at least 2, found {RATE}"),
+        };
+
+        // Construct the preimage: [ DOMAIN || LENGTH(INPUT) || [0; RATE-2] || KEY || NONCE ].
+        let mut preimage = Vec::with_capacity(RATE + 2);
+        preimage.push(self.domain);
+        preimage.push(F::from(num_fields as u128));
+        preimage.extend(&vec![F::zero(); num_padding]); // Pad up to RATE.
+        preimage.push(key);
+        preimage.push(nonce);
+
+        // Absorb the preimage, and squeeze the keystream.
+        let mut sponge = PoseidonSponge::<F, RATE, CAPACITY>::new(&self.parameters);
+        sponge.try_absorb(&preimage)?;
+        let keystream = sponge.squeeze(num_fields).to_vec();
+        Ok((sponge, keystream))
+    }
+
+    /// Returns `true` if the given field elements are equal, without branching on their bytes.
+    fn is_equal_constant_time(first: &F, second: &F) -> Result<bool> {
+        let first = first.to_bytes_le()?;
+        let second = second.to_bytes_le()?;
+        ensure!(first.len() == second.len(), "Mismatching field element sizes in the authentication tag");
+        Ok(first.iter().zip_eq(&second).fold(0u8, |difference, (a, b)| difference | (a ^ b)) == 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::Fq;
+    use snarkvm_fields::One;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    fn check_encrypt_and_decrypt<const RATE: usize>() -> Result<()> {
+        let poseidon = Poseidon::<Fq, RATE>::setup("PoseidonEncryptionTest")?;
+
+        for i in 0..ITERATIONS {
+            // Sample a random key, nonce, and plaintext.
+            let key = Fq::rand(&mut test_rng());
+            let nonce = Fq::rand(&mut test_rng());
+            let plaintext = (0..i).map(|_| Fq::rand(&mut test_rng())).collect::<Vec<_>>();
+
+            // Encrypt the plaintext.
+            let ciphertext = poseidon.encrypt(key, nonce, &plaintext)?;
+            assert_eq!(plaintext.len() + 1, ciphertext.len());
+            if i > 0 {
+                assert_ne!(plaintext, ciphertext[..i]);
+            }
+
+            // Decrypt the ciphertext.
+            let candidate = poseidon.decrypt(key, nonce, &ciphertext)?;
+            assert_eq!(plaintext, candidate);
+
+            // Ensure decryption fails with a different key or nonce.
+            assert!(poseidon.decrypt(Fq::rand(&mut test_rng()), nonce, &ciphertext).is_err());
+            assert!(poseidon.decrypt(key, Fq::rand(&mut test_rng()), &ciphertext).is_err());
+        }
+        Ok(())
+    }
+
+    fn check_tampered_ciphertext<const RATE: usize>() -> Result<()> {
+        let poseidon = Poseidon::<Fq, RATE>::setup("PoseidonEncryptionTest")?;
+
+        for i in 1..ITERATIONS {
+            // Sample a random key, nonce, and plaintext.
+            let key = Fq::rand(&mut test_rng());
+            let nonce = Fq::rand(&mut test_rng());
+            let plaintext = (0..i).map(|_| Fq::rand(&mut test_rng())).collect::<Vec<_>>();
+
+            // Encrypt the plaintext.
+            let ciphertext = poseidon.encrypt(key, nonce, &plaintext)?;
+
+            // Tamper with each field element of the ciphertext, including the authentication tag.
+            for j in 0..ciphertext.len() {
+                let mut tampered = ciphertext.clone();
+                tampered[j] += Fq::one();
+                let error = poseidon.decrypt(key, nonce, &tampered).unwrap_err();
+                assert_eq!("Failed to decrypt: the authentication tag is invalid", error.to_string());
+            }
+
+            // Ensure a truncated ciphertext fails to decrypt.
+            assert!(poseidon.decrypt(key, nonce, &ciphertext[1..]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_encrypt_and_decrypt() -> Result<()> {
+        check_encrypt_and_decrypt::<2>()?;
+        check_encrypt_and_decrypt::<4>()?;
+        check_encrypt_and_decrypt::<8>()
+    }
+
+    #[test]
+    fn test_tampered_ciphertext() -> Result<()> {
+        check_tampered_ciphertext::<2>()?;
+        check_tampered_ciphertext::<4>()?;
+        check_tampered_ciphertext::<8>()
+    }
+
+    #[test]
+    fn test_decrypt_missing_tag() -> Result<()> {
+        let poseidon = Poseidon::<Fq, 2>::setup("PoseidonEncryptionTest")?;
+        let error = poseidon.decrypt(Fq::one(), Fq::one(), &[]).unwrap_err();
+        assert_eq!("Failed to decrypt: the ciphertext is missing an authentication tag", error.to_string());
+        Ok(())
+    }
+}
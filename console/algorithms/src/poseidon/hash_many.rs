@@ -22,6 +22,10 @@ impl<F: PrimeField, const RATE: usize> HashMany for Poseidon<F, RATE> {
 
     /// Returns the cryptographic hash for a list of field elements as input,
     /// and returns the specified number of field elements as output.
+    ///
+    /// The outputs are squeezed `RATE` elements at a time, with a permutation between each block,
+    /// and a partial final block is truncated to `num_outputs`. As such, the outputs for `num_outputs`
+    /// are a prefix of the outputs for `num_outputs + 1`. This matches the circuit `hash_many`.
     #[inline]
     fn hash_many(&self, input: &[Self::Input], num_outputs: u16) -> Vec<Self::Output> {
         // Construct the preimage: [ DOMAIN || LENGTH(INPUT) || [0; RATE-2] || INPUT ].
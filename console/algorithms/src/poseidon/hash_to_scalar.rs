@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use snarkvm_utilities::FromBits;
+use crate::ToScalarReduced;
 
 impl<F: PrimeField, const RATE: usize> HashToScalar for Poseidon<F, RATE> {
     type Input = F;
@@ -24,23 +24,7 @@ impl<F: PrimeField, const RATE: usize> HashToScalar for Poseidon<F, RATE> {
     /// This method uses truncation (up to data bits) to project onto the scalar field.
     #[inline]
     fn hash_to_scalar<Scalar: PrimeField>(&self, input: &[Self::Input]) -> Result<Scalar> {
-        // Note: We are reconstituting the base field into a scalar field.
-        // This is safe as the scalar field modulus is less than the base field modulus,
-        // and thus will always fit within a single base field element.
-        debug_assert!(Scalar::size_in_bits() < F::size_in_bits());
-
-        // Hash the input to the base field.
-        let output = self.hash(input)?;
-
-        // Truncate the output to the size in data bits (1 bit less than the MODULUS) of the scalar.
-        // Slicing here is safe as the base field is larger than the scalar field.
-        let bits = &output.to_bits_le()[..Scalar::size_in_data_bits()];
-
-        // Output the scalar field.
-        match Scalar::from_repr(Scalar::BigInteger::from_bits_le(bits)?) {
-            // We know this case will always work, because we truncate the output to CAPACITY bits in the scalar field.
-            Some(scalar) => Ok(scalar),
-            _ => bail!("Failed to hash input into scalar field"),
-        }
+        // Hash the input to the base field, and reduce the output into the scalar field.
+        self.hash(input)?.to_scalar_reduced()
     }
 }
@@ -15,14 +15,18 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod sponge;
-pub(super) use sponge::*;
+pub use sponge::*;
 
 mod state;
 pub(super) use state::*;
 
 use snarkvm_fields::PrimeField;
 
-use core::fmt::Debug;
+use anyhow::{anyhow, bail, Error, Result};
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    str::FromStr,
+};
 use smallvec::SmallVec;
 
 /// The interface for a cryptographic sponge.
@@ -40,6 +44,9 @@ pub trait AlgebraicSponge<F: PrimeField, const RATE: usize, const CAPACITY: usiz
 
     /// Squeeze `num_elements` field elements from the sponge.
     fn squeeze(&mut self, num_elements: u16) -> SmallVec<[F; 10]>;
+
+    /// Resets the sponge to its initial state, retaining its parameters.
+    fn reset(&mut self);
 }
 
 /// The mode structure for duplex sponges.
@@ -56,3 +63,29 @@ pub enum DuplexSpongeMode {
         next_squeeze_index: usize,
     },
 }
+
+impl FromStr for DuplexSpongeMode {
+    type Err = Error;
+
+    /// Parses a string of the form `absorbing@{index}` or `squeezing@{index}` into a sponge mode.
+    fn from_str(string: &str) -> Result<Self> {
+        let (mode, index) =
+            string.split_once('@').ok_or_else(|| anyhow!("Invalid sponge mode '{string}', expected 'mode@index'"))?;
+        let index = index.parse::<usize>().map_err(|_| anyhow!("Invalid index in sponge mode '{string}'"))?;
+        match mode {
+            "absorbing" => Ok(Self::Absorbing { next_absorb_index: index }),
+            "squeezing" => Ok(Self::Squeezing { next_squeeze_index: index }),
+            _ => bail!("Invalid sponge mode '{string}', expected 'absorbing' or 'squeezing'"),
+        }
+    }
+}
+
+impl Display for DuplexSpongeMode {
+    /// Prints the sponge mode as `absorbing@{index}` or `squeezing@{index}`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Absorbing { next_absorb_index } => write!(f, "absorbing@{next_absorb_index}"),
+            Self::Squeezing { next_squeeze_index } => write!(f, "squeezing@{next_squeeze_index}"),
+        }
+    }
+}
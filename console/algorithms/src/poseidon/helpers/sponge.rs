@@ -20,6 +20,7 @@ use crate::poseidon::{
 };
 use snarkvm_fields::{PoseidonParameters, PrimeField};
 
+use anyhow::{bail, Result};
 use smallvec::SmallVec;
 use std::sync::Arc;
 
@@ -37,6 +38,12 @@ pub struct PoseidonSponge<F: PrimeField, const RATE: usize, const CAPACITY: usiz
     state: State<F, RATE, CAPACITY>,
     /// Current mode (whether its absorbing or squeezing)
     pub(in crate::poseidon) mode: DuplexSpongeMode,
+    /// The log of absorbed elements, if recording is enabled
+    absorbed_log: Option<Vec<F>>,
+    /// The maximum number of elements that may be absorbed, if limited
+    max_absorb_len: Option<usize>,
+    /// The number of elements absorbed since initialization (or since the last reset)
+    num_absorbed: usize,
 }
 
 impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> AlgebraicSponge<F, RATE, CAPACITY>
@@ -49,23 +56,34 @@ impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> AlgebraicSponge<F,
             parameters: parameters.clone(),
             state: State::default(),
             mode: DuplexSpongeMode::Absorbing { next_absorb_index: 0 },
+            absorbed_log: None,
+            max_absorb_len: None,
+            num_absorbed: 0,
         }
     }
 
+    /// Absorbs the input into the sponge, without checking the maximum absorb length.
+    /// Use `Self::try_absorb` to enforce the limit set by `Self::set_max_absorb_len`.
     fn absorb(&mut self, input: &[F]) {
-        if !input.is_empty() {
-            match self.mode {
-                DuplexSpongeMode::Absorbing { mut next_absorb_index } => {
-                    if next_absorb_index == RATE {
-                        self.permute();
-                        next_absorb_index = 0;
-                    }
-                    self.absorb_internal(next_absorb_index, input);
-                }
-                DuplexSpongeMode::Squeezing { next_squeeze_index: _ } => {
+        if input.is_empty() {
+            return;
+        }
+        self.num_absorbed = self.num_absorbed.saturating_add(input.len());
+
+        if let Some(log) = &mut self.absorbed_log {
+            log.extend_from_slice(input);
+        }
+        match self.mode {
+            DuplexSpongeMode::Absorbing { mut next_absorb_index } => {
+                if next_absorb_index == RATE {
                     self.permute();
-                    self.absorb_internal(0, input);
+                    next_absorb_index = 0;
                 }
+                self.absorb_internal(next_absorb_index, input);
+            }
+            DuplexSpongeMode::Squeezing { next_squeeze_index: _ } => {
+                self.permute();
+                self.absorb_internal(0, input);
             }
         }
     }
@@ -97,6 +115,80 @@ impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> AlgebraicSponge<F,
         output.truncate(num_elements as usize);
         output
     }
+
+    fn reset(&mut self) {
+        self.state = State::default();
+        self.mode = DuplexSpongeMode::Absorbing { next_absorb_index: 0 };
+        self.num_absorbed = 0;
+        if let Some(log) = &mut self.absorbed_log {
+            log.clear();
+        }
+    }
+}
+
+impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> PoseidonSponge<F, RATE, CAPACITY> {
+    /// Enables or disables recording of absorbed elements, for transcript auditing.
+    /// Disabling recording discards any elements logged so far.
+    pub fn set_record_absorbs(&mut self, record_absorbs: bool) {
+        if !record_absorbs {
+            self.absorbed_log = None;
+        } else if self.absorbed_log.is_none() {
+            self.absorbed_log = Some(Vec::new());
+        }
+    }
+
+    /// Returns the elements absorbed since recording was enabled (or since the last reset).
+    /// If recording is disabled, this returns an empty slice.
+    pub fn absorbed_log(&self) -> &[F] {
+        self.absorbed_log.as_deref().unwrap_or(&[])
+    }
+
+    /// Sets the maximum number of elements that may be absorbed, in total, until the next reset.
+    /// By default, the number of absorbed elements is unbounded.
+    /// Note: The limit is only enforced by `Self::try_absorb` and `Self::absorb_bytes`.
+    pub fn set_max_absorb_len(&mut self, max_absorb_len: Option<usize>) {
+        self.max_absorb_len = max_absorb_len;
+    }
+
+    /// Absorbs the input into the sponge, or returns an error (without absorbing)
+    /// if doing so would exceed the maximum absorb length.
+    pub fn try_absorb(&mut self, input: &[F]) -> Result<()> {
+        if input.is_empty() {
+            return Ok(());
+        }
+
+        // Ensure the input does not exceed the maximum absorb length.
+        if let Some(max_absorb_len) = self.max_absorb_len {
+            if self.num_absorbed.saturating_add(input.len()) > max_absorb_len {
+                bail!(
+                    "Failed to absorb {} elements, as it exceeds the maximum absorb length of {max_absorb_len}",
+                    input.len()
+                )
+            }
+        }
+
+        self.absorb(input);
+        Ok(())
+    }
+
+    /// Absorbs the given bytes into the sponge, packed into field elements by `Self::pack_bytes`,
+    /// or returns an error (without absorbing) if doing so would exceed the maximum absorb length.
+    pub fn absorb_bytes(&mut self, bytes: &[u8]) -> Result<()> {
+        self.try_absorb(&Self::pack_bytes(bytes))
+    }
+
+    /// Packs the given bytes into field elements as `[ LENGTH(BYTES) || BYTES[0..N] || BYTES[N..2N] || ... ]`,
+    /// where `N = F::size_in_data_bits() / 8`, and each chunk of bytes is read as a **little-endian** integer.
+    /// The leading length ensures inputs of different lengths (i.e. with trailing zeros) do not collide.
+    pub fn pack_bytes(bytes: &[u8]) -> Vec<F> {
+        let num_bytes_per_field = F::size_in_data_bits() / 8;
+        let mut fields = Vec::with_capacity(1 + bytes.len() / num_bytes_per_field + 1);
+        fields.push(F::from(bytes.len() as u128));
+        fields.extend(bytes.chunks(num_bytes_per_field).map(|chunk| {
+            chunk.iter().rev().fold(F::zero(), |acc, byte| acc * F::from(256u128) + F::from(*byte as u128))
+        }));
+        fields
+    }
 }
 
 impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> PoseidonSponge<F, RATE, CAPACITY> {
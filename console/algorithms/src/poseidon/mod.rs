@@ -15,7 +15,9 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod helpers;
+pub use helpers::{AlgebraicSponge, DuplexSpongeMode, PoseidonSponge};
 
+mod encrypt;
 mod hash;
 mod hash_many;
 mod hash_to_scalar;
@@ -25,6 +27,7 @@ use crate::{poseidon::helpers::*, Hash, HashMany, HashToScalar, PRF};
 use snarkvm_fields::{PoseidonParameters, PrimeField};
 
 use anyhow::{bail, ensure, Result};
+use itertools::Itertools;
 use std::sync::Arc;
 
 const CAPACITY: usize = 1;
@@ -58,6 +61,41 @@ impl<F: PrimeField, const RATE: usize> Poseidon<F, RATE> {
         })
     }
 
+    /// Initializes a new instance of Poseidon from the given custom parameters.
+    /// This is intended for experimenting with custom round constants and MDS matrices.
+    pub fn from_parameters(
+        domain: &str,
+        full_rounds: usize,
+        partial_rounds: usize,
+        alpha: u64,
+        ark: Vec<Vec<F>>,
+        mds: Vec<Vec<F>>,
+    ) -> Result<Self> {
+        // Ensure the given domain is within the allowed size in bits.
+        let num_bits = domain.len().saturating_mul(8);
+        let max_bits = F::size_in_data_bits();
+        ensure!(num_bits <= max_bits, "Domain cannot exceed {max_bits} bits, found {num_bits} bits");
+
+        // Ensure the additive round keys are of size `(full_rounds + partial_rounds) x (RATE + CAPACITY)`.
+        let num_rounds = full_rounds + partial_rounds;
+        let width = RATE + CAPACITY;
+        ensure!(ark.len() == num_rounds, "Expected {num_rounds} rows of round keys, found {} rows", ark.len());
+        for (i, row) in ark.iter().enumerate() {
+            ensure!(row.len() == width, "Expected {width} round keys in row {i}, found {}", row.len());
+        }
+
+        // Ensure the MDS matrix is of size `(RATE + CAPACITY) x (RATE + CAPACITY)`.
+        ensure!(mds.len() == width, "Expected {width} rows in the MDS matrix, found {} rows", mds.len());
+        for (i, row) in mds.iter().enumerate() {
+            ensure!(row.len() == width, "Expected {width} columns in row {i} of the MDS matrix, found {}", row.len());
+        }
+
+        Ok(Self {
+            domain: F::from_bytes_be_mod_order(domain.as_bytes()),
+            parameters: Arc::new(PoseidonParameters { full_rounds, partial_rounds, alpha, ark, mds }),
+        })
+    }
+
     /// Returns the domain separator for the hash function.
     pub fn domain(&self) -> F {
         self.domain
@@ -67,6 +105,16 @@ impl<F: PrimeField, const RATE: usize> Poseidon<F, RATE> {
     pub fn parameters(&self) -> &Arc<PoseidonParameters<F, RATE, CAPACITY>> {
         &self.parameters
     }
+
+    /// Returns the input rate of the Poseidon sponge.
+    pub const fn rate(&self) -> usize {
+        RATE
+    }
+
+    /// Returns the capacity of the Poseidon sponge.
+    pub const fn capacity(&self) -> usize {
+        CAPACITY
+    }
 }
 
 #[cfg(test)]
@@ -75,7 +123,7 @@ mod tests {
     use snarkvm_curves::edwards_bls12::Fq;
     use snarkvm_fields::{PoseidonDefaultField, PoseidonGrainLFSR};
 
-    use core::fmt::Debug;
+    use core::{fmt::Debug, str::FromStr};
     use std::{path::PathBuf, sync::Arc};
 
     /// Returns the path to the `resources` folder for this module.
@@ -153,6 +201,266 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sponge_reset() {
+        const RATE: usize = 2;
+        let parameters = Arc::new(Fq::default_poseidon_parameters::<RATE>().unwrap());
+
+        for absorb in 0..10 {
+            for squeeze in 1..10 {
+                let first_input = vec![Fq::from(1237812u64); absorb];
+                let second_input = vec![Fq::from(8127321u64); absorb + 1];
+
+                // Hash the first input.
+                let mut sponge = PoseidonSponge::<Fq, RATE, CAPACITY>::new(&parameters);
+                sponge.absorb(&first_input);
+                let first = sponge.squeeze(squeeze);
+
+                // Reset the sponge, and ensure it is in its initial mode.
+                sponge.reset();
+                assert_eq!(sponge.mode, DuplexSpongeMode::Absorbing { next_absorb_index: 0 });
+
+                // Hash the second input, and ensure it matches a fresh sponge.
+                sponge.absorb(&second_input);
+                let second = sponge.squeeze(squeeze);
+
+                let mut fresh = PoseidonSponge::<Fq, RATE, CAPACITY>::new(&parameters);
+                fresh.absorb(&second_input);
+                assert_eq!(second, fresh.squeeze(squeeze));
+
+                // Reset the sponge, and ensure the first hash is reproduced.
+                sponge.reset();
+                sponge.absorb(&first_input);
+                assert_eq!(first, sponge.squeeze(squeeze));
+            }
+        }
+    }
+
+    #[test]
+    fn test_sponge_absorbed_log() {
+        const RATE: usize = 2;
+        let parameters = Arc::new(Fq::default_poseidon_parameters::<RATE>().unwrap());
+
+        for num_inputs in 0..10 {
+            let first_input = (0..num_inputs).map(|i| Fq::from(i as u64)).collect::<Vec<_>>();
+            let second_input = vec![Fq::from(8127321u64); num_inputs + 1];
+
+            // Hash with recording disabled.
+            let mut plain = PoseidonSponge::<Fq, RATE, CAPACITY>::new(&parameters);
+            assert!(plain.absorbed_log().is_empty());
+            plain.absorb(&first_input);
+            let first = plain.squeeze(3);
+            plain.absorb(&second_input);
+            let second = plain.squeeze(3);
+            assert!(plain.absorbed_log().is_empty());
+
+            // Hash with recording enabled, and ensure the output is unchanged.
+            let mut recorded = PoseidonSponge::<Fq, RATE, CAPACITY>::new(&parameters);
+            recorded.set_record_absorbs(true);
+            recorded.absorb(&first_input);
+            assert_eq!(first, recorded.squeeze(3));
+            assert_eq!(first_input, recorded.absorbed_log());
+            recorded.absorb(&second_input);
+            assert_eq!(second, recorded.squeeze(3));
+
+            // Ensure the log matches the absorbed sequence.
+            let expected = first_input.iter().chain(&second_input).copied().collect::<Vec<_>>();
+            assert_eq!(expected, recorded.absorbed_log());
+
+            // Ensure a reset clears the log, but keeps recording enabled.
+            recorded.reset();
+            assert!(recorded.absorbed_log().is_empty());
+            recorded.absorb(&second_input);
+            assert_eq!(second_input, recorded.absorbed_log());
+
+            // Ensure disabling recording discards the log.
+            recorded.set_record_absorbs(false);
+            assert!(recorded.absorbed_log().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_sponge_max_absorb_len() {
+        const RATE: usize = 2;
+        let parameters = Arc::new(Fq::default_poseidon_parameters::<RATE>().unwrap());
+
+        let input = (0..10).map(|i| Fq::from(i as u64)).collect::<Vec<_>>();
+
+        // Hash with the default unbounded sponge.
+        let mut unbounded = PoseidonSponge::<Fq, RATE, CAPACITY>::new(&parameters);
+        unbounded.absorb(&input);
+        unbounded.absorb(&input);
+        let expected = unbounded.squeeze(3);
+
+        // Ensure absorbing up to the limit matches the unbounded sponge.
+        let mut bounded = PoseidonSponge::<Fq, RATE, CAPACITY>::new(&parameters);
+        bounded.set_max_absorb_len(Some(2 * input.len()));
+        bounded.try_absorb(&input).unwrap();
+        bounded.try_absorb(&input).unwrap();
+        assert_eq!(expected, bounded.squeeze(3));
+
+        // Ensure exceeding the limit fails, and leaves the sponge unchanged.
+        let mut candidate = bounded.clone();
+        assert!(candidate.try_absorb(&input[..1]).is_err());
+        assert_eq!(bounded.squeeze(3), candidate.squeeze(3));
+
+        // Ensure a reset restores the limit.
+        bounded.reset();
+        bounded.try_absorb(&input).unwrap();
+        bounded.try_absorb(&input).unwrap();
+        assert!(bounded.try_absorb(&input[..1]).is_err());
+
+        // Ensure removing the limit restores the unbounded behavior.
+        bounded.set_max_absorb_len(None);
+        bounded.try_absorb(&input).unwrap();
+    }
+
+    #[test]
+    fn test_sponge_max_absorb_len_errors() {
+        const RATE: usize = 2;
+        let parameters = Arc::new(Fq::default_poseidon_parameters::<RATE>().unwrap());
+
+        // Ensure absorbing beyond the limit returns an error, instead of panicking.
+        let mut sponge = PoseidonSponge::<Fq, RATE, CAPACITY>::new(&parameters);
+        sponge.set_max_absorb_len(Some(1));
+        let error = sponge.try_absorb(&[Fq::from(1u64), Fq::from(2u64)]).unwrap_err();
+        assert!(error.to_string().contains("exceeds the maximum absorb length"));
+        assert!(sponge.absorb_bytes(&[1u8]).is_err());
+    }
+
+    #[test]
+    fn test_sponge_absorb_bytes() {
+        const RATE: usize = 2;
+        let parameters = Arc::new(Fq::default_poseidon_parameters::<RATE>().unwrap());
+
+        let squeeze_bytes = |bytes: &[u8]| {
+            let mut sponge = PoseidonSponge::<Fq, RATE, CAPACITY>::new(&parameters);
+            sponge.absorb_bytes(bytes).unwrap();
+            sponge.squeeze(1)[0]
+        };
+
+        // Ensure absorbing bytes is equivalent to absorbing the packed field elements.
+        let bytes = (0..100u8).collect::<Vec<_>>();
+        let mut sponge = PoseidonSponge::<Fq, RATE, CAPACITY>::new(&parameters);
+        sponge.absorb(&PoseidonSponge::<Fq, RATE, CAPACITY>::pack_bytes(&bytes));
+        assert_eq!(sponge.squeeze(1)[0], squeeze_bytes(&bytes));
+
+        // Ensure the packing is little-endian and prefixed with the length.
+        let packed = PoseidonSponge::<Fq, RATE, CAPACITY>::pack_bytes(&[1, 2]);
+        assert_eq!(vec![Fq::from(2u64), Fq::from(0x0201u64)], packed);
+        // Ensure the bytes are split into chunks of `size_in_data_bits / 8` bytes.
+        let num_bytes_per_field = Fq::size_in_data_bits() / 8;
+        assert_eq!(1, PoseidonSponge::<Fq, RATE, CAPACITY>::pack_bytes(&[]).len());
+        assert_eq!(2, PoseidonSponge::<Fq, RATE, CAPACITY>::pack_bytes(&vec![0xff; num_bytes_per_field]).len());
+        assert_eq!(3, PoseidonSponge::<Fq, RATE, CAPACITY>::pack_bytes(&vec![0xff; num_bytes_per_field + 1]).len());
+
+        // Ensure inputs that only differ in their length (i.e. trailing zeros) produce different digests.
+        assert_ne!(squeeze_bytes(&[]), squeeze_bytes(&[0u8]));
+        assert_ne!(squeeze_bytes(&[0u8; 3]), squeeze_bytes(&[0u8; 4]));
+        assert_ne!(squeeze_bytes(&[1u8, 2]), squeeze_bytes(&[1u8, 2, 0]));
+    }
+
+    #[test]
+    fn test_rate_and_capacity() -> Result<()> {
+        let poseidon = Poseidon4::<Fq>::setup("PoseidonRateTest")?;
+        assert_eq!(4, poseidon.rate());
+        assert_eq!(1, poseidon.capacity());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_parameters() -> Result<()> {
+        let expected = Poseidon2::<Fq>::setup("PoseidonCustomTest")?;
+        let parameters = expected.parameters();
+
+        // Ensure a valid custom instance matches the default instance.
+        let candidate = Poseidon2::<Fq>::from_parameters(
+            "PoseidonCustomTest",
+            parameters.full_rounds,
+            parameters.partial_rounds,
+            parameters.alpha,
+            parameters.ark.clone(),
+            parameters.mds.clone(),
+        )?;
+        let input = (0..5).map(|i| Fq::from(i as u64)).collect::<Vec<_>>();
+        assert_eq!(expected.hash(&input)?, candidate.hash(&input)?);
+
+        // Ensure a custom MDS matrix changes the output.
+        let mut mds = parameters.mds.clone();
+        mds[0][0] += Fq::from(1u64);
+        let candidate = Poseidon2::<Fq>::from_parameters(
+            "PoseidonCustomTest",
+            parameters.full_rounds,
+            parameters.partial_rounds,
+            parameters.alpha,
+            parameters.ark.clone(),
+            mds,
+        )?;
+        assert_ne!(expected.hash(&input)?, candidate.hash(&input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_parameters_rejects_malformed() -> Result<()> {
+        let parameters = Poseidon2::<Fq>::setup("PoseidonCustomTest")?.parameters().clone();
+        let from_parameters = |ark: Vec<Vec<Fq>>, mds: Vec<Vec<Fq>>| {
+            Poseidon2::<Fq>::from_parameters(
+                "PoseidonCustomTest",
+                parameters.full_rounds,
+                parameters.partial_rounds,
+                parameters.alpha,
+                ark,
+                mds,
+            )
+        };
+
+        // Ensure the unmodified parameters are accepted.
+        assert!(from_parameters(parameters.ark.clone(), parameters.mds.clone()).is_ok());
+
+        // Ensure a missing round of round keys is rejected.
+        let mut ark = parameters.ark.clone();
+        ark.pop();
+        assert!(from_parameters(ark, parameters.mds.clone()).is_err());
+
+        // Ensure a short row of round keys is rejected.
+        let mut ark = parameters.ark.clone();
+        ark[3].pop();
+        assert!(from_parameters(ark, parameters.mds.clone()).is_err());
+
+        // Ensure an MDS matrix with a missing row is rejected.
+        let mut mds = parameters.mds.clone();
+        mds.pop();
+        assert!(from_parameters(parameters.ark.clone(), mds).is_err());
+
+        // Ensure an MDS matrix with an extra column is rejected.
+        let mut mds = parameters.mds.clone();
+        mds[1].push(Fq::from(1u64));
+        assert!(from_parameters(parameters.ark.clone(), mds).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sponge_mode_string() -> Result<()> {
+        for index in [0, 1, 7] {
+            for mode in [
+                DuplexSpongeMode::Absorbing { next_absorb_index: index },
+                DuplexSpongeMode::Squeezing { next_squeeze_index: index },
+            ] {
+                let candidate = DuplexSpongeMode::from_str(&mode.to_string())?;
+                assert_eq!(mode, candidate);
+            }
+        }
+        assert_eq!("absorbing@2", DuplexSpongeMode::Absorbing { next_absorb_index: 2 }.to_string());
+        assert_eq!("squeezing@3", DuplexSpongeMode::Squeezing { next_squeeze_index: 3 }.to_string());
+
+        // Ensure malformed strings are rejected.
+        assert!(DuplexSpongeMode::from_str("chewing@2").is_err());
+        assert!(DuplexSpongeMode::from_str("absorbing").is_err());
+        assert!(DuplexSpongeMode::from_str("absorbing@").is_err());
+        assert!(DuplexSpongeMode::from_str("squeezing@-1").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_parameters() {
         fn single_rate_test<const RATE: usize>() {
@@ -15,8 +15,9 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::FromBits;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 
 /// A trait for a commitment scheme.
 pub trait Commit {
@@ -28,6 +29,12 @@ pub trait Commit {
     fn commit(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Result<Self::Output>;
 }
 
+/// A trait for a commitment scheme that verifies openings, allowing generic code to accept any commitment backend.
+pub trait CommitmentScheme: Commit {
+    /// Returns `true` if the given commitment opens to the given input and randomizer.
+    fn verify(&self, input: &[Self::Input], randomizer: &Self::Randomizer, commitment: &Self::Output) -> Result<bool>;
+}
+
 /// A trait for a commitment scheme.
 pub trait CommitUncompressed {
     type Input;
@@ -82,3 +89,74 @@ pub trait PRF {
     /// Returns the output for the given seed and input.
     fn prf(&self, seed: &Self::Seed, input: &[Self::Input]) -> Result<Self::Output>;
 }
+
+/// A trait for reducing a base field element into the scalar field.
+pub trait ToScalarReduced {
+    /// Returns the scalar field element from truncating `self` to the size in data bits of the scalar field.
+    fn to_scalar_reduced<Scalar: PrimeField>(&self) -> Result<Scalar>;
+}
+
+impl<F: PrimeField> ToScalarReduced for F {
+    /// Returns the scalar field element from truncating `self` to the size in data bits of the scalar field.
+    /// This is the canonical projection from the base field onto the scalar field, used by `hash_to_scalar`.
+    fn to_scalar_reduced<Scalar: PrimeField>(&self) -> Result<Scalar> {
+        // Note: We are reconstituting the base field into a scalar field.
+        // This is safe as the scalar field modulus is less than the base field modulus,
+        // and thus will always fit within a single base field element.
+        debug_assert!(Scalar::size_in_bits() < F::size_in_bits());
+
+        // Truncate `self` to the size in data bits (1 bit less than the MODULUS) of the scalar.
+        // Slicing here is safe as the base field is larger than the scalar field.
+        let bits = &self.to_bits_le()[..Scalar::size_in_data_bits()];
+
+        // Output the scalar field.
+        match Scalar::from_repr(Scalar::BigInteger::from_bits_le(bits)?) {
+            // We know this case will always work, because we truncate to CAPACITY bits in the scalar field.
+            Some(scalar) => Ok(scalar),
+            _ => bail!("Failed to reduce the field element into the scalar field"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Pedersen64, BHP256};
+    use snarkvm_curves::edwards_bls12::EdwardsAffine;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 10;
+
+    /// Checks that a commitment verifies with its opening, and fails to verify with a different input or randomizer.
+    fn check_commitment_scheme<C: CommitmentScheme<Input = bool>>(scheme: &C, num_bits: usize) -> Result<()>
+    where
+        C::Randomizer: UniformRand,
+    {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random input and randomizer.
+            let input = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+            let randomizer = C::Randomizer::rand(rng);
+
+            // Ensure the commitment verifies with its opening.
+            let commitment = scheme.commit(&input, &randomizer)?;
+            assert!(scheme.verify(&input, &randomizer, &commitment)?);
+
+            // Ensure the commitment does not verify with a different input.
+            let mut other_input = input.clone();
+            other_input[0] = !other_input[0];
+            assert!(!scheme.verify(&other_input, &randomizer, &commitment)?);
+
+            // Ensure the commitment does not verify with a different randomizer.
+            assert!(!scheme.verify(&input, &C::Randomizer::rand(rng), &commitment)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_commitment_scheme() -> Result<()> {
+        check_commitment_scheme(&Pedersen64::<EdwardsAffine>::setup("CommitmentSchemeTest0"), 64)?;
+        check_commitment_scheme(&BHP256::<EdwardsAffine>::setup("CommitmentSchemeTest0")?, 256)
+    }
+}
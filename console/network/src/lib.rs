@@ -24,7 +24,7 @@ pub mod testnet3;
 pub use testnet3::*;
 
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
-use snarkvm_fields::traits::*;
+use snarkvm_fields::{traits::*, ConditionallySelectable};
 
 use anyhow::Result;
 use core::{fmt, hash};
@@ -35,10 +35,10 @@ pub trait Network: Copy + Clone + fmt::Debug + Eq + PartialEq + hash::Hash {
         BaseField = Self::Field,
         ScalarField = Self::Scalar,
         Coordinates = (Self::Field, Self::Field),
-    >;
+    > + ConditionallySelectable;
     type Projective: ProjectiveCurve<Affine = Self::Affine, BaseField = Self::Field, ScalarField = Self::Scalar>;
-    type Field: PrimeField + Copy;
-    type Scalar: PrimeField + Copy;
+    type Field: PrimeField + Copy + ConditionallySelectable;
+    type Scalar: PrimeField + Copy + ConditionallySelectable;
 
     /// The maximum recursive depth of a value.
     /// Note: This value must be strictly less than u8::MAX.
@@ -70,6 +70,10 @@ pub trait Network: Copy + Clone + fmt::Debug + Eq + PartialEq + hash::Hash {
     /// A helper method to recover a scalar from **big-endian** bits.
     fn scalar_from_bits_be(bits: &[bool]) -> Result<Self::Scalar>;
 
+    /// A helper method to recover a scalar from a base field element.
+    /// This method returns an error if the field element is not less than the scalar field modulus.
+    fn scalar_from_field(field: &Self::Field) -> Result<Self::Scalar>;
+
     /// Returns the encryption domain as a constant field element.
     fn encryption_domain() -> Self::Field;
 
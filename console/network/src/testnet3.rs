@@ -148,6 +148,13 @@ impl Network for Testnet3 {
         Self::scalar_from_bits_le(&bits)
     }
 
+    /// A helper method to recover a scalar from a base field element.
+    /// This method returns an error if the field element is not less than the scalar field modulus.
+    fn scalar_from_field(field: &Self::Field) -> Result<Self::Scalar> {
+        Self::scalar_from_bits_le(&field.to_bits_le())
+            .map_err(|_| anyhow!("The field element {field} exceeds the scalar field modulus"))
+    }
+
     /// Returns the encryption domain as a constant field element.
     fn encryption_domain() -> Self::Field {
         ENCRYPTION_DOMAIN.with(|domain| *domain)
@@ -302,3 +309,41 @@ impl Network for Testnet3 {
         POSEIDON_8.with(|poseidon| poseidon.prf(seed, input))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 1000;
+
+    #[test]
+    fn test_scalar_from_field() -> Result<()> {
+        type Field = <CurrentNetwork as Network>::Field;
+        type Scalar = <CurrentNetwork as Network>::Scalar;
+
+        // Ensure in-range field elements are converted.
+        for _ in 0..ITERATIONS {
+            let expected = Scalar::rand(&mut test_rng());
+            let field = Field::from_repr(expected.to_repr()).unwrap();
+            assert_eq!(expected, CurrentNetwork::scalar_from_field(&field)?);
+        }
+        assert_eq!(Scalar::zero(), CurrentNetwork::scalar_from_field(&Field::zero())?);
+
+        // Ensure the largest scalar, `ScalarField::MODULUS - 1`, is in range.
+        let modulus_minus_one = -Scalar::one();
+        let field = Field::from_repr(modulus_minus_one.to_repr()).unwrap();
+        assert_eq!(modulus_minus_one, CurrentNetwork::scalar_from_field(&field)?);
+
+        // Ensure `ScalarField::MODULUS` and `ScalarField::MODULUS + 1` are out of range.
+        let modulus = field + Field::one();
+        assert!(CurrentNetwork::scalar_from_field(&modulus).is_err());
+        assert!(CurrentNetwork::scalar_from_field(&(modulus + Field::one())).is_err());
+        // Ensure the largest field element is out of range.
+        assert!(CurrentNetwork::scalar_from_field(&-Field::one()).is_err());
+        Ok(())
+    }
+}
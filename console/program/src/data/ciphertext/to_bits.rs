@@ -19,7 +19,7 @@ use super::*;
 impl<N: Network> ToBits for Ciphertext<N> {
     /// Returns this entry as a list of **little-endian** bits.
     fn to_bits_le(&self) -> Vec<bool> {
-        let bits_le = self.0.to_bits_le();
+        let bits_le = self.to_bits_le_with_capacity(self.0.len() * N::Field::size_in_bits());
         assert_eq!(self.0.len() * N::Field::size_in_bits(), bits_le.len());
         bits_le
     }
@@ -31,3 +31,98 @@ impl<N: Network> ToBits for Ciphertext<N> {
         bits_be
     }
 }
+
+impl<N: Network> Ciphertext<N> {
+    /// Returns this entry as a list of **little-endian** bits, allocated with the given capacity.
+    /// The capacity is a hint, and the output always matches `to_bits_le`.
+    pub fn to_bits_le_with_capacity(&self, capacity: usize) -> Vec<bool> {
+        let mut bits_le = Vec::with_capacity(capacity);
+        self.0.iter().for_each(|field| bits_le.extend(field.to_bits_le()));
+        bits_le
+    }
+
+    /// Returns this entry as a list of **little-endian** bits, or an error if the number of bits is inconsistent.
+    /// This method is intended for paths handling untrusted input, where `to_bits_le` would panic.
+    pub fn checked_to_bits_le(&self) -> Result<Vec<bool>> {
+        Self::check_num_bits(self.0.len(), self.0.to_bits_le())
+    }
+
+    /// Returns the given bits, if the number of bits matches the expansion of `num_fields` field elements.
+    fn check_num_bits(num_fields: usize, bits: Vec<bool>) -> Result<Vec<bool>> {
+        // Compute the expected number of bits.
+        let expected = match num_fields.checked_mul(N::Field::size_in_bits()) {
+            Some(expected) => expected,
+            None => bail!("Ciphertext is too large to expand into bits ({num_fields} field elements)"),
+        };
+        // Ensure the number of bits matches.
+        match bits.len() == expected {
+            true => Ok(bits),
+            false => bail!("Ciphertext bits are inconsistent: found {} bits, expected {expected} bits", bits.len()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_checked_to_bits_le() -> Result<()> {
+        for i in 0..ITERATIONS {
+            // Sample a ciphertext.
+            let fields = (0..i).map(|_| UniformRand::rand(&mut test_rng())).collect::<Vec<_>>();
+            let ciphertext = Ciphertext::<CurrentNetwork>(fields);
+
+            // Ensure the happy path matches `to_bits_le`.
+            assert_eq!(ciphertext.to_bits_le(), ciphertext.checked_to_bits_le()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_bits_le_with_capacity() {
+        for i in 0..ITERATIONS {
+            // Sample a ciphertext.
+            let fields = (0..i).map(|_| UniformRand::rand(&mut test_rng())).collect::<Vec<_>>();
+            let ciphertext = Ciphertext::<CurrentNetwork>(fields);
+            let expected = ciphertext.to_bits_le();
+
+            // Ensure the output matches `to_bits_le`, and the capacity is respected.
+            let num_bits = i * <CurrentNetwork as Network>::Field::size_in_bits();
+            for capacity in [0, num_bits / 2, num_bits, num_bits + 1] {
+                let candidate = ciphertext.to_bits_le_with_capacity(capacity);
+                assert_eq!(expected, candidate);
+                assert!(candidate.capacity() >= capacity);
+            }
+
+            // Ensure an exact capacity does not reallocate.
+            assert_eq!(num_bits, ciphertext.to_bits_le_with_capacity(num_bits).capacity());
+        }
+    }
+
+    #[test]
+    fn test_checked_to_bits_le_inconsistent() -> Result<()> {
+        // Sample a ciphertext.
+        let fields = (0..4).map(|_| UniformRand::rand(&mut test_rng())).collect::<Vec<_>>();
+        let ciphertext = Ciphertext::<CurrentNetwork>(fields);
+        let bits = ciphertext.to_bits_le();
+
+        // Ensure a consistent number of bits is accepted.
+        assert_eq!(bits, Ciphertext::<CurrentNetwork>::check_num_bits(4, bits.clone())?);
+        // Ensure an inconsistent number of bits is rejected, instead of panicking.
+        assert!(Ciphertext::<CurrentNetwork>::check_num_bits(5, bits.clone()).is_err());
+        assert!(Ciphertext::<CurrentNetwork>::check_num_bits(3, bits.clone()).is_err());
+        assert!(Ciphertext::<CurrentNetwork>::check_num_bits(4, bits[1..].to_vec()).is_err());
+        // Ensure an overflowing number of field elements is rejected.
+        assert!(Ciphertext::<CurrentNetwork>::check_num_bits(usize::MAX, bits).is_err());
+        Ok(())
+    }
+}
@@ -20,7 +20,7 @@ impl<N: Network> Data<N, Ciphertext<N>> {
     /// Decrypts `self` into plaintext using the given view key & nonce.
     pub fn decrypt(&self, view_key: ViewKey<N>, nonce: N::Affine) -> Result<Data<N, Plaintext<N>>> {
         // Compute the data view key.
-        let data_view_key = (nonce * *view_key).to_affine().to_x_coordinate();
+        let data_view_key = view_key.mul_point(nonce).to_affine().to_x_coordinate();
         // Decrypt the data.
         self.decrypt_symmetric(&data_view_key)
     }
@@ -41,11 +41,12 @@ impl<N: Network> Data<N, Ciphertext<N>> {
             // Retrieve the randomizers for this entry.
             let randomizers = &randomizers[index..index + num_randomizers];
             // Decrypt the entry, and add the entry.
-            decrypted_data.push((id.clone(), entry.decrypt(randomizers)?));
+            decrypted_data.push((id.clone(), entry.decrypt(randomizers)));
             // Increment the index.
             index += num_randomizers;
         }
-        Ok(Data(decrypted_data))
+        // Ensure every entry decrypted successfully, only after attempting to decrypt all of them.
+        Ok(Data(decrypted_data.into_iter().map(|(id, entry)| Ok((id, entry?))).collect::<Result<Vec<_>>>()?))
     }
 }
 
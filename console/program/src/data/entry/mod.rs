@@ -17,6 +17,7 @@
 mod decrypt;
 mod encrypt;
 mod num_randomizers;
+mod size_in_fields;
 // mod to_bits;
 
 use crate::{Ciphertext, FromFields, Plaintext, ToFields, Visibility};
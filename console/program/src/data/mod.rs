@@ -31,6 +31,7 @@ pub use plaintext::Plaintext;
 
 mod decrypt;
 mod encrypt;
+mod size_in_fields;
 
 use crate::{FromFields, ToFields};
 use snarkvm_console_account::{Address, ViewKey};
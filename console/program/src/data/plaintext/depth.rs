@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the nesting depth of the plaintext, where a literal has a depth of `0`.
+    /// Note: This method is iterative, so that it is safe to call on adversarially-deep plaintexts.
+    pub fn depth(&self) -> usize {
+        let mut max_depth = 0;
+        let mut stack = vec![(self, 0usize)];
+        while let Some((plaintext, depth)) = stack.pop() {
+            max_depth = max_depth.max(depth);
+            if let Self::Composite(composite, ..) = plaintext {
+                stack.extend(composite.iter().map(|(_, entry)| (entry, depth + 1)));
+            }
+        }
+        max_depth
+    }
+
+    /// Ensures the nesting depth of the plaintext does not exceed `N::DEPTH`.
+    pub fn ensure_depth(&self) -> Result<()> {
+        let depth = self.depth();
+        match depth <= N::DEPTH as usize {
+            true => Ok(()),
+            false => bail!("Plaintext exceeds the maximum depth of {} (found {depth})", N::DEPTH),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a plaintext with the given number of nested composites.
+    fn nested(depth: usize) -> Result<Plaintext<CurrentNetwork>> {
+        let mut plaintext = Plaintext::from(Literal::Boolean(true));
+        for _ in 0..depth {
+            plaintext = Plaintext::Composite(vec![(Identifier::from_str("a")?, plaintext)], OnceCell::new());
+        }
+        Ok(plaintext)
+    }
+
+    #[test]
+    fn test_depth() -> Result<()> {
+        for depth in 0..=CurrentNetwork::DEPTH as usize {
+            let plaintext = nested(depth)?;
+            assert_eq!(depth, plaintext.depth());
+            assert!(plaintext.ensure_depth().is_ok());
+            assert!(plaintext.size_in_fields().is_ok());
+            assert!(plaintext.to_fields().is_ok());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_depth_exceeds_maximum() -> Result<()> {
+        for depth in [CurrentNetwork::DEPTH as usize + 1, 1_000] {
+            let plaintext = nested(depth)?;
+            assert_eq!(depth, plaintext.depth());
+            assert!(plaintext.ensure_depth().is_err());
+            assert!(plaintext.size_in_fields().is_err());
+            assert!(plaintext.to_fields().is_err());
+        }
+
+        // Ensure decoding rejects a plaintext that is nested too deeply.
+        let plaintext = nested(CurrentNetwork::DEPTH as usize + 1)?;
+        assert!(Plaintext::<CurrentNetwork>::from_bits_le(&plaintext.to_bits_le()).is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_bits_be(&plaintext.to_bits_be()).is_err());
+        let plaintext = nested(CurrentNetwork::DEPTH as usize)?;
+        assert_eq!(plaintext, Plaintext::<CurrentNetwork>::from_bits_le(&plaintext.to_bits_le())?);
+        Ok(())
+    }
+}
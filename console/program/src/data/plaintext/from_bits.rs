@@ -19,20 +19,38 @@ use super::*;
 impl<N: Network> FromBits for Plaintext<N> {
     /// Initializes a new value from a list of little-endian bits *without* trailing zeros.
     fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        Self::from_bits_le_internal(bits_le, 0)
+    }
+
+    /// Initializes a new value from a list of big-endian bits *without* trailing zeros.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        Self::from_bits_be_internal(bits_be, 0)
+    }
+}
+
+impl<N: Network> Plaintext<N> {
+    /// Initializes a new value at the given nesting depth from a list of little-endian bits.
+    fn from_bits_le_internal(bits_le: &[bool], depth: usize) -> Result<Self> {
+        // Ensure the plaintext is not nested too deeply.
+        if depth > N::DEPTH as usize {
+            bail!("Plaintext exceeds the maximum depth of {}", N::DEPTH)
+        }
+
         let mut counter = 0;
 
-        let is_literal = !bits_le[counter];
+        let is_literal = !bits_in_range(bits_le, counter, 1)?[0];
         counter += 1;
 
         // Literal
         if is_literal {
-            let literal_variant = u8::from_bits_le(&bits_le[counter..counter + 8])?;
+            let literal_variant = u8::from_bits_le(bits_in_range(bits_le, counter, 8)?)?;
             counter += 8;
 
-            let literal_size = u16::from_bits_le(&bits_le[counter..counter + 16])?;
+            let literal_size = u16::from_bits_le(bits_in_range(bits_le, counter, 16)?)?;
             counter += 16;
 
-            let literal = Literal::from_bits_le(literal_variant, &bits_le[counter..counter + literal_size as usize])?;
+            let literal =
+                Literal::from_bits_le(literal_variant, bits_in_range(bits_le, counter, literal_size as usize)?)?;
 
             // Store the plaintext bits in the cache.
             let cache = OnceCell::new();
@@ -44,21 +62,24 @@ impl<N: Network> FromBits for Plaintext<N> {
         }
         // Composite
         else {
-            let num_composites = u8::from_bits_le(&bits_le[counter..counter + 8])?;
+            let num_composites = u8::from_bits_le(bits_in_range(bits_le, counter, 8)?)?;
             counter += 8;
 
             let mut composites = Vec::with_capacity(num_composites as usize);
             for _ in 0..num_composites {
-                let identifier_size = u8::from_bits_le(&bits_le[counter..counter + 8])?;
+                let identifier_size = u8::from_bits_le(bits_in_range(bits_le, counter, 8)?)?;
                 counter += 8;
 
-                let identifier = Identifier::from_bits_le(&bits_le[counter..counter + identifier_size as usize])?;
+                let identifier = Identifier::from_bits_le(bits_in_range(bits_le, counter, identifier_size as usize)?)?;
                 counter += identifier_size as usize;
 
-                let composite_size = u16::from_bits_le(&bits_le[counter..counter + 16])?;
+                let composite_size = u16::from_bits_le(bits_in_range(bits_le, counter, 16)?)?;
                 counter += 16;
 
-                let entry = Plaintext::from_bits_le(&bits_le[counter..counter + composite_size as usize])?;
+                let entry = Plaintext::from_bits_le_internal(
+                    bits_in_range(bits_le, counter, composite_size as usize)?,
+                    depth + 1,
+                )?;
                 counter += composite_size as usize;
 
                 composites.push((identifier, entry));
@@ -74,22 +95,28 @@ impl<N: Network> FromBits for Plaintext<N> {
         }
     }
 
-    /// Initializes a new value from a list of big-endian bits *without* trailing zeros.
-    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+    /// Initializes a new value at the given nesting depth from a list of big-endian bits.
+    fn from_bits_be_internal(bits_be: &[bool], depth: usize) -> Result<Self> {
+        // Ensure the plaintext is not nested too deeply.
+        if depth > N::DEPTH as usize {
+            bail!("Plaintext exceeds the maximum depth of {}", N::DEPTH)
+        }
+
         let mut counter = 0;
 
-        let is_literal = !bits_be[counter];
+        let is_literal = !bits_in_range(bits_be, counter, 1)?[0];
         counter += 1;
 
         // Literal
         if is_literal {
-            let literal_variant = u8::from_bits_be(&bits_be[counter..counter + 8])?;
+            let literal_variant = u8::from_bits_be(bits_in_range(bits_be, counter, 8)?)?;
             counter += 8;
 
-            let literal_size = u16::from_bits_be(&bits_be[counter..counter + 16])?;
+            let literal_size = u16::from_bits_be(bits_in_range(bits_be, counter, 16)?)?;
             counter += 16;
 
-            let literal = Literal::from_bits_be(literal_variant, &bits_be[counter..counter + literal_size as usize])?;
+            let literal =
+                Literal::from_bits_be(literal_variant, bits_in_range(bits_be, counter, literal_size as usize)?)?;
 
             // Store the plaintext bits in the cache.
             let cache = OnceCell::new();
@@ -101,21 +128,24 @@ impl<N: Network> FromBits for Plaintext<N> {
         }
         // Composite
         else {
-            let num_composites = u8::from_bits_be(&bits_be[counter..counter + 8])?;
+            let num_composites = u8::from_bits_be(bits_in_range(bits_be, counter, 8)?)?;
             counter += 8;
 
             let mut composites = Vec::with_capacity(num_composites as usize);
             for _ in 0..num_composites {
-                let identifier_size = u8::from_bits_be(&bits_be[counter..counter + 8])?;
+                let identifier_size = u8::from_bits_be(bits_in_range(bits_be, counter, 8)?)?;
                 counter += 8;
 
-                let identifier = Identifier::from_bits_be(&bits_be[counter..counter + identifier_size as usize])?;
+                let identifier = Identifier::from_bits_be(bits_in_range(bits_be, counter, identifier_size as usize)?)?;
                 counter += identifier_size as usize;
 
-                let composite_size = u16::from_bits_be(&bits_be[counter..counter + 16])?;
+                let composite_size = u16::from_bits_be(bits_in_range(bits_be, counter, 16)?)?;
                 counter += 16;
 
-                let entry = Plaintext::from_bits_be(&bits_be[counter..counter + composite_size as usize])?;
+                let entry = Plaintext::from_bits_be_internal(
+                    bits_in_range(bits_be, counter, composite_size as usize)?,
+                    depth + 1,
+                )?;
                 counter += composite_size as usize;
 
                 composites.push((identifier, entry));
@@ -131,3 +161,11 @@ impl<N: Network> FromBits for Plaintext<N> {
         }
     }
 }
+
+/// Returns the `length` bits starting at index `start`, or an error if the range is out of bounds.
+fn bits_in_range(bits: &[bool], start: usize, length: usize) -> Result<&[bool]> {
+    match start.checked_add(length).and_then(|end| bits.get(start..end)) {
+        Some(bits) => Ok(bits),
+        None => bail!("Plaintext is missing bits at index {start} (found {} bits)", bits.len()),
+    }
+}
@@ -44,18 +44,7 @@ impl<N: Network> FromFields for Plaintext<N> {
             bail!("Plaintext exceeds maximum allowed size")
         }
 
-        // Unpack the field elements into little-endian bits, and reverse the list for popping the terminus bit off.
-        let mut bits_le =
-            fields.iter().flat_map(|field| field.to_bits_le()[..N::Field::size_in_data_bits()].to_vec()).rev();
-        // Remove the terminus bit that was added during encoding.
-        for boolean in bits_le.by_ref() {
-            // Drop all extraneous `0` bits, in addition to the final `1` bit.
-            if boolean {
-                // This case will always be reached, since the terminus bit is always `1`.
-                break;
-            }
-        }
-        // Reverse the bits back and recover the data from the bits.
-        Self::from_bits_le(&bits_le.rev().collect::<Vec<_>>())
+        // Unpack the field elements, removing the terminus bit that was added during encoding.
+        Self::from_packed_fields(fields)
     }
 }
@@ -14,13 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod depth;
 mod from_bits;
 mod from_fields;
 mod size_in_fields;
 mod to_bits;
 mod to_fields;
 
-use crate::{FromFields, Identifier, Literal, ToFields, Visibility};
+use crate::{FromFields, FromPackedFields, Identifier, Literal, ToFields, ToPackedFields, Visibility};
 use snarkvm_console_network::Network;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{FromBits, ToBits};
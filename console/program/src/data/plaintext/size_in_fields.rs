@@ -19,10 +19,10 @@ use super::*;
 impl<N: Network> Visibility<N> for Plaintext<N> {
     /// Returns the number of field elements to encode `self`.
     fn size_in_fields(&self) -> Result<u16> {
-        // Compute the number of bits.
-        let num_bits = self.to_bits_le().len() + 1; // 1 extra bit for the terminus indicator.
-        // Compute the ceiling division of the number of bits by the number of bits in a field element.
-        let num_fields = (num_bits + N::Field::size_in_data_bits() - 1) / N::Field::size_in_data_bits();
+        // Ensure the plaintext is not nested too deeply to encode.
+        self.ensure_depth()?;
+        // Compute the number of field elements, including 1 extra bit for the terminus indicator.
+        let num_fields = num_fields_for_bits::<N>(self.to_bits_le().len())?;
         // Ensure the number of field elements does not exceed the maximum allowed size.
         match num_fields <= N::MAX_DATA_SIZE_IN_FIELDS as usize {
             // Return the number of field elements.
@@ -31,3 +31,70 @@ impl<N: Network> Visibility<N> for Plaintext<N> {
         }
     }
 }
+
+/// Returns the number of field elements required to encode the given number of plaintext bits,
+/// accounting for 1 extra bit for the terminus indicator.
+fn num_fields_for_bits<N: Network>(num_bits: usize) -> Result<usize> {
+    num_fields_for_bits_with_capacity(num_bits, N::Field::size_in_data_bits())
+}
+
+/// Returns the number of field elements required to encode the given number of plaintext bits,
+/// where each field element holds `size_in_data_bits` bits.
+fn num_fields_for_bits_with_capacity(num_bits: usize, size_in_data_bits: usize) -> Result<usize> {
+    // Ensure each field element can hold at least one bit, as the division below would otherwise be undefined.
+    if size_in_data_bits == 0 {
+        bail!("Plaintext cannot be encoded in a field with no data bits (check the network parameters).")
+    }
+    // Compute the ceiling division of the number of bits by the number of bits in a field element.
+    match num_bits.checked_add(1).and_then(|num_bits| num_bits.checked_add(size_in_data_bits - 1)) {
+        Some(numerator) => Ok(numerator / size_in_data_bits),
+        None => bail!("Plaintext is too large to encode in field elements ({num_bits} bits)."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_num_fields_for_bits() -> Result<()> {
+        let size_in_data_bits = <CurrentNetwork as Network>::Field::size_in_data_bits();
+
+        // The terminus bit alone requires one field element.
+        assert_eq!(1, num_fields_for_bits::<CurrentNetwork>(0)?);
+        // Filling a field element exactly (including the terminus bit) requires one field element.
+        assert_eq!(1, num_fields_for_bits::<CurrentNetwork>(size_in_data_bits - 1)?);
+        // One more bit spills over into a second field element.
+        assert_eq!(2, num_fields_for_bits::<CurrentNetwork>(size_in_data_bits)?);
+
+        // The largest number of bits that does not overflow succeeds.
+        let max_num_bits = usize::MAX - size_in_data_bits;
+        assert_eq!(usize::MAX / size_in_data_bits, num_fields_for_bits::<CurrentNetwork>(max_num_bits)?);
+        // Any larger number of bits fails gracefully, instead of wrapping.
+        assert!(num_fields_for_bits::<CurrentNetwork>(max_num_bits + 1).is_err());
+        assert!(num_fields_for_bits::<CurrentNetwork>(usize::MAX - 1).is_err());
+        assert!(num_fields_for_bits::<CurrentNetwork>(usize::MAX).is_err());
+        // A 32-bit boundary does not overflow on 64-bit targets.
+        assert!(num_fields_for_bits::<CurrentNetwork>(u32::MAX as usize).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_num_fields_for_bits_with_capacity() -> Result<()> {
+        // A tiny field that holds a single data bit requires one field element per bit, plus the terminus bit.
+        assert_eq!(1, num_fields_for_bits_with_capacity(0, 1)?);
+        assert_eq!(9, num_fields_for_bits_with_capacity(8, 1)?);
+        // A field that holds two data bits rounds up.
+        assert_eq!(5, num_fields_for_bits_with_capacity(8, 2)?);
+
+        // A field with no data bits fails cleanly, instead of underflowing.
+        for num_bits in [0, 1, usize::MAX] {
+            let error = num_fields_for_bits_with_capacity(num_bits, 0).unwrap_err();
+            assert!(error.to_string().contains("no data bits"));
+        }
+        Ok(())
+    }
+}
@@ -21,16 +21,10 @@ impl<N: Network> ToFields for Plaintext<N> {
 
     /// Returns this plaintext as a list of field elements.
     fn to_fields(&self) -> Result<Vec<Self::Field>> {
-        // Encode the data as little-endian bits.
-        let mut bits_le = self.to_bits_le();
-        // Adds one final bit to the data, to serve as a terminus indicator.
-        // During decryption, this final bit ensures we've reached the end.
-        bits_le.push(true);
-        // Pack the bits into field elements.
-        let fields = bits_le
-            .chunks(N::Field::size_in_data_bits())
-            .map(|bits_le| N::field_from_bits_le(bits_le))
-            .collect::<Result<Vec<_>>>()?;
+        // Ensure the plaintext is not nested too deeply to encode.
+        self.ensure_depth()?;
+        // Pack the bits into field elements, with a terminus bit to indicate the end of the data.
+        let fields = self.to_packed_fields::<N::Field>()?;
         // Ensure the number of field elements does not exceed the maximum allowed size.
         match fields.len() <= N::MAX_DATA_SIZE_IN_FIELDS as usize {
             true => Ok(fields),
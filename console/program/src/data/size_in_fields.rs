@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network, Private: Visibility<N>> Data<N, Private> {
+    /// Returns the number of field elements to encode `self`.
+    pub fn size_in_fields(&self) -> Result<u16> {
+        // Sum the number of field elements in each entry.
+        let mut num_fields = 0u32;
+        for (_, entry) in &self.0 {
+            num_fields += entry.size_in_fields()? as u32;
+            // Ensure the number of field elements does not exceed the maximum allowed size.
+            if num_fields > N::MAX_DATA_SIZE_IN_FIELDS {
+                bail!("Data is too large to encode in field elements.")
+            }
+        }
+        Ok(num_fields as u16)
+    }
+}
@@ -21,7 +21,10 @@
 extern crate enum_index_derive;
 
 mod merkle_tree;
-pub use merkle_tree::MerkleTree;
+pub use merkle_tree::{LeafHash, MerklePath, MerkleTree, PathHash};
+
+mod sparse_merkle_tree;
+pub use sparse_merkle_tree::{SparseMerklePath, SparseMerkleTree};
 
 mod data;
 pub use data::*;
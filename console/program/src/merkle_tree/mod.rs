@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod helpers;
-use helpers::*;
+pub use helpers::*;
 
 mod path;
 pub use path::*;
@@ -53,6 +53,11 @@ pub struct MerkleTree<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH:
 }
 
 impl<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> MerkleTree<N, LH, PH, DEPTH> {
+    /// Returns a new Merkle tree of depth `DEPTH` for the given leaves.
+    ///
+    /// If the number of leaves is not a power of two, the leaf row is padded on the right with
+    /// `PathHash::hash_empty` (i.e. `path_hasher.hash(0, 0)`), up to the next power of two.
+    /// The tree is then extended to `DEPTH` by hashing the current root with `hash_empty` at each level.
     #[inline]
     pub fn new(leaf_hasher: &LH, path_hasher: &PH, leaves: &[LH::Leaf]) -> Result<Self> {
         // Ensure the DEPTH is non-zero.
@@ -102,23 +107,12 @@ impl<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> MerkleTree<N
 
         // Finished computing actual tree.
         // Now, we compute the dummy nodes until we hit our DEPTH goal.
-        let mut current_depth = tree_depth;
-        let mut padding_tree = Vec::with_capacity(DEPTH.saturating_sub(current_depth + 1) as usize);
-        let mut current_hash = tree[0];
-        while current_depth < DEPTH {
-            current_hash = path_hasher.hash(&current_hash, &empty_hash)?;
-
-            // do not pad at the top-level of the tree
-            if current_depth < DEPTH - 1 {
-                padding_tree.push((current_hash, empty_hash));
-            }
-            current_depth += 1;
-        }
+        let (root, padding_tree) = Self::compute_padding_tree(path_hasher, tree[0], tree_depth)?;
 
         Ok(Self {
             leaf_hasher: leaf_hasher.clone(),
             path_hasher: path_hasher.clone(),
-            root: current_hash,
+            root,
             tree,
             padding_tree,
             starting_leaf_index,
@@ -126,8 +120,10 @@ impl<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> MerkleTree<N
         })
     }
 
+    /// Returns a new Merkle tree with the given leaves appended, leaving `self` unchanged.
+    /// Only the tree paths that are altered by the new leaves are recomputed.
     #[inline]
-    pub fn append(&self, new_leaves: &[LH::Leaf]) -> Result<Self> {
+    pub fn prepare_append(&self, new_leaves: &[LH::Leaf]) -> Result<Self> {
         // Compute the tree size and tree depth := log2(tree_size).
         let last_level_size = (self.number_of_leaves + new_leaves.len()).next_power_of_two();
         let tree_size = 2 * last_level_size - 1;
@@ -227,6 +223,59 @@ impl<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> MerkleTree<N
         })
     }
 
+    /// Appends the given leaf to the Merkle tree, updating only the path from the leaf to the root.
+    ///
+    /// If the leaf row is full, the tree is resized to the next power of two with `prepare_append`,
+    /// which amortizes to `O(log n)` hashes per leaf. On failure, the tree is left unchanged.
+    #[inline]
+    pub fn append(&mut self, leaf: &LH::Leaf) -> Result<()> {
+        // If the leaf row is full, the tree must be resized.
+        if self.number_of_leaves == self.hashed_leaves().len() {
+            *self = self.prepare_append(core::slice::from_ref(leaf))?;
+            return Ok(());
+        }
+
+        // Compute the leaf hash, and store it in the first empty slot of the leaf row.
+        let mut current_index = self.starting_leaf_index + self.number_of_leaves;
+        let mut current_hash = self.leaf_hasher.hash(leaf)?;
+        let mut updates = vec![(current_index, current_hash)];
+
+        // Iterate from the leaf up to the root, computing the hash of each ancestor.
+        while let Some(parent_index) = parent(current_index) {
+            // Retrieve the sibling, and compute Hash(left || right).
+            current_hash = match is_left_child(current_index) {
+                true => self.path_hasher.hash(&current_hash, &self.tree[current_index + 1])?,
+                false => self.path_hasher.hash(&self.tree[current_index - 1], &current_hash)?,
+            };
+            current_index = parent_index;
+            updates.push((current_index, current_hash));
+        }
+
+        // Compute the dummy nodes until we hit our DEPTH goal.
+        let tree_depth = tree_depth::<DEPTH>(self.tree.len())?;
+        let (root, padding_tree) = Self::compute_padding_tree(&self.path_hasher, current_hash, tree_depth)?;
+
+        // Update the values at the very end so the original tree is not altered in case of failure.
+        updates.into_iter().for_each(|(index, hash)| self.tree[index] = hash);
+        self.root = root;
+        self.padding_tree = padding_tree;
+        self.number_of_leaves += 1;
+        Ok(())
+    }
+
+    /// Appends the given leaves to the Merkle tree. On failure, the tree is left unchanged.
+    #[inline]
+    pub fn append_batch(&mut self, new_leaves: &[LH::Leaf]) -> Result<()> {
+        match new_leaves.len() {
+            0 => Ok(()),
+            1 => self.append(&new_leaves[0]),
+            _ => {
+                *self = self.prepare_append(new_leaves)?;
+                Ok(())
+            }
+        }
+    }
+
     /// Returns the Merkle path for the given leaf index and leaf.
     #[inline]
     pub fn prove(&self, leaf_index: usize, leaf: &LH::Leaf) -> Result<MerklePath<N, DEPTH>> {
@@ -280,6 +329,43 @@ impl<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> MerkleTree<N
         &self.tree[self.starting_leaf_index..]
     }
 
+    /// Returns the number of leaves in the tree, excluding the padding.
+    #[inline]
+    pub const fn number_of_leaves(&self) -> usize {
+        self.number_of_leaves
+    }
+
+    /// Returns the hash used to pad the leaf row and the tree up to `DEPTH`.
+    #[inline]
+    pub fn padding_hash(&self) -> Result<N::Field> {
+        self.path_hasher.hash_empty()
+    }
+
+    /// Returns the root and the padding tree, given the root of the full tree and its depth.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn compute_padding_tree(
+        path_hasher: &PH,
+        tree_root: N::Field,
+        tree_depth: u8,
+    ) -> Result<(N::Field, Vec<(N::Field, N::Field)>)> {
+        let empty_hash = path_hasher.hash_empty()?;
+
+        let mut current_depth = tree_depth;
+        let mut padding_tree = Vec::with_capacity(DEPTH.saturating_sub(current_depth + 1) as usize);
+        let mut current_hash = tree_root;
+        while current_depth < DEPTH {
+            current_hash = path_hasher.hash(&current_hash, &empty_hash)?;
+
+            // do not pad at the top-level of the tree
+            if current_depth < DEPTH - 1 {
+                padding_tree.push((current_hash, empty_hash));
+            }
+            current_depth += 1;
+        }
+        Ok((current_hash, padding_tree))
+    }
+
     #[inline]
     fn hash_leaf_row(leaf_hasher: &LH, leaf_nodes: &[LH::Leaf]) -> Result<Vec<N::Field>> {
         match leaf_nodes.len() {
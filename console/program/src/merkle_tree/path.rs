@@ -44,6 +44,16 @@ impl<N: Network, const DEPTH: u8> TryFrom<(Vec<N::Field>, u64)> for MerklePath<N
 }
 
 impl<N: Network, const DEPTH: u8> MerklePath<N, DEPTH> {
+    /// Returns the sibling hashes of the Merkle path, from leaf to root.
+    pub fn siblings(&self) -> &[N::Field] {
+        &self.path
+    }
+
+    /// Returns the leaf index of the Merkle path.
+    pub const fn leaf_index(&self) -> u64 {
+        self.leaf_index
+    }
+
     /// Returns `true` if the Merkle path is valid for the given root and leaf.
     pub fn verify<LH: LeafHash<N>, PH: PathHash<N>>(
         &self,
@@ -55,7 +55,7 @@ fn check_merkle_tree<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH:
     // If additional leaves are provided, check that the Merkle tree is consistent with them.
     if !additional_leaves.is_empty() {
         // Append additional leaves to the Merkle tree.
-        let merkle_tree = merkle_tree.append(additional_leaves)?;
+        let merkle_tree = merkle_tree.prepare_append(additional_leaves)?;
         // Check each additional leaf in the Merkle tree.
         for (leaf_index, leaf) in additional_leaves.iter().enumerate() {
             // Compute a Merkle proof for the leaf.
@@ -158,7 +158,7 @@ fn check_merkle_tree_depth_3_padded<N: Network, LH: LeafHash<N>, PH: PathHash<N>
     // ------------------------------------------------------------------------------------------ //
 
     // Rebuild the Merkle tree with the additional leaf.
-    merkle_tree = merkle_tree.append(additional_leaves)?;
+    merkle_tree = merkle_tree.prepare_append(additional_leaves)?;
     assert_eq!(15, merkle_tree.tree.len());
     assert_eq!(0, merkle_tree.padding_tree.len());
     assert_eq!(5, merkle_tree.number_of_leaves);
@@ -254,7 +254,7 @@ fn check_merkle_tree_depth_4_padded<N: Network, LH: LeafHash<N>, PH: PathHash<N>
     // ------------------------------------------------------------------------------------------ //
 
     // Rebuild the Merkle tree with the additional leaf.
-    merkle_tree = merkle_tree.append(&[additional_leaves[0].clone()])?;
+    merkle_tree = merkle_tree.prepare_append(&[additional_leaves[0].clone()])?;
     assert_eq!(15, merkle_tree.tree.len());
     assert_eq!(0, merkle_tree.padding_tree.len());
     assert_eq!(5, merkle_tree.number_of_leaves);
@@ -310,7 +310,7 @@ fn check_merkle_tree_depth_4_padded<N: Network, LH: LeafHash<N>, PH: PathHash<N>
     assert_eq!(5, merkle_tree.number_of_leaves);
 
     // Rebuild the Merkle tree with the additional leaf.
-    merkle_tree = merkle_tree.append(&[additional_leaves[1].clone()])?;
+    merkle_tree = merkle_tree.prepare_append(&[additional_leaves[1].clone()])?;
     assert_eq!(15, merkle_tree.tree.len());
     assert_eq!(0, merkle_tree.padding_tree.len());
     assert_eq!(6, merkle_tree.number_of_leaves);
@@ -359,6 +359,107 @@ fn check_merkle_tree_depth_4_padded<N: Network, LH: LeafHash<N>, PH: PathHash<N>
     Ok(())
 }
 
+/// Runs the following test:
+/// 1. Append each leaf one-by-one to an empty Merkle tree.
+/// 2. Check that the tree matches a fresh Merkle tree of the same leaves at each step.
+/// 3. Append the leaves in batches, and check the tree matches a fresh Merkle tree.
+/// 4. Check that appending beyond the depth fails, and leaves the tree unchanged.
+fn check_merkle_tree_incremental<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    assert_eq!(2usize.pow(DEPTH as u32), leaves.len(), "Incremental test requires a full set of leaves");
+
+    // Append each leaf one-by-one.
+    let mut merkle_tree = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &[])?;
+    for (i, leaf) in leaves.iter().enumerate() {
+        merkle_tree.append(leaf)?;
+
+        // Ensure the tree matches a fresh tree with the same leaves.
+        let expected = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &leaves[..=i])?;
+        assert_eq!(expected.root(), merkle_tree.root());
+        assert_eq!(expected.tree(), merkle_tree.tree());
+        assert_eq!(expected.padding_tree, merkle_tree.padding_tree);
+        assert_eq!(expected.number_of_leaves(), merkle_tree.number_of_leaves());
+
+        // Ensure the Merkle proof for the appended leaf is valid.
+        let proof = merkle_tree.prove(i, leaf)?;
+        assert!(proof.verify(leaf_hasher, path_hasher, merkle_tree.root(), leaf));
+    }
+
+    // Append the leaves in batches of increasing size.
+    let mut merkle_tree = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &[])?;
+    let (mut start, mut batch_size) = (0, 0);
+    while start < leaves.len() {
+        let end = core::cmp::min(start + batch_size, leaves.len());
+        merkle_tree.append_batch(&leaves[start..end])?;
+
+        // Ensure the tree matches a fresh tree with the same leaves.
+        let expected = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &leaves[..end])?;
+        assert_eq!(expected.root(), merkle_tree.root());
+        assert_eq!(expected.tree(), merkle_tree.tree());
+
+        start = end;
+        batch_size += 1;
+    }
+
+    // Ensure appending to a full tree fails, and leaves the tree unchanged.
+    let root = *merkle_tree.root();
+    assert!(merkle_tree.append(&leaves[0]).is_err());
+    assert!(merkle_tree.append_batch(&leaves[..2]).is_err());
+    assert_eq!(root, *merkle_tree.root());
+    assert_eq!(leaves.len(), merkle_tree.number_of_leaves());
+    Ok(())
+}
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves, and check the leaf row is padded with the padding hash.
+/// 2. Check that the Merkle proof for every leaf is valid.
+/// 3. Check that the Merkle proof **fails** for a tampered sibling, leaf index, or leaf.
+fn check_merkle_tree_tampered<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    tampered_leaf: &LH::Leaf,
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let merkle_tree = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    assert_eq!(leaves.len(), merkle_tree.number_of_leaves());
+
+    // Ensure the leaf row is padded up to the next power of two with the padding hash.
+    let padding_hash = merkle_tree.padding_hash()?;
+    assert_eq!(padding_hash, path_hasher.hash(&N::Field::zero(), &N::Field::zero())?);
+    assert_eq!(leaves.len().next_power_of_two(), merkle_tree.hashed_leaves().len());
+    assert!(merkle_tree.hashed_leaves()[leaves.len()..].iter().all(|hash| *hash == padding_hash));
+
+    for (leaf_index, leaf) in leaves.iter().enumerate() {
+        // Compute a Merkle proof for the leaf.
+        let proof = merkle_tree.prove(leaf_index, leaf)?;
+        // Verify the Merkle proof succeeds.
+        assert!(proof.verify(leaf_hasher, path_hasher, merkle_tree.root(), leaf));
+        // Verify the Merkle proof **fails** on a tampered leaf.
+        assert!(!proof.verify(leaf_hasher, path_hasher, merkle_tree.root(), tampered_leaf));
+
+        // Verify the Merkle proof **fails** on each tampered sibling.
+        for i in 0..DEPTH as usize {
+            let mut siblings = proof.siblings().to_vec();
+            siblings[i] += N::Field::one();
+            let tampered = MerklePath::<N, DEPTH>::try_from((siblings, proof.leaf_index()))?;
+            assert!(!tampered.verify(leaf_hasher, path_hasher, merkle_tree.root(), leaf));
+        }
+
+        // Verify the Merkle proof **fails** on a tampered leaf index.
+        let tampered = MerklePath::<N, DEPTH>::try_from((proof.siblings().to_vec(), proof.leaf_index() ^ 1))?;
+        assert!(!tampered.verify(leaf_hasher, path_hasher, merkle_tree.root(), leaf));
+    }
+
+    // Ensure a proof for an out-of-bounds leaf index, or a leaf that is not at the index, fails.
+    assert!(merkle_tree.prove(leaves.len().next_power_of_two(), &leaves[0]).is_err());
+    assert!(merkle_tree.prove(0, tampered_leaf).is_err());
+    Ok(())
+}
+
 #[test]
 fn test_merkle_tree_bhp() -> Result<()> {
     fn run_test<const DEPTH: u8>() -> Result<()> {
@@ -587,6 +688,81 @@ fn test_merkle_tree_depth_4_poseidon() -> Result<()> {
     )
 }
 
+#[test]
+fn test_merkle_tree_tampered_bhp() -> Result<()> {
+    type LH = BHP1024<<CurrentNetwork as Network>::Affine>;
+    type PH = BHP512<<CurrentNetwork as Network>::Affine>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let create_leaves = |num_leaves| {
+        (0..num_leaves)
+            .map(|_| <CurrentNetwork as Network>::Field::rand(&mut test_rng()).to_bits_le())
+            .collect::<Vec<Vec<bool>>>()
+    };
+
+    for num_leaves in [1, 2, 5, 8] {
+        let tampered_leaf = <CurrentNetwork as Network>::Field::rand(&mut test_rng()).to_bits_le();
+        check_merkle_tree_tampered::<CurrentNetwork, LH, PH, 3>(
+            &leaf_hasher,
+            &path_hasher,
+            &create_leaves(num_leaves),
+            &tampered_leaf,
+        )?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_tampered_poseidon() -> Result<()> {
+    type LH = Poseidon<<CurrentNetwork as Network>::Field, 4>;
+    type PH = Poseidon<<CurrentNetwork as Network>::Field, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let create_leaves =
+        |num_leaves| (0..num_leaves).map(|_| vec![UniformRand::rand(&mut test_rng())]).collect::<Vec<_>>();
+
+    for num_leaves in [1, 2, 5, 8] {
+        let tampered_leaf = vec![UniformRand::rand(&mut test_rng())];
+        check_merkle_tree_tampered::<CurrentNetwork, LH, PH, 3>(
+            &leaf_hasher,
+            &path_hasher,
+            &create_leaves(num_leaves),
+            &tampered_leaf,
+        )?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_incremental_bhp() -> Result<()> {
+    type LH = BHP1024<<CurrentNetwork as Network>::Affine>;
+    type PH = BHP512<<CurrentNetwork as Network>::Affine>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..16)
+        .map(|_| <CurrentNetwork as Network>::Field::rand(&mut test_rng()).to_bits_le())
+        .collect::<Vec<Vec<bool>>>();
+    check_merkle_tree_incremental::<CurrentNetwork, LH, PH, 4>(&leaf_hasher, &path_hasher, &leaves)
+}
+
+#[test]
+fn test_merkle_tree_incremental_poseidon() -> Result<()> {
+    type LH = Poseidon<<CurrentNetwork as Network>::Field, 4>;
+    type PH = Poseidon<<CurrentNetwork as Network>::Field, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let leaves = (0..16).map(|_| vec![UniformRand::rand(&mut test_rng())]).collect::<Vec<_>>();
+    check_merkle_tree_incremental::<CurrentNetwork, LH, PH, 4>(&leaf_hasher, &path_hasher, &leaves)
+}
+
 // fn merkle_path_serialization_test<P: MerkleParameters, L: ToBytes + Send + Sync + Clone + Eq>(
 //     leaves: &[L],
 //     parameters: &P,
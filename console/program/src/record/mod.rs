@@ -14,10 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod size_in_fields;
+
 use crate::{Ciphertext, Data, State};
-use snarkvm_console_account::{Address, ViewKey};
+use snarkvm_console_account::{Address, RecordCiphertext, ViewKey};
 use snarkvm_console_network::Network;
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{Choice, ConditionallySelectable, ConstantTimeEq};
 use snarkvm_utilities::{ToBits, ToBytes};
 
 use anyhow::{bail, Result};
@@ -39,21 +42,22 @@ pub struct Record<N: Network> {
     bcm: N::Field,
 }
 
+impl<N: Network> RecordCiphertext<N> for Record<N> {
+    /// Returns the nonce of the record (i.e. `G^r`).
+    fn nonce(&self) -> &N::Affine {
+        &self.nonce
+    }
+
+    /// Returns the owner tag of the record (i.e. the record MAC `Hash(G^r^view_key)`).
+    fn owner_tag(&self) -> &N::Field {
+        &self.mac
+    }
+}
+
 impl<N: Network> Record<N> {
     /// Returns `true` if this record belongs to the account of the given view key.
     pub fn is_owner(&self, view_key: &ViewKey<N>) -> bool {
-        // Compute the record view key := G^r^view_key.
-        let record_view_key = (self.nonce * **view_key).to_affine().to_x_coordinate();
-        // Compute the candidate MAC := Hash(G^r^view_key).
-        match N::hash_psd2(&[N::mac_domain(), record_view_key]) {
-            // Check if the MACs match.
-            Ok(candidate_mac) => self.mac == candidate_mac,
-            // If the computation fails, return false.
-            Err(error) => {
-                eprintln!("{error}");
-                false
-            }
-        }
+        view_key.is_owner(self)
     }
 
     /// Returns the record ID.
@@ -82,7 +86,7 @@ impl<N: Network> Record<N> {
     /// Initializes a new record by encrypting the given state with a given randomizer.
     pub fn encrypt_symmetric(state: &State<N>, record_view_key: &N::Field) -> Result<Self> {
         // Ensure the balance is less than or equal to 2^52.
-        if state.balance().to_bits_le()[52..].iter().all(|bit| !bit) {
+        if !state.balance().to_bits_le()[52..].iter().all(|bit| !bit) {
             bail!("Failed to encrypt an invalid balance into a record")
         }
         // Compute the randomizers.
@@ -107,13 +111,28 @@ impl<N: Network> Record<N> {
     }
 
     /// Returns the state corresponding to the record using the given view key.
+    ///
+    /// The decryption and every check (the MAC, the owner, the balance, the program data, and the
+    /// balance commitment) are always computed in full. The checks are compared and aggregated in
+    /// constant time, and this method only branches on the aggregated outcome, so the failure path
+    /// does not reveal which check failed, nor where a mismatch occurred.
+    ///
+    /// Note that the scalar multiplication by the view key, the recovery of the owner from its
+    /// x-coordinate, and the decryption of the program data are not constant-time.
+    ///
+    /// This method is defined on `Record`, rather than as `ViewKey::decrypt`,
+    /// as `snarkvm-console-account` (which defines `ViewKey`) cannot depend on this crate.
     pub fn decrypt(&self, view_key: &ViewKey<N>) -> Result<State<N>> {
         // Compute the record view key := G^r^view_key.
-        let record_view_key = (self.nonce * **view_key).to_affine().to_x_coordinate();
-        // Decrypt the record.
-        let state = self.decrypt_symmetric(&record_view_key)?;
-        // Ensure the owner matches the account of the given view key.
-        match *state.owner() == Address::try_from(view_key)? {
+        let record_view_key = view_key.mul_point(self.nonce).to_affine().to_x_coordinate();
+        // Decrypt the record, without branching on the outcome of the checks.
+        let (state, is_valid) = self.decrypt_symmetric_unchecked(&record_view_key)?;
+        // Check that the owner matches the account of the given view key.
+        let address = Address::try_from(view_key)?;
+        let is_owner = Self::ct_eq_field(&state.owner().to_x_coordinate(), &address.to_x_coordinate())?
+            & Self::ct_eq_field(&state.owner().to_y_coordinate(), &address.to_y_coordinate())?;
+        // Branch only on the aggregated outcome of all of the checks.
+        match bool::from(Choice::from(is_valid as u8) & is_owner) {
             // Output the state.
             true => Ok(state),
             // Abort the decryption.
@@ -122,43 +141,227 @@ impl<N: Network> Record<N> {
     }
 
     /// Returns the state corresponding to the record using the given record view key.
+    ///
+    /// As with `Record::decrypt`, this method only branches on the aggregated outcome of the checks.
     pub fn decrypt_symmetric(&self, record_view_key: &N::Field) -> Result<State<N>> {
+        // Decrypt the record, without branching on the outcome of the checks.
+        match self.decrypt_symmetric_unchecked(record_view_key)? {
+            // Output the state.
+            (state, true) => Ok(state),
+            // Abort the decryption.
+            (_, false) => bail!("Failed to decrypt using the given record view key"),
+        }
+    }
+
+    /// Returns the candidate state corresponding to the record using the given record view key,
+    /// and `true` if every decryption check passed.
+    ///
+    /// This method does not return early when a check fails. Instead, a placeholder value stands in
+    /// for any component that fails to decrypt, and the remaining steps are computed as usual.
+    /// The checks are compared and aggregated in constant time, and the owner placeholder is selected
+    /// in constant time. An error is only returned if a hash cannot be computed, which does not depend on the key.
+    fn decrypt_symmetric_unchecked(&self, record_view_key: &N::Field) -> Result<(State<N>, bool)> {
         // Compute the candidate MAC := Hash(G^r^view_key).
         let candidate_mac = N::hash_psd2(&[N::mac_domain(), *record_view_key])?;
-        // Ensure the MAC matches.
-        if self.mac != candidate_mac {
-            bail!("Failed to decrypt using the given record view key")
-        }
+        // Check that the MAC matches.
+        let is_mac_valid = Self::ct_eq_field(&self.mac, &candidate_mac)?;
 
         // Compute the randomizers.
         let randomizers = N::hash_many_psd2(&[N::encryption_domain(), *record_view_key], 3);
 
-        // Decrypt and recover the owner.
-        let owner = Address::from_group(N::affine_from_x_coordinate(self.owner - randomizers[0])?);
+        // Decrypt and recover the owner, selecting the placeholder in constant time on failure.
+        let candidate_owner = N::affine_from_x_coordinate(self.owner - randomizers[0]);
+        let is_owner_valid = Choice::from(candidate_owner.is_ok() as u8);
+        let owner =
+            N::Affine::conditional_select(&N::Affine::default(), &candidate_owner.unwrap_or_default(), is_owner_valid);
+        let owner = Address::from_group(owner);
 
         // Decrypt the balance.
         let balance = (self.balance - randomizers[1]).to_bytes_le()?;
-        // Ensure the balance is less than or equal to 2^52.
-        if balance.to_bits_le()[52..].iter().all(|bit| !bit) {
-            bail!("Failed to decrypt an invalid balance into state")
-        }
+        // Check the balance is less than or equal to 2^52, without short-circuiting.
+        let is_balance_valid =
+            !balance.to_bits_le()[52..].iter().fold(Choice::from(0), |acc, bit| acc | Choice::from(*bit as u8));
         // Recover the balance.
-        let balance = u64::from_le_bytes(balance[0..8].try_into()?);
+        let mut balance_bytes = [0u8; 8];
+        balance_bytes.copy_from_slice(&balance[0..8]);
+        let balance = u64::from_le_bytes(balance_bytes);
 
         // Decrypt the data.
-        let data = self.data.decrypt_symmetric(&(*record_view_key * randomizers[2]))?;
+        let (data, is_data_valid) = match self.data.decrypt_symmetric(&(*record_view_key * randomizers[2])) {
+            Ok(data) => (data, Choice::from(1)),
+            Err(_) => (Data::from(vec![]), Choice::from(0)),
+        };
 
         // Compute the randomizer for the balance commitment (i.e. HashToScalar(G^r^view_key));
         let r_bcm = N::hash_to_scalar_psd2(&[N::randomizer_domain(), *record_view_key])?;
         // Compute the balance commitment := G^balance H^HashToScalar(G^r^view_key).
         let candidate_bcm = N::commit_ped64(&balance.to_bits_le(), &r_bcm)?;
-        // Ensure the balance commitment matches.
-        if self.bcm != candidate_bcm {
-            bail!("Failed to decrypt the balance commitment")
+        // Check that the balance commitment matches.
+        let is_bcm_valid = Self::ct_eq_field(&self.bcm, &candidate_bcm)?;
+
+        // Aggregate the checks in constant time.
+        let is_valid = is_mac_valid & is_owner_valid & is_balance_valid & is_data_valid & is_bcm_valid;
+
+        // Output the candidate state.
+        Ok((State::from((owner, balance, data, self.nonce)), bool::from(is_valid)))
+    }
+
+    /// Returns `Choice::from(1)` if the given field elements are equal, comparing their bytes in constant time.
+    fn ct_eq_field(first: &N::Field, second: &N::Field) -> Result<Choice> {
+        Ok(first.to_bytes_le()?.ct_eq(&second.to_bytes_le()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Entry, Identifier, Literal, Plaintext};
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::{test_crypto_rng, UniformRand};
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    /// Samples a view key, and a record (with its state) that belongs to the view key.
+    fn sample_record() -> Result<(ViewKey<CurrentNetwork>, State<CurrentNetwork>, Record<CurrentNetwork>)> {
+        let rng = &mut test_crypto_rng();
+
+        // Sample a view key and address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Sample the state.
+        let randomizer: <CurrentNetwork as Network>::Scalar = UniformRand::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer).to_affine();
+        let balance = u64::rand(rng) >> 12;
+        let data = Data::from(vec![(
+            Identifier::from_str("a")?,
+            Entry::Private(Plaintext::from(Literal::Field(UniformRand::rand(rng)))),
+        )]);
+        let state = State::from((address, balance, data, nonce));
+
+        // Encrypt the state into a record.
+        let record = Record::encrypt(&state, &randomizer)?;
+        Ok((view_key, state, record))
+    }
+
+    fn check_state(expected: &State<CurrentNetwork>, candidate: &State<CurrentNetwork>) {
+        assert_eq!(expected.owner(), candidate.owner());
+        assert_eq!(expected.balance(), candidate.balance());
+        assert_eq!(expected.data(), candidate.data());
+        assert_eq!(expected.nonce(), candidate.nonce());
+    }
+
+    #[test]
+    fn test_decrypt() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            let (view_key, expected, record) = sample_record()?;
+
+            // Check that the record decrypts with the view key.
+            check_state(&expected, &record.decrypt(&view_key)?);
+
+            // Check that the record decrypts with the record view key.
+            let record_view_key = view_key.mul_point(record.nonce).to_affine().to_x_coordinate();
+            check_state(&expected, &record.decrypt_symmetric(&record_view_key)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_owner() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            let (view_key, _, record) = sample_record()?;
+            let (other_view_key, _, other_record) = sample_record()?;
+
+            // Check that a record encrypted to the address of the view key is owned by the view key.
+            assert!(view_key.is_owner(&record));
+            assert!(record.is_owner(&view_key));
+
+            // Check that a record encrypted to another address is not owned by the view key.
+            assert!(!view_key.is_owner(&other_record));
+            assert!(!other_record.is_owner(&view_key));
+            assert!(other_view_key.is_owner(&other_record));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt_fails_without_early_return() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            let (view_key, expected, mut record) = sample_record()?;
+            let record_view_key = view_key.mul_point(record.nonce).to_affine().to_x_coordinate();
+
+            // Tamper with the MAC.
+            record.mac += <CurrentNetwork as Network>::Field::from(1u128);
+
+            // Check that the full decryption is still computed when the MAC check fails,
+            // and that only the aggregated outcome reports the failure.
+            let (candidate, is_valid) = record.decrypt_symmetric_unchecked(&record_view_key)?;
+            check_state(&expected, &candidate);
+            assert!(!is_valid);
+
+            // Check that the decryption fails, with the same error regardless of which check failed.
+            let error = Some("Failed to decrypt a record with the given view key".to_string());
+            assert_eq!(error, record.decrypt(&view_key).err().map(|error| error.to_string()));
+
+            // Tamper with the balance commitment instead.
+            record.mac -= <CurrentNetwork as Network>::Field::from(1u128);
+            record.bcm += <CurrentNetwork as Network>::Field::from(1u128);
+            assert_eq!(error, record.decrypt(&view_key).err().map(|error| error.to_string()));
+
+            // Check that the decryption fails with a different view key.
+            record.bcm -= <CurrentNetwork as Network>::Field::from(1u128);
+            let (other_view_key, ..) = sample_record()?;
+            assert_eq!(error, record.decrypt(&other_view_key).err().map(|error| error.to_string()));
+
+            // Check that the untampered record still decrypts.
+            check_state(&expected, &record.decrypt(&view_key)?);
         }
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt_fails_on_tampered_record() -> Result<()> {
+        let one = <CurrentNetwork as Network>::Field::from(1u128);
+
+        for _ in 0..ITERATIONS {
+            let (view_key, expected, mut record) = sample_record()?;
+            let error = Some("Failed to decrypt a record with the given view key".to_string());
+
+            // Check that the decryption fails with the view key of another account.
+            let (other_view_key, ..) = sample_record()?;
+            assert_eq!(error, record.decrypt(&other_view_key).err().map(|error| error.to_string()));
+
+            // Check that the decryption fails with a tampered owner.
+            record.owner += one;
+            assert_eq!(error, record.decrypt(&view_key).err().map(|error| error.to_string()));
+            record.owner -= one;
+
+            // Check that the decryption fails with a tampered balance.
+            record.balance += one;
+            assert_eq!(error, record.decrypt(&view_key).err().map(|error| error.to_string()));
+            record.balance -= one;
+
+            // Check that the untampered record still decrypts.
+            check_state(&expected, &record.decrypt(&view_key)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_encrypt_fails_on_invalid_balance() -> Result<()> {
+        let (_, state, _) = sample_record()?;
+        let randomizer: <CurrentNetwork as Network>::Scalar = UniformRand::rand(&mut test_crypto_rng());
 
-        // Output the state.
-        Ok(State::from((owner, balance, data, self.nonce)))
+        // Check that a balance of 2^52 or more is rejected.
+        let state = State::from((*state.owner(), 1 << 52, state.data().clone(), *state.nonce()));
+        assert!(Record::encrypt(&state, &randomizer).is_err());
+        Ok(())
     }
 }
 
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Record<N> {
+    /// The number of field elements in the record metadata
+    /// (i.e. the owner, balance, nonce, MAC, and balance commitment).
+    const NUM_METADATA_FIELDS: u16 = 5;
+
+    /// Returns the number of field elements to encode `self`.
+    pub fn size_in_fields(&self) -> Result<u16> {
+        // Compute the number of field elements, including the record metadata.
+        let num_fields = self.data.size_in_fields()? as u32 + Self::NUM_METADATA_FIELDS as u32;
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        match num_fields <= N::MAX_DATA_SIZE_IN_FIELDS {
+            // Return the number of field elements.
+            true => Ok(num_fields as u16),
+            false => bail!("Record is too large to encode in field elements."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Entry, Identifier, Literal, Plaintext, ToFields};
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::{test_crypto_rng, UniformRand};
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_size_in_fields() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Sample a ciphertext with the given number of field elements.
+        let mut sample_ciphertext = |num_fields: usize| {
+            Ciphertext::<CurrentNetwork>::try_from((0..num_fields).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>())
+        };
+
+        // Construct several entries of each visibility.
+        let entries = vec![
+            (Identifier::from_str("a")?, Entry::Private(sample_ciphertext(1)?)),
+            (Identifier::from_str("b")?, Entry::Private(sample_ciphertext(3)?)),
+            (Identifier::from_str("c")?, Entry::Private(sample_ciphertext(17)?)),
+            (Identifier::from_str("d")?, Entry::Constant(Plaintext::from(Literal::Field(UniformRand::rand(rng))))),
+            (Identifier::from_str("e")?, Entry::Public(Plaintext::from(Literal::Scalar(UniformRand::rand(rng))))),
+            (
+                Identifier::from_str("f")?,
+                Entry::Public(Plaintext::Composite(
+                    vec![
+                        (Identifier::from_str("g")?, Plaintext::from(Literal::Group(UniformRand::rand(rng)))),
+                        (Identifier::from_str("h")?, Plaintext::from(Literal::Field(UniformRand::rand(rng)))),
+                    ],
+                    Default::default(),
+                )),
+            ),
+        ];
+
+        // Compute the actual encoded field length of the entries.
+        let mut expected = 0;
+        for (_, entry) in &entries {
+            expected += match entry {
+                Entry::Constant(plaintext) | Entry::Public(plaintext) => plaintext.to_fields()?.len(),
+                Entry::Private(ciphertext) => ciphertext.to_fields()?.len(),
+            };
+        }
+
+        let record = Record::<CurrentNetwork> {
+            owner: UniformRand::rand(rng),
+            balance: UniformRand::rand(rng),
+            data: Data::from(entries),
+            nonce: UniformRand::rand(rng),
+            mac: UniformRand::rand(rng),
+            bcm: UniformRand::rand(rng),
+        };
+
+        // Ensure the record size includes the owner, balance, nonce, MAC, and balance commitment.
+        assert_eq!(expected, record.data.size_in_fields()? as usize);
+        assert_eq!(
+            expected + Record::<CurrentNetwork>::NUM_METADATA_FIELDS as usize,
+            record.size_in_fields()? as usize
+        );
+        Ok(())
+    }
+}
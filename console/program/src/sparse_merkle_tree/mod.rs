@@ -0,0 +1,238 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod path;
+pub use path::*;
+
+#[cfg(test)]
+mod tests;
+
+use crate::{LeafHash, PathHash};
+use snarkvm_console_network::Network;
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::ToBits;
+
+use anyhow::{bail, Error, Result};
+use std::collections::{BTreeMap, HashMap};
+
+/// A sparse Merkle tree of depth `DEPTH`, keyed by field elements.
+///
+/// Each key is placed at the leaf position given by its `DEPTH` least significant bits.
+/// An occupied leaf is `path_hasher.hash(key, leaf_hasher.hash(value))`,
+/// and an empty leaf is `path_hasher.hash_empty()`.
+///
+/// Note: If `DEPTH` is less than the size of the field, two keys may share a leaf position.
+/// In this case, inserting a key into a position that is occupied by another key fails.
+#[derive(Clone)]
+pub struct SparseMerkleTree<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> {
+    /// The hash function for the leaf nodes.
+    leaf_hasher: LH,
+    /// The hash function for the path nodes.
+    path_hasher: PH,
+    /// The computed root of the sparse Merkle tree.
+    root: N::Field,
+    /// The hashes of the empty subtrees, indexed by height (from the leaves to the root).
+    empty_hashes: Vec<N::Field>,
+    /// The hashes of the non-empty nodes, keyed by height and position.
+    nodes: HashMap<(u8, Vec<bool>), N::Field>,
+    /// The key and value of each occupied leaf, keyed by position.
+    leaves: BTreeMap<Vec<bool>, (N::Field, LH::Leaf)>,
+}
+
+impl<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> SparseMerkleTree<N, LH, PH, DEPTH> {
+    /// Returns a new empty sparse Merkle tree.
+    #[inline]
+    pub fn new(leaf_hasher: &LH, path_hasher: &PH) -> Result<Self> {
+        // Ensure the DEPTH is non-zero.
+        if DEPTH == 0 {
+            bail!("The depth of the sparse Merkle tree must be non-zero.");
+        }
+        // Ensure the DEPTH does not exceed the number of bits in a key.
+        if DEPTH as usize > N::Field::size_in_bits() {
+            bail!("The depth of the sparse Merkle tree cannot exceed {} bits.", N::Field::size_in_bits());
+        }
+
+        // Compute the hash of the empty subtree at each height.
+        let mut empty_hashes = Vec::with_capacity(DEPTH as usize + 1);
+        empty_hashes.push(path_hasher.hash_empty()?);
+        for height in 0..DEPTH as usize {
+            empty_hashes.push(path_hasher.hash(&empty_hashes[height], &empty_hashes[height])?);
+        }
+
+        Ok(Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            root: empty_hashes[DEPTH as usize],
+            empty_hashes,
+            nodes: Default::default(),
+            leaves: Default::default(),
+        })
+    }
+
+    /// Inserts the given key and value into the tree, returning the previous value for the key (if any).
+    /// On failure, the tree is left unchanged.
+    #[inline]
+    pub fn insert(&mut self, key: N::Field, value: LH::Leaf) -> Result<Option<LH::Leaf>> {
+        // Compute the leaf position of the key.
+        let position = leaf_position::<N, DEPTH>(&key);
+        // Ensure the leaf position is not occupied by another key.
+        if let Some((existing_key, _)) = self.leaves.get(&position) {
+            if *existing_key != key {
+                bail!("Failed to insert into the sparse Merkle tree: the leaf position is occupied by another key")
+            }
+        }
+
+        // Compute the leaf hash, and update the path from the leaf to the root.
+        let leaf_hash = self.path_hasher.hash(&key, &self.leaf_hasher.hash(&value)?)?;
+        self.update_path(&position, leaf_hash)?;
+
+        // Store the key and value.
+        Ok(self.leaves.insert(position, (key, value)).map(|(_, previous)| previous))
+    }
+
+    /// Removes the given key from the tree, returning its value (if any).
+    /// On failure, the tree is left unchanged.
+    #[inline]
+    pub fn remove(&mut self, key: &N::Field) -> Result<Option<LH::Leaf>> {
+        // Compute the leaf position of the key.
+        let position = leaf_position::<N, DEPTH>(key);
+        // If the key is not in the tree, there is nothing to remove.
+        match self.leaves.get(&position) {
+            Some((existing_key, _)) if existing_key == key => (),
+            _ => return Ok(None),
+        }
+
+        // Reset the leaf to the empty hash, and update the path from the leaf to the root.
+        self.update_path(&position, self.empty_hashes[0])?;
+
+        // Remove the key and value.
+        Ok(self.leaves.remove(&position).map(|(_, value)| value))
+    }
+
+    /// Returns the value for the given key, if it is in the tree.
+    #[inline]
+    pub fn get(&self, key: &N::Field) -> Option<&LH::Leaf> {
+        match self.leaves.get(&leaf_position::<N, DEPTH>(key)) {
+            Some((existing_key, value)) if existing_key == key => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the Merkle path proving the given key is in the tree.
+    #[inline]
+    pub fn prove_membership(&self, key: &N::Field) -> Result<SparseMerklePath<N, DEPTH>> {
+        match self.get(key) {
+            Some(_) => self.prove(&leaf_position::<N, DEPTH>(key)),
+            None => bail!("Failed to prove membership in the sparse Merkle tree: the key is not in the tree"),
+        }
+    }
+
+    /// Returns the Merkle path proving the given key is not in the tree, by proving its leaf position is empty.
+    #[inline]
+    pub fn prove_non_membership(&self, key: &N::Field) -> Result<SparseMerklePath<N, DEPTH>> {
+        let position = leaf_position::<N, DEPTH>(key);
+        match self.leaves.get(&position) {
+            None => self.prove(&position),
+            Some((existing_key, _)) if existing_key == key => {
+                bail!("Failed to prove non-membership in the sparse Merkle tree: the key is in the tree")
+            }
+            Some(_) => {
+                bail!("Failed to prove non-membership in the sparse Merkle tree: the leaf position is occupied")
+            }
+        }
+    }
+
+    /// Returns the root of the sparse Merkle tree.
+    #[inline]
+    pub const fn root(&self) -> &N::Field {
+        &self.root
+    }
+
+    /// Returns the number of keys in the tree.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Returns `true` if the tree contains no keys.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Returns the Merkle path for the given leaf position.
+    #[inline]
+    fn prove(&self, position: &[bool]) -> Result<SparseMerklePath<N, DEPTH>> {
+        let path =
+            (0..DEPTH).map(|height| self.node(height, sibling(&position[height as usize..]))).collect::<Vec<_>>();
+        SparseMerklePath::try_from(path)
+    }
+
+    /// Updates the path from the given leaf position to the root, given the new leaf hash.
+    #[inline]
+    fn update_path(&mut self, position: &[bool], leaf_hash: N::Field) -> Result<()> {
+        // Compute the hashes from the leaf to the root.
+        let mut current_hash = leaf_hash;
+        let mut updates = Vec::with_capacity(DEPTH as usize + 1);
+        updates.push(((0, position.to_vec()), current_hash));
+        for height in 0..DEPTH {
+            let index = height as usize;
+            // Retrieve the sibling, and compute Hash(left || right).
+            let sibling_hash = self.node(height, sibling(&position[index..]));
+            current_hash = match position[index] {
+                false => self.path_hasher.hash(&current_hash, &sibling_hash)?,
+                true => self.path_hasher.hash(&sibling_hash, &current_hash)?,
+            };
+            updates.push(((height + 1, position[index + 1..].to_vec()), current_hash));
+        }
+
+        // Update the values at the very end so the tree is not altered in case of failure.
+        for ((height, node_position), hash) in updates {
+            // Only store the nodes that are not empty.
+            match hash == self.empty_hashes[height as usize] {
+                true => self.nodes.remove(&(height, node_position)),
+                false => self.nodes.insert((height, node_position), hash),
+            };
+        }
+        self.root = current_hash;
+        Ok(())
+    }
+
+    /// Returns the hash of the node at the given height and position.
+    #[inline]
+    fn node(&self, height: u8, position: Vec<bool>) -> N::Field {
+        match self.nodes.get(&(height, position)) {
+            Some(hash) => *hash,
+            None => self.empty_hashes[height as usize],
+        }
+    }
+}
+
+/// Returns the leaf position of the given key, as its `DEPTH` least significant bits.
+#[inline]
+fn leaf_position<N: Network, const DEPTH: u8>(key: &N::Field) -> Vec<bool> {
+    let mut position = key.to_bits_le();
+    position.truncate(DEPTH as usize);
+    position
+}
+
+/// Returns the position of the sibling, given the position of a node.
+#[inline]
+fn sibling(position: &[bool]) -> Vec<bool> {
+    let mut sibling = position.to_vec();
+    sibling[0] = !sibling[0];
+    sibling
+}
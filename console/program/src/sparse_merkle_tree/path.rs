@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Stores the sibling hashes of a path (in order) from the leaf to the root of a sparse Merkle tree.
+/// The leaf position is given by the key, so it is not stored in the path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseMerklePath<N: Network, const DEPTH: u8> {
+    siblings: Vec<N::Field>,
+}
+
+impl<N: Network, const DEPTH: u8> TryFrom<Vec<N::Field>> for SparseMerklePath<N, DEPTH> {
+    type Error = Error;
+
+    /// Returns a new instance of a sparse Merkle path.
+    fn try_from(siblings: Vec<N::Field>) -> Result<Self> {
+        // Ensure the sparse Merkle path is the correct length.
+        match siblings.len() == DEPTH as usize {
+            // Return the sparse Merkle path.
+            true => Ok(Self { siblings }),
+            false => bail!("Expected a sparse Merkle path of length {DEPTH}, found length {}", siblings.len()),
+        }
+    }
+}
+
+impl<N: Network, const DEPTH: u8> SparseMerklePath<N, DEPTH> {
+    /// Returns the sibling hashes of the sparse Merkle path, from leaf to root.
+    pub fn siblings(&self) -> &[N::Field] {
+        &self.siblings
+    }
+
+    /// Returns `true` if the path proves the given key and value are in the tree with the given root.
+    pub fn verify_membership<LH: LeafHash<N>, PH: PathHash<N>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &N::Field,
+        key: &N::Field,
+        value: &LH::Leaf,
+    ) -> bool {
+        // Compute the leaf hash, and return `false` on failure.
+        let leaf_hash = match leaf_hasher.hash(value).and_then(|value_hash| path_hasher.hash(key, &value_hash)) {
+            Ok(hash) => hash,
+            Err(error) => {
+                eprintln!("Failed to hash leaf during sparse Merkle path verification: {error}");
+                return false;
+            }
+        };
+        self.verify(path_hasher, root, key, leaf_hash)
+    }
+
+    /// Returns `true` if the path proves the given key is not in the tree with the given root,
+    /// by proving the leaf at the position of the key is empty.
+    pub fn verify_non_membership<PH: PathHash<N>>(&self, path_hasher: &PH, root: &N::Field, key: &N::Field) -> bool {
+        // Compute the empty leaf hash, and return `false` on failure.
+        let leaf_hash = match path_hasher.hash_empty() {
+            Ok(hash) => hash,
+            Err(error) => {
+                eprintln!("Failed to hash leaf during sparse Merkle path verification: {error}");
+                return false;
+            }
+        };
+        self.verify(path_hasher, root, key, leaf_hash)
+    }
+
+    /// Returns `true` if the path from the given leaf hash at the position of the key leads to the given root.
+    fn verify<PH: PathHash<N>>(&self, path_hasher: &PH, root: &N::Field, key: &N::Field, leaf_hash: N::Field) -> bool {
+        // Ensure the path length matches the expected depth.
+        if self.siblings.len() != DEPTH as usize {
+            return false;
+        }
+
+        let mut current_hash = leaf_hash;
+
+        // Check levels between leaf level and root.
+        for (is_right, sibling_hash) in leaf_position::<N, DEPTH>(key).into_iter().zip(&self.siblings) {
+            // Check if path node at this level is left or right, and update the current path node.
+            let hash = match is_right {
+                false => path_hasher.hash(&current_hash, sibling_hash),
+                true => path_hasher.hash(sibling_hash, &current_hash),
+            };
+            match hash {
+                Ok(hash) => current_hash = hash,
+                Err(error) => {
+                    eprintln!("Failed to hash path node during sparse Merkle path verification: {error}");
+                    return false;
+                }
+            }
+        }
+
+        // Check if final hash is root.
+        current_hash == *root
+    }
+}
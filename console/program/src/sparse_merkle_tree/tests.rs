@@ -0,0 +1,230 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_network::Testnet3;
+
+use snarkvm_utilities::{test_rng, UniformRand};
+
+type CurrentNetwork = Testnet3;
+
+type LH = Poseidon<<CurrentNetwork as Network>::Field, 4>;
+type PH = Poseidon<<CurrentNetwork as Network>::Field, 2>;
+
+const DEPTH: u8 = 32;
+const ITERATIONS: usize = 10;
+
+/// Returns a new sparse Merkle tree of the given depth, with Poseidon leaf and path hashers.
+fn sample_tree<const DEPTH: u8>() -> Result<(LH, PH, SparseMerkleTree<CurrentNetwork, LH, PH, DEPTH>)> {
+    let leaf_hasher = LH::setup("AleoSMTTest0")?;
+    let path_hasher = PH::setup("AleoSMTTest1")?;
+    let tree = SparseMerkleTree::new(&leaf_hasher, &path_hasher)?;
+    Ok((leaf_hasher, path_hasher, tree))
+}
+
+#[test]
+fn test_depth() -> Result<()> {
+    // Ensure DEPTH = 0 fails.
+    assert!(sample_tree::<0>().is_err());
+    // Ensure a DEPTH beyond the size of the key fails.
+    assert!(sample_tree::<254>().is_err());
+    // Ensure the maximum DEPTH succeeds.
+    assert!(sample_tree::<253>().is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_insert_and_prove_membership() -> Result<()> {
+    let (leaf_hasher, path_hasher, mut tree) = sample_tree::<DEPTH>()?;
+    let rng = &mut test_rng();
+
+    let mut entries = vec![];
+    for _ in 0..ITERATIONS {
+        // Insert a new key and value.
+        let key = UniformRand::rand(rng);
+        let value = vec![UniformRand::rand(rng)];
+        let previous_root = *tree.root();
+        assert_eq!(None, tree.insert(key, value.clone())?);
+        assert_ne!(previous_root, *tree.root());
+        assert_eq!(Some(&value), tree.get(&key));
+        entries.push((key, value));
+    }
+    assert_eq!(ITERATIONS, tree.len());
+
+    for (key, value) in &entries {
+        // Ensure the membership proof is valid.
+        let path = tree.prove_membership(key)?;
+        assert!(path.verify_membership(&leaf_hasher, &path_hasher, tree.root(), key, value));
+        // Ensure the membership proof **fails** on an invalid value, root, or key.
+        let wrong_value = vec![UniformRand::rand(rng)];
+        assert!(!path.verify_membership(&leaf_hasher, &path_hasher, tree.root(), key, &wrong_value));
+        assert!(!path.verify_membership(&leaf_hasher, &path_hasher, &UniformRand::rand(rng), key, value));
+        assert!(!path.verify_membership(&leaf_hasher, &path_hasher, tree.root(), &UniformRand::rand(rng), value));
+        // Ensure the membership proof does not prove non-membership.
+        assert!(!path.verify_non_membership(&path_hasher, tree.root(), key));
+        // Ensure a non-membership proof cannot be produced for the key.
+        assert!(tree.prove_non_membership(key).is_err());
+    }
+
+    // Ensure updating the value of a key returns the previous value.
+    let (key, value) = &entries[0];
+    let new_value = vec![UniformRand::rand(rng)];
+    assert_eq!(Some(value.clone()), tree.insert(*key, new_value.clone())?);
+    assert_eq!(ITERATIONS, tree.len());
+    let path = tree.prove_membership(key)?;
+    assert!(path.verify_membership(&leaf_hasher, &path_hasher, tree.root(), key, &new_value));
+    assert!(!path.verify_membership(&leaf_hasher, &path_hasher, tree.root(), key, value));
+    Ok(())
+}
+
+#[test]
+fn test_prove_non_membership() -> Result<()> {
+    let (leaf_hasher, path_hasher, mut tree) = sample_tree::<DEPTH>()?;
+    let rng = &mut test_rng();
+
+    // Ensure non-membership can be proven in an empty tree.
+    let key = UniformRand::rand(rng);
+    assert!(tree.prove_membership(&key).is_err());
+    let path = tree.prove_non_membership(&key)?;
+    assert!(path.verify_non_membership(&path_hasher, tree.root(), &key));
+
+    for _ in 0..ITERATIONS {
+        tree.insert(UniformRand::rand(rng), vec![UniformRand::rand(rng)])?;
+    }
+
+    for _ in 0..ITERATIONS {
+        // Ensure the non-membership proof is valid.
+        let key = UniformRand::rand(rng);
+        assert!(tree.prove_membership(&key).is_err());
+        let path = tree.prove_non_membership(&key)?;
+        assert!(path.verify_non_membership(&path_hasher, tree.root(), &key));
+        // Ensure the non-membership proof **fails** on an invalid root.
+        assert!(!path.verify_non_membership(&path_hasher, &UniformRand::rand(rng), &key));
+        // Ensure the non-membership proof does not prove membership.
+        let value = vec![UniformRand::rand(rng)];
+        assert!(!path.verify_membership(&leaf_hasher, &path_hasher, tree.root(), &key, &value));
+
+        // Ensure the non-membership proof **fails** once the key is inserted.
+        let mut tree = tree.clone();
+        tree.insert(key, value)?;
+        assert!(!path.verify_non_membership(&path_hasher, tree.root(), &key));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_remove_restores_root() -> Result<()> {
+    let (_, _, mut tree) = sample_tree::<DEPTH>()?;
+    let rng = &mut test_rng();
+
+    let empty_root = *tree.root();
+
+    // Insert each key, storing the root before each insertion.
+    let mut entries = vec![];
+    for _ in 0..ITERATIONS {
+        let key = UniformRand::rand(rng);
+        let value = vec![UniformRand::rand(rng)];
+        entries.push((*tree.root(), key, value.clone()));
+        tree.insert(key, value)?;
+    }
+
+    // Ensure removing a key that is not in the tree does not change the root.
+    let root = *tree.root();
+    assert_eq!(None, tree.remove(&UniformRand::rand(rng))?);
+    assert_eq!(root, *tree.root());
+
+    // Remove each key in reverse order, and ensure the prior root is restored.
+    for (previous_root, key, value) in entries.into_iter().rev() {
+        assert_eq!(Some(value), tree.remove(&key)?);
+        assert_eq!(previous_root, *tree.root());
+        assert_eq!(None, tree.get(&key));
+        assert_eq!(None, tree.remove(&key)?);
+    }
+
+    // Ensure the tree is empty, and matches a new tree.
+    assert!(tree.is_empty());
+    assert!(tree.nodes.is_empty());
+    assert_eq!(empty_root, *tree.root());
+    Ok(())
+}
+
+#[test]
+fn test_root_is_independent_of_insertion_order() -> Result<()> {
+    let (_, _, mut tree) = sample_tree::<DEPTH>()?;
+    let (_, _, mut reversed) = sample_tree::<DEPTH>()?;
+    let rng = &mut test_rng();
+
+    let entries = (0..ITERATIONS).map(|_| (UniformRand::rand(rng), vec![UniformRand::rand(rng)])).collect::<Vec<_>>();
+    for (key, value) in &entries {
+        tree.insert(*key, value.clone())?;
+    }
+    for (key, value) in entries.iter().rev() {
+        reversed.insert(*key, value.clone())?;
+    }
+    assert_eq!(tree.root(), reversed.root());
+    Ok(())
+}
+
+#[test]
+fn test_occupied_leaf_position() -> Result<()> {
+    let (_, _, mut tree) = sample_tree::<2>()?;
+
+    // Ensure keys with the same 2 least significant bits share a leaf position.
+    let key = <CurrentNetwork as Network>::Field::from(1u64);
+    let other_key = <CurrentNetwork as Network>::Field::from(5u64);
+    tree.insert(key, vec![key])?;
+
+    // Ensure inserting the other key fails, and leaves the tree unchanged.
+    let root = *tree.root();
+    assert!(tree.insert(other_key, vec![other_key]).is_err());
+    assert_eq!(root, *tree.root());
+    assert_eq!(None, tree.get(&other_key));
+    assert_eq!(None, tree.remove(&other_key)?);
+    assert_eq!(root, *tree.root());
+
+    // Ensure membership and non-membership cannot be proven for the other key.
+    assert!(tree.prove_membership(&other_key).is_err());
+    assert!(tree.prove_non_membership(&other_key).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_sparse_merkle_tree_bhp() -> Result<()> {
+    type LH = BHP1024<<CurrentNetwork as Network>::Affine>;
+    type PH = BHP512<<CurrentNetwork as Network>::Affine>;
+
+    let leaf_hasher = LH::setup("AleoSMTTest0")?;
+    let path_hasher = PH::setup("AleoSMTTest1")?;
+    let mut tree = SparseMerkleTree::<CurrentNetwork, LH, PH, 16>::new(&leaf_hasher, &path_hasher)?;
+    let rng = &mut test_rng();
+
+    let empty_root = *tree.root();
+    let key = UniformRand::rand(rng);
+    let value = <CurrentNetwork as Network>::Field::rand(rng).to_bits_le();
+
+    // Ensure non-membership is proven before insertion, and membership is proven after insertion.
+    let path = tree.prove_non_membership(&key)?;
+    assert!(path.verify_non_membership(&path_hasher, tree.root(), &key));
+    tree.insert(key, value.clone())?;
+    let path = tree.prove_membership(&key)?;
+    assert!(path.verify_membership(&leaf_hasher, &path_hasher, tree.root(), &key, &value));
+
+    // Ensure removing the key restores the empty root.
+    tree.remove(&key)?;
+    assert_eq!(empty_root, *tree.root());
+    Ok(())
+}
@@ -14,9 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use snarkvm_console_algorithms::{Hash, Poseidon};
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{FromBits, ToBits};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 
 /// Unary operator for converting to a list of base fields.
 pub trait ToFields {
@@ -35,3 +37,127 @@ pub trait FromFields {
     where
         Self: Sized;
 }
+
+/// Unary operator for packing the little-endian bits of a type into a list of base fields.
+pub trait ToPackedFields: ToBits {
+    /// Returns the little-endian bits of `self` as a list of base field elements.
+    ///
+    /// A terminus bit is appended to the bits, which are then packed into chunks of
+    /// `F::size_in_data_bits()` bits, matching the encoding used by `Plaintext::to_fields`.
+    fn to_packed_fields<F: PrimeField>(&self) -> Result<Vec<F>> {
+        // Encode the data as little-endian bits.
+        let mut bits_le = self.to_bits_le();
+        // Adds one final bit to the data, to serve as a terminus indicator.
+        bits_le.push(true);
+        // Pack the bits into field elements.
+        bits_le
+            .chunks(F::size_in_data_bits())
+            .map(|bits_le| {
+                F::from_repr(F::BigInteger::from_bits_le(bits_le)?)
+                    .ok_or_else(|| anyhow!("Invalid field element from bits"))
+            })
+            .collect()
+    }
+}
+
+impl<T: ToBits> ToPackedFields for T {}
+
+/// Unary operator for unpacking a type from a list of base fields, using its little-endian bits.
+pub trait FromPackedFields: FromBits {
+    /// Initializes `Self` from a list of base field elements, produced by `ToPackedFields::to_packed_fields`.
+    fn from_packed_fields<F: PrimeField>(fields: &[F]) -> Result<Self> {
+        // Unpack the field elements into little-endian bits, and reverse the list for popping the terminus bit off.
+        let mut bits_le = fields.iter().flat_map(|field| field.to_bits_le()[..F::size_in_data_bits()].to_vec()).rev();
+        // Remove the terminus bit that was added during encoding.
+        for boolean in bits_le.by_ref() {
+            // Drop all extraneous `0` bits, in addition to the final `1` bit.
+            if boolean {
+                break;
+            }
+        }
+        // Reverse the bits back and recover the data from the bits.
+        Self::from_bits_le(&bits_le.rev().collect::<Vec<_>>())
+    }
+}
+
+impl<T: FromBits> FromPackedFields for T {}
+
+/// Unary operator for hashing any value that converts to a list of base fields.
+pub trait HashValue<F: PrimeField> {
+    /// Returns the hash of the base field elements of the given value.
+    fn hash_value<T: ToFields<Field = F>>(&self, value: &T) -> Result<F>;
+}
+
+impl<F: PrimeField, const RATE: usize> HashValue<F> for Poseidon<F, RATE> {
+    /// Returns the Poseidon hash of `value.to_fields()`.
+    fn hash_value<T: ToFields<Field = F>>(&self, value: &T) -> Result<F> {
+        self.hash(&value.to_fields()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Ciphertext, Identifier, Literal, Plaintext, Visibility};
+    use snarkvm_console_network::{Network, Testnet3};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_packed_fields() -> Result<()> {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            let plaintext = Plaintext::<CurrentNetwork>::Composite(
+                vec![
+                    (Identifier::from_str("a")?, Plaintext::from(Literal::Field(UniformRand::rand(rng)))),
+                    (Identifier::from_str("b")?, Plaintext::from(Literal::Scalar(UniformRand::rand(rng)))),
+                    (Identifier::from_str("c")?, Plaintext::from(Literal::Boolean(UniformRand::rand(rng)))),
+                ],
+                Default::default(),
+            );
+
+            // Ensure the packed fields match the plaintext encoding.
+            let fields = plaintext.to_packed_fields::<<CurrentNetwork as Network>::Field>()?;
+            assert_eq!(plaintext.size_in_fields()? as usize, fields.len());
+            assert_eq!(ToFields::to_fields(&plaintext)?, fields);
+            // Ensure the packing is reversible.
+            assert_eq!(plaintext, Plaintext::from_packed_fields(&fields)?);
+
+            // Ensure the ciphertext is packable, and the packing is reversible.
+            let ciphertext = Ciphertext::<CurrentNetwork>::from_fields(&fields)?;
+            let packed = ciphertext.to_packed_fields::<<CurrentNetwork as Network>::Field>()?;
+            // Ensure the field count is the bit count plus the terminus bit, divided by the data bits (rounded up).
+            let size_in_data_bits = <CurrentNetwork as Network>::Field::size_in_data_bits();
+            let num_bits = ciphertext.to_bits_le().len() + 1;
+            assert_eq!((num_bits + size_in_data_bits - 1) / size_in_data_bits, packed.len());
+            assert_eq!(ciphertext, Ciphertext::from_packed_fields(&packed)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_value() -> Result<()> {
+        let rng = &mut test_rng();
+        let poseidon = Poseidon::<<CurrentNetwork as Network>::Field, 4>::setup("HashValueTest")?;
+
+        for _ in 0..ITERATIONS {
+            let plaintext = Plaintext::<CurrentNetwork>::Composite(
+                vec![
+                    (Identifier::from_str("a")?, Plaintext::from(Literal::Field(UniformRand::rand(rng)))),
+                    (Identifier::from_str("b")?, Plaintext::from(Literal::Boolean(UniformRand::rand(rng)))),
+                ],
+                Default::default(),
+            );
+
+            // Ensure the hash matches the hash of the base fields.
+            assert_eq!(poseidon.hash(&ToFields::to_fields(&plaintext)?)?, poseidon.hash_value(&plaintext)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::BigInteger;
+
+/// Operations on prime field elements, using the extended Euclidean algorithm
+/// over the canonical integer representation of the field elements.
+///
+/// These are provided as a cross-check for the Montgomery-based operations of the field.
+pub trait ExtendedGcd: PrimeField {
+    /// Returns the greatest common divisor of the canonical representations of `self` and `other`.
+    fn gcd(&self, other: &Self) -> Self::BigInteger {
+        let (mut a, mut b) = (self.to_repr(), other.to_repr());
+        // Handle the case where either element is zero.
+        if a.is_zero() {
+            return b;
+        } else if b.is_zero() {
+            return a;
+        }
+        // Remove the common factors of two.
+        let mut shift = 0;
+        while a.is_even() && b.is_even() {
+            a.div2();
+            b.div2();
+            shift += 1;
+        }
+        // Compute the binary GCD of the remaining odd values.
+        while a.is_even() {
+            a.div2();
+        }
+        while !b.is_zero() {
+            while b.is_even() {
+                b.div2();
+            }
+            if a > b {
+                core::mem::swap(&mut a, &mut b);
+            }
+            b.sub_noborrow(&a);
+        }
+        // Restore the common factors of two.
+        a.muln(shift);
+        a
+    }
+
+    /// Returns the multiplicative inverse of `self`, using the binary extended Euclidean algorithm.
+    /// Returns `None` if `self` is zero.
+    fn inverse_via_egcd(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+
+        let modulus = Self::modulus();
+        let one = Self::BigInteger::from(1u64);
+
+        // Maintain the invariants `x1 * self == u` and `x2 * self == v` (mod p).
+        let (mut u, mut v) = (self.to_repr(), modulus);
+        let (mut x1, mut x2) = (one, Self::BigInteger::from(0u64));
+
+        while u != one && v != one {
+            while u.is_even() {
+                u.div2();
+                halve_mod(&mut x1, &modulus);
+            }
+            while v.is_even() {
+                v.div2();
+                halve_mod(&mut x2, &modulus);
+            }
+            if u >= v {
+                u.sub_noborrow(&v);
+                sub_mod(&mut x1, &x2, &modulus);
+            } else {
+                v.sub_noborrow(&u);
+                sub_mod(&mut x2, &x1, &modulus);
+            }
+        }
+
+        Self::from_repr(if u == one { x1 } else { x2 })
+    }
+}
+
+impl<F: PrimeField> ExtendedGcd for F {}
+
+/// Sets `x := x / 2 (mod p)`, for `x < p`.
+///
+/// Note: If `x` is odd, `x + p` may overflow the representation, in which case the carry bit
+/// is shifted back in as the most significant bit.
+fn halve_mod<B: BigInteger>(x: &mut B, modulus: &B) {
+    let carry = x.is_odd() && x.add_nocarry(modulus);
+    x.div2();
+    if carry {
+        if let Some(limb) = x.as_mut().last_mut() {
+            *limb |= 1 << 63;
+        }
+    }
+}
+
+/// Sets `x := x - y (mod p)`, for `x, y < p`.
+fn sub_mod<B: BigInteger>(x: &mut B, y: &B, modulus: &B) {
+    if *x < *y {
+        x.add_nocarry(modulus);
+    }
+    x.sub_noborrow(y);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::{Fq, Fr};
+    use snarkvm_utilities::{test_rng, BigInteger256, UniformRand};
+
+    const ITERATIONS: usize = 1000;
+
+    fn check_inverse_via_egcd<F: PrimeField>() {
+        // Ensure zero has no inverse.
+        assert_eq!(None, F::zero().inverse_via_egcd());
+        // Ensure the inverse of one is one.
+        assert_eq!(Some(F::one()), F::one().inverse_via_egcd());
+        // Ensure the inverse of -1 is -1.
+        assert_eq!(Some(-F::one()), (-F::one()).inverse_via_egcd());
+
+        for _ in 0..ITERATIONS {
+            // Sample a random element.
+            let element = F::rand(&mut test_rng());
+            // Ensure the inverse matches the native inverse.
+            assert_eq!(element.inverse(), element.inverse_via_egcd());
+        }
+    }
+
+    #[test]
+    fn test_inverse_via_egcd() {
+        check_inverse_via_egcd::<Fr>();
+        check_inverse_via_egcd::<Fq>();
+    }
+
+    #[test]
+    fn test_halve_mod() {
+        fn check_halve_mod<B: BigInteger>(modulus: B) {
+            let one = B::from(1u64);
+            let (mut p_minus_one, mut p_minus_two) = (modulus, modulus);
+            p_minus_one.sub_noborrow(&one);
+            p_minus_two.sub_noborrow(&B::from(2u64));
+
+            // Ensure `(p - 1) / 2` is halved without a reduction.
+            let mut expected = p_minus_one;
+            expected.div2();
+            let mut candidate = p_minus_one;
+            halve_mod(&mut candidate, &modulus);
+            assert_eq!(expected, candidate);
+
+            // Ensure `(p - 2) / 2 == (2p - 2) / 2 == p - 1 (mod p)`.
+            let mut candidate = p_minus_two;
+            halve_mod(&mut candidate, &modulus);
+            assert_eq!(p_minus_one, candidate);
+
+            // Ensure `1 / 2 == (p + 1) / 2 (mod p)`.
+            let mut expected = p_minus_one;
+            expected.div2();
+            expected.add_nocarry(&one);
+            let mut candidate = one;
+            halve_mod(&mut candidate, &modulus);
+            assert_eq!(expected, candidate);
+        }
+
+        // Check the field moduli, which leave a spare top bit.
+        check_halve_mod(Fr::modulus());
+        check_halve_mod(Fq::modulus());
+        // Check a modulus without a spare top bit (i.e. `2^256 - 189`), for which `x + p` overflows.
+        check_halve_mod(BigInteger256::new([u64::MAX - 188, u64::MAX, u64::MAX, u64::MAX]));
+    }
+
+    #[test]
+    fn test_gcd() {
+        let gcd = |a: u64, b: u64| Fr::from(a as u128).gcd(&Fr::from(b as u128));
+        assert_eq!(<Fr as PrimeField>::BigInteger::from(6u64), gcd(12, 18));
+        assert_eq!(<Fr as PrimeField>::BigInteger::from(1u64), gcd(17, 31));
+        assert_eq!(<Fr as PrimeField>::BigInteger::from(8u64), gcd(8, 0));
+        assert_eq!(<Fr as PrimeField>::BigInteger::from(8u64), gcd(0, 8));
+        assert_eq!(<Fr as PrimeField>::BigInteger::from(0u64), gcd(0, 0));
+        assert_eq!(<Fr as PrimeField>::BigInteger::from(48u64), gcd(96, 144));
+
+        for _ in 0..ITERATIONS {
+            let (a, b, c) = (u32::rand(&mut test_rng()), u32::rand(&mut test_rng()), u32::rand(&mut test_rng()));
+            // Ensure the gcd is symmetric, and divides out common factors.
+            let expected = gcd(a as u64 * c as u64, b as u64 * c as u64);
+            assert_eq!(expected, gcd(b as u64 * c as u64, a as u64 * c as u64));
+            // Ensure the gcd matches the gcd of the native integers.
+            let (mut x, mut y) = (a as u64 * c as u64, b as u64 * c as u64);
+            while y != 0 {
+                (x, y) = (y, x % y);
+            }
+            assert_eq!(<Fr as PrimeField>::BigInteger::from(x), expected);
+        }
+    }
+}
@@ -16,3 +16,6 @@
 
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
+
+mod egcd;
+pub use egcd::*;
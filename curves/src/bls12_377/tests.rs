@@ -28,6 +28,7 @@ use crate::{
         Fq6Parameters,
         FqParameters,
         Fr,
+        FrParameters,
         G1Affine,
         G1Projective,
         G2Affine,
@@ -58,7 +59,7 @@ use snarkvm_fields::{
     Zero,
 };
 use snarkvm_utilities::{
-    biginteger::{BigInteger, BigInteger384},
+    biginteger::{BigInteger, BigInteger256, BigInteger384},
     rand::{test_rng, UniformRand},
 };
 
@@ -129,6 +130,36 @@ fn test_bls12_377_fq12() {
     field_serialization_test::<Fq12>();
 }
 
+#[test]
+fn test_fr_montgomery_limbs() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let scalar = Fr::rand(&mut rng);
+        let limbs = scalar.to_montgomery_limbs();
+        // The limbs are the internal Montgomery representation, not the canonical integer.
+        assert_eq!(limbs, scalar.0.0);
+        assert_eq!(Some(scalar), Fr::from_montgomery_limbs(limbs));
+    }
+
+    // Zero and one round-trip.
+    assert_eq!(Some(Fr::zero()), Fr::from_montgomery_limbs(Fr::zero().to_montgomery_limbs()));
+    assert_eq!(Some(Fr::one()), Fr::from_montgomery_limbs(Fr::one().to_montgomery_limbs()));
+
+    // The modulus, and anything above it, is rejected.
+    let modulus = FrParameters::MODULUS;
+    assert_eq!(None, Fr::from_montgomery_limbs(modulus.0));
+    let mut modulus_plus_one = modulus;
+    modulus_plus_one.add_nocarry(&BigInteger256::from(1));
+    assert_eq!(None, Fr::from_montgomery_limbs(modulus_plus_one.0));
+    assert_eq!(None, Fr::from_montgomery_limbs([u64::MAX; 4]));
+
+    // The largest canonical value is accepted.
+    let mut modulus_minus_one = modulus;
+    modulus_minus_one.sub_noborrow(&BigInteger256::from(1));
+    assert!(Fr::from_montgomery_limbs(modulus_minus_one.0).is_some());
+}
+
 #[test]
 fn test_fq_repr_from() {
     assert_eq!(BigInteger384::from(100), BigInteger384([100, 0, 0, 0, 0, 0]));
@@ -27,7 +27,17 @@ use crate::{
         TwistedEdwardsParameters,
     },
 };
-use snarkvm_fields::{Field, LegendreSymbol, One, SquareRootField, Zero};
+use snarkvm_fields::{
+    Choice,
+    ConditionallySelectable,
+    Field,
+    Fp256,
+    Fp256Parameters,
+    LegendreSymbol,
+    One,
+    SquareRootField,
+    Zero,
+};
 use snarkvm_utilities::{rand::UniformRand, to_bytes_le, ToBytes};
 
 use rand::thread_rng;
@@ -404,3 +414,59 @@ fn test_isomorphism() {
 
     assert_eq!(fr_element, fr_element_reconstructed);
 }
+
+#[test]
+fn test_conditional_select() {
+    fn check_conditional_select<T: ConditionallySelectable + PartialEq + core::fmt::Debug>(a: T, b: T) {
+        // Ensure the selection semantics hold.
+        assert_eq!(a, T::conditional_select(&a, &b, Choice::from(0)));
+        assert_eq!(b, T::conditional_select(&a, &b, Choice::from(1)));
+        // Ensure the conditional swap is consistent with the selection.
+        let (mut first, mut second) = (a, b);
+        T::conditional_swap(&mut first, &mut second, Choice::from(0));
+        assert_eq!((a, b), (first, second));
+        T::conditional_swap(&mut first, &mut second, Choice::from(1));
+        assert_eq!((b, a), (first, second));
+        // Ensure the conditional assignment is consistent with the selection.
+        let mut candidate = a;
+        candidate.conditional_assign(&b, Choice::from(0));
+        assert_eq!(a, candidate);
+        candidate.conditional_assign(&b, Choice::from(1));
+        assert_eq!(b, candidate);
+    }
+
+    let rng = &mut thread_rng();
+    for _ in 0..100 {
+        check_conditional_select(Fq::rand(rng), Fq::rand(rng));
+        check_conditional_select(Fr::rand(rng), Fr::rand(rng));
+        check_conditional_select(EdwardsAffine::rand(rng), EdwardsAffine::rand(rng));
+    }
+}
+
+#[test]
+fn test_conditional_select_touches_every_limb() {
+    /// A best-effort check that both choices do the same work: every limb of the output is
+    /// selected with `u64::conditional_select`, including when all of the other limbs are equal,
+    /// so the selection cannot exit early on a prefix of equal limbs.
+    fn check_conditional_select_limbs<P: Fp256Parameters>(a: Fp256<P>) {
+        for i in 0..4 {
+            // Construct an element that differs from `a` only in the `i`-th limb.
+            let mut b = a;
+            (b.0).0[i] ^= 1;
+
+            for choice in [0u8, 1u8] {
+                let candidate = Fp256::<P>::conditional_select(&a, &b, Choice::from(choice));
+                for j in 0..4 {
+                    let expected = u64::conditional_select(&(a.0).0[j], &(b.0).0[j], Choice::from(choice));
+                    assert_eq!(expected, (candidate.0).0[j]);
+                }
+            }
+        }
+    }
+
+    let rng = &mut thread_rng();
+    for _ in 0..100 {
+        check_conditional_select_limbs(Fq::rand(rng));
+        check_conditional_select_limbs(Fr::rand(rng));
+    }
+}
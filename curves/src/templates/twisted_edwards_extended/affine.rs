@@ -19,7 +19,7 @@ use crate::{
     templates::twisted_edwards_extended::Projective,
     traits::{AffineCurve, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
 };
-use snarkvm_fields::{Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_fields::{batch_inversion, Choice, ConditionallySelectable, Field, One, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{
     bititerator::BitIteratorBE,
     rand::UniformRand,
@@ -128,6 +128,37 @@ impl<P: Parameters> AffineCurve for Affine<P> {
         })
     }
 
+    /// Attempts to construct affine points given a list of x-coordinates.
+    /// The points are not guaranteed to be in the prime order subgroup.
+    ///
+    /// This method batches the inversions of the denominators, and otherwise
+    /// matches the output of `from_x_coordinate` on each x-coordinate.
+    fn batch_from_x_coordinates(xs: &[Self::BaseField], greatest: bool) -> Vec<Option<Self>> {
+        // y = sqrt( (a * x^2 - 1)  / (d * x^2 - 1) )
+        let one = Self::BaseField::one();
+        let x2s = xs.iter().map(|x| x.square()).collect::<Vec<_>>();
+        let mut inverses = x2s.iter().map(|x2| P::COEFF_D * x2 - one).collect::<Vec<_>>();
+        // Note: Zero denominators are left as zero by the batch inversion.
+        batch_inversion(&mut inverses);
+
+        xs.iter()
+            .zip(&x2s)
+            .zip(&inverses)
+            .map(|((x, x2), inverse)| {
+                // If the denominator is zero, there is no point with this x-coordinate.
+                if inverse.is_zero() {
+                    return None;
+                }
+                let y2 = *inverse * (P::mul_by_a(x2) - one);
+                y2.sqrt().map(|y| {
+                    let negy = -y;
+                    let y = if (y < negy) ^ greatest { y } else { negy };
+                    Self::new(*x, y)
+                })
+            })
+            .collect()
+    }
+
     /// Attempts to construct an affine point given a y-coordinate. The
     /// point is not guaranteed to be in the prime order subgroup.
     ///
@@ -245,6 +276,20 @@ impl<P: Parameters> Neg for Affine<P> {
     }
 }
 
+impl<P: Parameters> ConditionallySelectable for Affine<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    /// Returns `a` if `choice` is 0, and `b` if `choice` is 1, in constant time.
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+        )
+    }
+}
+
 impl<P: Parameters> Mul<P::ScalarField> for Affine<P> {
     type Output = Projective<P>;
 
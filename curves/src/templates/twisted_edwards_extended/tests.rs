@@ -54,6 +54,7 @@ where
     edwards_curve_serialization_test::<P>();
     edwards_from_random_bytes::<P>();
     edwards_from_x_and_y_coordinates::<P>();
+    edwards_batch_from_x_coordinates::<P>();
 }
 
 pub fn edwards_curve_serialization_test<P: TwistedEdwardsParameters>() {
@@ -219,3 +220,24 @@ where
         }
     }
 }
+
+pub fn edwards_batch_from_x_coordinates<P: TwistedEdwardsParameters>()
+where
+    P::BaseField: PrimeField,
+{
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // Sample x-coordinates of points on the curve, interleaved with random (likely invalid) x-coordinates.
+    let mut xs = vec![P::BaseField::zero(), P::BaseField::one()];
+    for _ in 0..ITERATIONS {
+        xs.push(Projective::<P>::rand(&mut rng).to_affine().x);
+        xs.push(P::BaseField::rand(&mut rng));
+    }
+
+    for greatest in [true, false] {
+        let expected = xs.iter().map(|x| Affine::<P>::from_x_coordinate(*x, greatest)).collect::<Vec<_>>();
+        assert_eq!(expected, Affine::<P>::batch_from_x_coordinates(&xs, greatest));
+    }
+    // Ensure an empty batch is supported.
+    assert!(Affine::<P>::batch_from_x_coordinates(&[], true).is_empty());
+}
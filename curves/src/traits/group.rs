@@ -105,6 +105,11 @@ pub trait ProjectiveCurve:
     fn to_affine(&self) -> Self::Affine;
 }
 
+/// Converts the given projective points into affine form, using a single shared inversion for the batch.
+pub fn batch_into_affine<G: AffineCurve>(points: &[G::Projective]) -> Vec<G> {
+    G::Projective::batch_normalization_into_affine(points.to_vec())
+}
+
 /// Affine representation of an elliptic curve point guaranteed to be
 /// in the correct prime order subgroup.
 #[allow(clippy::wrong_self_convention)]
@@ -153,6 +158,16 @@ pub trait AffineCurve:
     /// largest y-coordinate be selected.
     fn from_x_coordinate(x: Self::BaseField, greatest: bool) -> Option<Self>;
 
+    /// Attempts to construct affine points given a list of x-coordinates,
+    /// returning `None` for each x-coordinate that does not correspond to a point.
+    /// The points are not guaranteed to be in the prime order subgroup.
+    ///
+    /// This is equivalent to calling `from_x_coordinate` on each x-coordinate,
+    /// however implementations may share work (e.g. inversions) across the x-coordinates.
+    fn batch_from_x_coordinates(xs: &[Self::BaseField], greatest: bool) -> Vec<Option<Self>> {
+        xs.iter().map(|x| Self::from_x_coordinate(*x, greatest)).collect()
+    }
+
     /// Attempts to construct an affine point given a y-coordinate. The
     /// point is not guaranteed to be in the prime order subgroup.
     ///
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::traits::{AffineCurve, ProjectiveCurve};
+use crate::traits::{batch_into_affine, AffineCurve, ProjectiveCurve};
 use snarkvm_fields::Zero;
 use snarkvm_utilities::rand::{test_rng, UniformRand};
 
@@ -226,6 +226,23 @@ fn random_transformation_test<G: ProjectiveCurve>() {
     }
 }
 
+fn random_batch_into_affine_test<G: ProjectiveCurve>() {
+    let mut rng = test_rng();
+
+    // Sample a window of points, including the point at infinity.
+    let mut points = (0..251).map(|_| G::rand(&mut rng)).collect::<Vec<_>>();
+    points[0] = G::zero();
+    points[125] = G::zero();
+    // Include a point that is already normalized.
+    points[250] = points[250].to_affine().to_projective();
+
+    let expected = points.iter().map(|point| point.to_affine()).collect::<Vec<_>>();
+    let candidate = batch_into_affine::<G::Affine>(&points);
+    assert_eq!(expected, candidate);
+    assert!(candidate[0].is_zero());
+    assert!(candidate[125].is_zero());
+}
+
 pub fn curve_tests<G: ProjectiveCurve>() {
     let mut rng = test_rng();
 
@@ -280,4 +297,5 @@ pub fn curve_tests<G: ProjectiveCurve>() {
     random_doubling_test::<G>();
     random_negation_test::<G>();
     random_transformation_test::<G>();
+    random_batch_into_affine_test::<G>();
 }
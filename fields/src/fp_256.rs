@@ -17,6 +17,8 @@
 use crate::{
     impl_add_sub_from_field_ref,
     impl_mul_div_from_field_ref,
+    Choice,
+    ConditionallySelectable,
     FftField,
     Field,
     FieldError,
@@ -70,6 +72,29 @@ impl<P: Fp256Parameters> Fp256<P> {
         Fp256::<P>(element, PhantomData)
     }
 
+    /// Returns the limbs of the field element in Montgomery form, in little-endian order.
+    ///
+    /// This is an advanced API intended for zero-copy interop (e.g. FFI), and exposes
+    /// the internal representation as-is. For the canonical integer, use `to_repr` instead.
+    #[inline]
+    pub fn to_montgomery_limbs(&self) -> [u64; 4] {
+        (self.0).0
+    }
+
+    /// Returns the field element from its limbs in Montgomery form, in little-endian order.
+    /// Returns `None` if the limbs are not a canonical Montgomery representation (i.e. `>= MODULUS`).
+    ///
+    /// This is an advanced API intended for zero-copy interop (e.g. FFI). The limbs are interpreted
+    /// as `a * R mod p`, not as the integer `a`. For the canonical integer, use `from_repr` instead.
+    #[inline]
+    pub fn from_montgomery_limbs(limbs: [u64; 4]) -> Option<Self> {
+        let element = Self::new(BigInteger::new(limbs));
+        match element.is_valid() {
+            true => Some(element),
+            false => None,
+        }
+    }
+
     #[inline]
     fn is_valid(&self) -> bool {
         self.0 < P::MODULUS
@@ -477,6 +502,18 @@ impl<P: Fp256Parameters> FromBytes for Fp256<P> {
     }
 }
 
+impl<P: Fp256Parameters> ConditionallySelectable for Fp256<P> {
+    /// Returns `a` if `choice` is 0, and `b` if `choice` is 1, in constant time.
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            *limb = u64::conditional_select(&(a.0).0[i], &(b.0).0[i], choice);
+        }
+        Fp256::<P>(BigInteger(limbs), PhantomData)
+    }
+}
+
 /// `Fp` elements are ordered lexicographically.
 impl<P: Fp256Parameters> Ord for Fp256<P> {
     #[inline(always)]
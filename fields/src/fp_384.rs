@@ -17,6 +17,8 @@
 use crate::{
     impl_add_sub_from_field_ref,
     impl_mul_div_from_field_ref,
+    Choice,
+    ConditionallySelectable,
     FftField,
     Field,
     FieldError,
@@ -508,6 +510,18 @@ impl<P: Fp384Parameters> SquareRootField for Fp384<P> {
     }
 }
 
+impl<P: Fp384Parameters> ConditionallySelectable for Fp384<P> {
+    /// Returns `a` if `choice` is 0, and `b` if `choice` is 1, in constant time.
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut limbs = [0u64; 6];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            *limb = u64::conditional_select(&(a.0).0[i], &(b.0).0[i], choice);
+        }
+        Fp384::<P>(BigInteger(limbs), PhantomData)
+    }
+}
+
 impl<P: Fp384Parameters> Ord for Fp384<P> {
     #[inline(always)]
     fn cmp(&self, other: &Self) -> Ordering {
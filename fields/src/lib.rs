@@ -52,6 +52,8 @@ pub use to_field_vec::*;
 pub mod traits;
 pub use traits::*;
 
+pub use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
 use snarkvm_utilities::{
     biginteger::*,
     serialize::{CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize, CanonicalSerializeWithFlags},
@@ -157,7 +157,8 @@ impl<T: CanonicalSerialize> CanonicalSerialize for Option<T> {
 
     #[inline]
     fn serialized_size(&self, compress: Compress) -> usize {
-        8 + self.as_ref().map(|s| s.serialized_size(compress)).unwrap_or(0)
+        // Note: The presence of the item is serialized as a `bool`, which is 1 byte.
+        1 + self.as_ref().map(|s| s.serialized_size(compress)).unwrap_or(0)
     }
 }
 